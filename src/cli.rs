@@ -0,0 +1,258 @@
+//! command line arguments of the program, which are turned into the options of the library
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+/// indicates the level of error correction
+/// desired from the generated QR Code
+#[derive(Debug, Clone, ValueEnum, Copy, PartialEq)]
+pub enum ErrorLevel {
+    /// approx. 7% correction
+    L,
+    /// approx. 15% correction
+    M,
+    /// approx. 25% correction
+    Q,
+    /// approx. 30% correction
+    H,
+}
+
+impl From<ErrorLevel> for qrcode::ErrorLevel {
+    fn from(error_level: ErrorLevel) -> Self {
+        match error_level {
+            ErrorLevel::L => qrcode::ErrorLevel::L,
+            ErrorLevel::M => qrcode::ErrorLevel::M,
+            ErrorLevel::Q => qrcode::ErrorLevel::Q,
+            ErrorLevel::H => qrcode::ErrorLevel::H,
+        }
+    }
+}
+
+/// character sets that can be selected with an extended channel interpretation (ECI)
+#[derive(Debug, Clone, ValueEnum, Copy, PartialEq)]
+pub enum EciCharset {
+    /// ISO-8859-1 (Latin-1, western european)
+    #[value(name = "iso-8859-1")]
+    Iso8859_1,
+    /// ISO-8859-2 (Latin-2, central european)
+    #[value(name = "iso-8859-2")]
+    Iso8859_2,
+    /// ISO-8859-5 (cyrillic)
+    #[value(name = "iso-8859-5")]
+    Iso8859_5,
+    /// ISO-8859-7 (greek)
+    #[value(name = "iso-8859-7")]
+    Iso8859_7,
+    /// ISO-8859-15 (Latin-9, western european with euro sign)
+    #[value(name = "iso-8859-15")]
+    Iso8859_15,
+    /// Windows-1250 (central european)
+    #[value(name = "windows-1250")]
+    Windows1250,
+    /// Windows-1251 (cyrillic)
+    #[value(name = "windows-1251")]
+    Windows1251,
+    /// Windows-1252 (western european)
+    #[value(name = "windows-1252")]
+    Windows1252,
+    /// UTF-8
+    #[value(name = "utf-8")]
+    Utf8,
+}
+
+impl From<EciCharset> for qrcode::EciCharset {
+    fn from(charset: EciCharset) -> Self {
+        match charset {
+            EciCharset::Iso8859_1 => qrcode::EciCharset::Iso8859_1,
+            EciCharset::Iso8859_2 => qrcode::EciCharset::Iso8859_2,
+            EciCharset::Iso8859_5 => qrcode::EciCharset::Iso8859_5,
+            EciCharset::Iso8859_7 => qrcode::EciCharset::Iso8859_7,
+            EciCharset::Iso8859_15 => qrcode::EciCharset::Iso8859_15,
+            EciCharset::Windows1250 => qrcode::EciCharset::Windows1250,
+            EciCharset::Windows1251 => qrcode::EciCharset::Windows1251,
+            EciCharset::Windows1252 => qrcode::EciCharset::Windows1252,
+            EciCharset::Utf8 => qrcode::EciCharset::Utf8,
+        }
+    }
+}
+
+/// ways to print qr codes into the terminal
+#[derive(Debug, Clone, ValueEnum, Copy, PartialEq)]
+pub enum TerminalStyle {
+    /// three colored spaces per element
+    Ansi,
+    /// half block characters in black and white, two rows of elements per line
+    Compact,
+    /// half block characters without colors for terminals with dark text on a light background
+    Text,
+    /// half block characters without colors for terminals with light text on a dark background
+    Inverted,
+}
+
+impl From<TerminalStyle> for qrcode::TerminalStyle {
+    fn from(style: TerminalStyle) -> Self {
+        match style {
+            TerminalStyle::Ansi => qrcode::TerminalStyle::Ansi,
+            TerminalStyle::Compact => qrcode::TerminalStyle::Compact,
+            TerminalStyle::Text => qrcode::TerminalStyle::Text,
+            TerminalStyle::Inverted => qrcode::TerminalStyle::Inverted,
+        }
+    }
+}
+
+/// kinds of symbols that can be generated
+#[derive(Debug, Clone, ValueEnum, Copy, PartialEq)]
+pub enum SymbolType {
+    /// qr code of version 1 to 40 (21x21 to 177x177 elements)
+    Standard,
+    /// micro qr code of version M1 to M4 (11x11 to 17x17 elements) with a single finder pattern;
+    /// M1 only detects errors, level Q is only available in M4 and level H not at all
+    Micro,
+    /// rectangular micro qr code of version R7x43 to R17x139 (ISO/IEC 23941) with a fixed mask;
+    /// only has the levels M and H, L is raised to M and Q to H
+    Rmqr,
+}
+
+impl From<SymbolType> for qrcode::SymbolType {
+    fn from(symbol_type: SymbolType) -> Self {
+        match symbol_type {
+            SymbolType::Standard => qrcode::SymbolType::Standard,
+            SymbolType::Micro => qrcode::SymbolType::Micro,
+            SymbolType::Rmqr => qrcode::SymbolType::Rmqr,
+        }
+    }
+}
+
+/// appearance of the qr codes written into image files
+#[derive(Args, Debug, Clone, PartialEq)]
+pub struct ImageArgs {
+    /// Width and height of a single element in pixels
+    #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u32).range(1..))]
+    pub module_size: u32,
+    /// Width of the light border around the qr code in elements
+    /// [default: 4, 2 for micro qr codes]
+    #[arg(long)]
+    pub quiet_zone: Option<u32>,
+    /// Color of the dark elements (svg only)
+    #[arg(long, default_value = "#000000")]
+    pub foreground: String,
+    /// Color of the light elements and the quiet zone (svg only)
+    #[arg(long, default_value = "#ffffff")]
+    pub background: String,
+}
+
+impl From<&ImageArgs> for qrcode::ImageOptions {
+    fn from(image: &ImageArgs) -> Self {
+        qrcode::ImageOptions {
+            module_size: image.module_size,
+            quiet_zone: image.quiet_zone,
+            foreground: image.foreground.clone(),
+            background: image.background.clone(),
+        }
+    }
+}
+
+/// restrictions on the version, mask and error correction level of the generated qr codes
+#[derive(Args, Debug, Clone, PartialEq)]
+pub struct SymbolArgs {
+    /// Kind of symbol to generate; the version options count the versions of this kind
+    /// (1 to 4 for micro qr codes, 1 to 32 for rMQR codes ordered by height and width)
+    #[arg(long = "symbol", value_enum, default_value_t = SymbolType::Standard)]
+    pub symbol_type: SymbolType,
+    /// Generate a qr code of exactly this version (1 to 40)
+    #[arg(long = "version", value_name = "N", conflicts_with_all = ["min_version", "max_version"],
+        value_parser = clap::value_parser!(u8).range(1..=40))]
+    pub version: Option<u8>,
+    /// Smallest version the qr code may have
+    #[arg(long, value_name = "N", default_value_t = 1,
+        value_parser = clap::value_parser!(u8).range(1..=40))]
+    pub min_version: u8,
+    /// Largest version the qr code may have
+    #[arg(long, value_name = "N", default_value_t = 40,
+        value_parser = clap::value_parser!(u8).range(1..=40))]
+    pub max_version: u8,
+    /// Apply this mask (0 to 7, 0 to 3 for micro qr codes) instead of the best one
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u8).range(0..=7))]
+    pub mask: Option<u8>,
+    /// Raise the error correction level as long as the data still fits into the chosen version
+    #[arg(long)]
+    pub boost_ecl: bool,
+}
+
+impl From<&SymbolArgs> for qrcode::SymbolOptions {
+    fn from(symbol: &SymbolArgs) -> Self {
+        qrcode::SymbolOptions {
+            symbol_type: symbol.symbol_type.into(),
+            version: symbol.version,
+            min_version: symbol.min_version,
+            max_version: symbol.max_version,
+            mask: symbol.mask,
+            boost_ecl: symbol.boost_ecl,
+            ..qrcode::SymbolOptions::default()
+        }
+    }
+}
+
+/// commands that print information instead of a qr code
+#[derive(Debug, Subcommand, Clone, Copy, PartialEq)]
+pub enum Command {
+    /// Print how many characters of every mode fit into every version
+    /// and error correction level of a standard qr code
+    Capacity,
+}
+
+/// stores the to be encoded text and the
+/// error correction level
+#[derive(Parser, Debug, Clone)]
+#[clap(
+    about,
+    long_about = None,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true,
+    disable_help_subcommand = true
+)]
+pub struct Arguments {
+    /// the text to be incoded into the qr code
+    #[arg(required = true)]
+    pub information: Option<String>,
+    /// specifies the error correction level of the generated qr code
+    #[arg(value_enum, default_value_t = ErrorLevel::L)]
+    pub error_level: ErrorLevel,
+    /// Write the text in the given character set and mark it with an ECI header,
+    /// so scanners don't have to guess the character set (without it the text is written as UTF-8)
+    #[arg(long, value_enum)]
+    pub eci: Option<EciCharset>,
+    /// Read the text as GS1 element string like "(01)09501101530003(10)ABC"
+    /// and mark the qr code as GS1 qr code (FNC1 in first position)
+    #[arg(long, conflicts_with = "fnc1_second")]
+    pub gs1: bool,
+    /// Mark the qr code as formatted according to the industry application with the given
+    /// application indicator (FNC1 in second position); two digits or a single letter
+    #[arg(long)]
+    pub fnc1_second: Option<String>,
+    /// Read the qr code in the PBM or PGM image at the path given as text
+    /// and print its content instead of generating a qr code
+    #[arg(long)]
+    pub read: bool,
+    /// How the qr code is printed into the terminal
+    #[arg(long, value_enum, default_value_t = TerminalStyle::Ansi)]
+    pub terminal: TerminalStyle,
+    /// Write the qr code into an svg or png file (chosen by the extension) instead of
+    /// printing it to the terminal; a sequence of qr codes is written into numbered files
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<String>,
+    #[command(flatten, next_help_heading = "Image options")]
+    pub image: ImageArgs,
+    #[command(flatten, next_help_heading = "Symbol options")]
+    pub symbol: SymbolArgs,
+    /// Print debugging info to the terminal, while generating the code
+    #[arg(short, long)]
+    pub debugging: bool,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+impl Arguments {
+    /// returns the text, which is only missing if a command is given
+    pub fn information(&self) -> &str {
+        self.information.as_deref().unwrap_or_default()
+    }
+}
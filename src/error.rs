@@ -0,0 +1,97 @@
+use std::fmt::Display;
+
+use crate::input::EciCharset;
+
+/// everything that can go wrong while generating or reading a qr code
+#[derive(Debug, Clone, PartialEq)]
+pub enum QrError {
+    /// the data needs more bits than the symbol (or the largest version) can hold
+    DataTooLong {
+        needed_bits: usize,
+        available_bits: usize,
+    },
+    /// the text would have to be split into more symbols than structured append allows
+    TooManySymbols(u8),
+    /// there is no generator polynomial for this number of error correction bytes
+    UnsupportedEcLength(u8),
+    /// the version doesn't exist
+    InvalidVersion(u8),
+    /// the smallest allowed version is larger than the largest one
+    InvalidVersionRange { min_version: u8, max_version: u8 },
+    /// the mask doesn't exist
+    InvalidMask(u8),
+    /// the option isn't available for the kind of symbol
+    UnsupportedOption(String),
+    /// the character can't be written in any mode with this character set
+    InvalidCharacter {
+        character: char,
+        charset: EciCharset,
+    },
+    /// the ECI assignment number is out of range or its character set isn't supported
+    UnsupportedEci(u32),
+    /// the GS1 element string or application indicator is malformed
+    InvalidGs1(String),
+    /// the file extension doesn't belong to an image format that can be written
+    UnknownImageFormat(String),
+    /// a file couldn't be read or written
+    Io(String),
+    /// the file isn't a PBM or PGM image that can be read
+    InvalidImage(String),
+    /// no qr code was found in the image
+    SymbolNotFound(String),
+    /// the symbol was found, but its content can't be read
+    InvalidSymbol(String),
+    /// more codewords are damaged than the error correction can restore
+    TooManyErrors,
+}
+
+impl Display for QrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QrError::DataTooLong {
+                needed_bits,
+                available_bits,
+            } => write!(
+                f,
+                "data needs {needed_bits} bits, but only {available_bits} bits fit into the qr code"
+            ),
+            QrError::TooManySymbols(max_symbols) => write!(
+                f,
+                "text is too long to be split into {max_symbols} qr codes"
+            ),
+            QrError::UnsupportedEcLength(num_error_bytes) => write!(
+                f,
+                "no generator polynomial for {num_error_bytes} error correction bytes"
+            ),
+            QrError::InvalidVersion(version) => write!(f, "version {version} doesn't exist"),
+            QrError::InvalidVersionRange {
+                min_version,
+                max_version,
+            } => write!(
+                f,
+                "minimum version {min_version} is larger than the maximum version {max_version}"
+            ),
+            QrError::InvalidMask(mask) => write!(f, "mask {mask} doesn't exist"),
+            QrError::UnsupportedOption(message) => write!(f, "{message}"),
+            QrError::InvalidCharacter { character, charset } => write!(
+                f,
+                "character '{character}' can't be encoded (character set {charset})"
+            ),
+            QrError::UnsupportedEci(assignment_number) => {
+                write!(f, "unsupported ECI {assignment_number}")
+            }
+            QrError::InvalidGs1(msg)
+            | QrError::Io(msg)
+            | QrError::InvalidImage(msg)
+            | QrError::SymbolNotFound(msg)
+            | QrError::InvalidSymbol(msg) => write!(f, "{msg}"),
+            QrError::UnknownImageFormat(path) => write!(
+                f,
+                "unknown image format of \"{path}\", use a .svg or .png file"
+            ),
+            QrError::TooManyErrors => write!(f, "too many errors to correct"),
+        }
+    }
+}
+
+impl std::error::Error for QrError {}
@@ -1,8 +1,9 @@
 /// based on "Tutorial on Reed-Solomon Error Correction Coding" by William A. Geisel (August 1990)
 /// https://ntrs.nasa.gov/api/citations/19900019023/downloads/19900019023.pdf
 /// (last viewed 17.04.2025)
-use std::{collections::HashMap, fmt::Display, vec};
+use std::{fmt::Display, vec};
 
+use crate::error::QrError;
 use crate::polynomials::Indeterminate;
 
 use super::polynomials::Polynomial;
@@ -11,19 +12,15 @@ use super::polynomials::Polynomial;
 pub struct GaloisFields {
     m: u8,
     mod_poly: Polynomial,
-    galois_table: HashMap<u8, u8>,
+    /// a^index for index 0..2 * (2^m - 1), stored twice so the sum of two alpha indices
+    /// can be looked up without a modulo
+    exp_table: [u8; 512],
+    /// alpha index of every value; the entry of 0 is unused, as 0 isn't a power of a
+    log_table: [u8; 256],
 }
 
-/// macro for checking the length of the given vector in a test
-macro_rules! generator_macro {
-    ($vect_len:expr, $galois_field:expr, $vector_coeff:expr) => {{
-        assert_eq!($vector_coeff.len(), $vect_len + 1);
-        Some(GaloisFields::generate_corr_polynomial(
-            $galois_field,
-            $vector_coeff,
-        ))
-    }};
-}
+/// highest degree of a generator polynomial that can be calculated
+pub const MAXGENERATORDEGREE: u8 = 68;
 
 impl GaloisFields {
     /// converts a polynomila into it's obinary representation
@@ -48,343 +45,307 @@ impl GaloisFields {
         mask
     }
 
+    /// generates GF(2^m), where a^m = mod_fx
     pub fn _new(m: u8, mod_fx: Polynomial) -> Self {
         assert!(m > 0);
         assert!(m <= 8);
-        let mut res_map = HashMap::new();
-
-        // first two entires can be hard coded
-        res_map.insert(0, 0);
-        res_map.insert(1, 1);
-        if m == 1 {
-            return GaloisFields {
-                m,
-                mod_poly: mod_fx,
-                galois_table: res_map,
-            };
-        }
-
-        // put in the generic stuff
-        let x1 = Polynomial::new(vec![Indeterminate::new(1, 1)]);
-        let mut x = x1.clone();
-        for index in 2..=m {
-            if let Some(existing_value) =
-                res_map.insert(index, GaloisFields::_to_galois_u8(x.clone()))
-            {
-                eprintln!("overwrote exisitng entry {existing_value:?} in hasmap");
-            }
-            // don't if this is the last iteration
-            if index != m {
-                x = x * x1.clone();
+        let order: usize = (1 << m) - 1;
+        let reduction: u16 = GaloisFields::_to_galois_u8(mod_fx.clone()) as u16;
+        let mut exp_table: [u8; 512] = [0; 512];
+        let mut log_table: [u8; 256] = [0; 256];
+        // multiply by x (shift left) and replace an overflowing x^m by the mod polynomial
+        let mut value: u16 = 1;
+        for index in 0..order {
+            exp_table[index] = value as u8;
+            exp_table[index + order] = value as u8;
+            log_table[value as usize] = index as u8;
+            value <<= 1;
+            if value & (1 << m) != 0 {
+                value ^= (1 << m) | reduction;
             }
         }
-
-        // the acually interesting stuff
-        let for_range = if m != 8 {
-            m + 1..=(2_u8.pow(m.into()) - 1)
-        } else {
-            m + 1..=255
-        };
-        for index in for_range {
-            x = x.galois_mul_x1(mod_fx.clone(), m);
-            if let Some(existing_value) =
-                res_map.insert(index, GaloisFields::_to_galois_u8(x.clone()))
-            {
-                eprintln!("overwrote exisitng entry {existing_value:?} in hasmap");
-            }
-        }
-
         GaloisFields {
             m,
             mod_poly: mod_fx,
-            galois_table: res_map,
+            exp_table,
+            log_table,
         }
     }
 
+    /// returns the number of non zero elements (2^m - 1)
+    fn order(&self) -> usize {
+        (1 << self.m) - 1
+    }
+
     /// takes the index of an alpha value and returns the value stored in that alpha
     pub fn index_to_value(&self, alpha_indice: u8) -> u8 {
-        // compensate the frost two elements 0 and 1 in the galois field
-        let indice = ((alpha_indice as u16 + 1_u16) % 256) as u8;
-        assert!(self.galois_table.len() >= (indice as usize));
-        // get value
-        if let Some(alpha) = self.galois_table.get(&indice) {
-            *alpha
-        } else {
-            panic!("key {} wasn't in hashmap {:?}", indice, self.galois_table);
-        }
+        self.exp_table[alpha_indice as usize % self.order()]
     }
 
+    // only used in tests
     /// takes an alpha value and returns the index that value has in the galois field
-    pub fn value_to_index(&self, value: u8) -> Result<u8, String> {
-        for (index, value_map) in self.galois_table.clone().into_iter() {
-            if value_map == value && index != 0 {
-                return Ok(index - 1);
-            }
+    pub fn _value_to_index(&self, value: u8) -> Result<u8, String> {
+        if value == 0 || value as usize > self.order() {
+            return Err(format!("value {value} not found"));
         }
-        Err(format!("value {value} not found"))
+        Ok(self.log_table[value as usize])
     }
 
-    /// generates the error correction polynomial based on the given galois field and coefficients
-    fn generate_corr_polynomial(galois_field: &Self, coefficients_indices: Vec<u8>) -> Polynomial {
-        let mut polynomial = Polynomial::new(vec![]);
-        let mut current_degree = coefficients_indices.len() - 1;
-        for coefficient_index in coefficients_indices.iter() {
-            // println!("index: {current_degree}");
-            // println!("coefficient_index: {coefficient_index}");
-            let alpha_value = galois_field.index_to_value(*coefficient_index);
-            // println!("value to index: {alpha_value}");
-            if current_degree == (coefficients_indices.len() - 1) {
-                polynomial.push(Indeterminate::new(1, current_degree.try_into().unwrap()));
-            } else {
-                polynomial.push(Indeterminate::new(
-                    alpha_value,
-                    current_degree.try_into().unwrap(),
-                ));
+    /// multiplies two values of the galois field by adding their alpha indices
+    fn multiply_values(&self, lhs: u8, rhs: u8) -> u8 {
+        if lhs == 0 || rhs == 0 {
+            return 0;
+        }
+        self.exp_table
+            [self.log_table[lhs as usize] as usize + self.log_table[rhs as usize] as usize]
+    }
+
+    /// divides two values of the galois field by subtracting their alpha indices
+    fn divide_values(&self, lhs: u8, rhs: u8) -> u8 {
+        assert!(rhs != 0, "division by zero in the galois field");
+        if lhs == 0 {
+            return 0;
+        }
+        self.exp_table[self.log_table[lhs as usize] as usize + self.order()
+            - self.log_table[rhs as usize] as usize]
+    }
+
+    /// returns the coefficients of the generator polynomial
+    /// (x - a^0) * (x - a^1) * ... * (x - a^(n - 1)) with n = num_error_corr,
+    /// from the highest degree (which is always 1) to the lowest
+    fn generator_coefficients(&self, num_error_corr: u8) -> Option<Vec<u8>> {
+        if num_error_corr == 0 || num_error_corr > MAXGENERATORDEGREE {
+            return None;
+        }
+        let mut coefficients: Vec<u8> = vec![1];
+        for alpha_index in 0..num_error_corr {
+            // multiply with (x - a^i); subtraction is the same as addition (XOR)
+            let root = self.index_to_value(alpha_index);
+            coefficients.push(0);
+            for index in (1..coefficients.len()).rev() {
+                coefficients[index] ^= self.multiply_values(coefficients[index - 1], root);
             }
-            current_degree = current_degree.saturating_sub(1);
         }
-        assert!(current_degree == 0);
-        // println!("poly: {polynomial}");
-        polynomial
+        Some(coefficients)
     }
 
+    /// generates the generator polynomial (x - a^0) * (x - a^1) * ... * (x - a^(n - 1))
+    /// with n = num_error_corr, which the error correction bytes are calculated with;
     /// meant to be used on a galosi field of m=8 and Polynomial x^4+x^3+x^2+1
     pub fn correction_polynomial(&self, num_error_corr: u8) -> Option<Polynomial> {
-        match num_error_corr {
-            2 => generator_macro!(2, self, vec![1, 25, 1]),
-            5 => generator_macro!(5, self, vec![1, 113, 164, 166, 119, 10]),
-            7 => generator_macro!(7, self, vec![1, 87, 229, 146, 149, 238, 102, 21]),
-            8 => generator_macro!(8, self, vec![1, 175, 238, 208, 249, 215, 252, 196, 28]),
-            10 => generator_macro!(10, self, vec![1, 251, 67, 46, 61, 118, 70, 64, 94, 32, 45]),
-            13 => generator_macro!(
-                13,
-                self,
-                vec![1, 74, 152, 176, 100, 86, 100, 106, 104, 130, 218, 206, 140, 78,]
-            ),
-            14 => generator_macro!(
-                14,
-                self,
-                vec![1, 199, 249, 155, 48, 190, 124, 218, 137, 216, 87, 207, 59, 22, 91,]
-            ),
-            16 => generator_macro!(
-                16,
-                self,
-                vec![
-                    1, 120, 104, 107, 109, 102, 161, 76, 3, 91, 191, 147, 169, 182, 194, 225, 120,
-                ]
-            ),
-            17 => generator_macro!(
-                17,
-                self,
-                vec![
-                    1, 43, 139, 206, 78, 43, 239, 123, 206, 214, 147, 24, 99, 150, 39, 243, 163,
-                    136,
-                ]
-            ),
-            18 => generator_macro!(
-                18,
-                self,
-                vec![
-                    1, 215, 234, 158, 94, 184, 97, 118, 170, 79, 187, 152, 148, 252, 179, 5, 98,
-                    96, 153,
-                ]
-            ),
-            20 => generator_macro!(
-                20,
-                self,
-                vec![
-                    1, 17, 60, 79, 50, 61, 163, 26, 187, 202, 180, 221, 225, 83, 239, 156, 164,
-                    212, 212, 188, 190,
-                ]
-            ),
-            22 => generator_macro!(
-                22,
-                self,
-                vec![
-                    1, 210, 171, 247, 242, 93, 230, 14, 109, 221, 53, 200, 74, 8, 172, 98, 80, 219,
-                    134, 160, 105, 165, 231,
-                ]
-            ),
-            24 => generator_macro!(
-                24,
-                self,
-                vec![
-                    1, 229, 121, 135, 48, 211, 117, 251, 126, 159, 180, 169, 152, 192, 226, 228,
-                    218, 111, 0, 117, 232, 87, 96, 227, 21,
-                ]
-            ),
-            26 => generator_macro!(
-                26,
-                self,
-                vec![
-                    1, 173, 125, 158, 2, 103, 182, 118, 17, 145, 201, 111, 28, 165, 53, 161, 21,
-                    245, 142, 13, 102, 48, 227, 153, 145, 218, 70,
-                ]
-            ),
-            28 => generator_macro!(
-                28,
-                self,
-                vec![
-                    1, 168, 223, 200, 104, 224, 234, 108, 180, 110, 190, 195, 147, 205, 27, 232,
-                    201, 21, 43, 245, 87, 42, 195, 212, 119, 242, 37, 9, 123,
-                ]
-            ),
-            30 => generator_macro!(
-                30,
-                self,
-                vec![
-                    1, 41, 173, 145, 152, 216, 31, 179, 182, 50, 48, 110, 86, 239, 96, 222, 125,
-                    42, 173, 226, 193, 224, 130, 156, 37, 251, 216, 238, 40, 192, 180,
-                ]
-            ),
-            32 => generator_macro!(
-                32,
-                self,
-                vec![
-                    1, 10, 6, 106, 190, 249, 167, 4, 67, 209, 138, 138, 32, 242, 123, 89, 27, 120,
-                    185, 80, 156, 38, 69, 171, 60, 28, 222, 80, 52, 254, 185, 220, 241,
-                ]
-            ),
-            34 => generator_macro!(
-                34,
-                self,
-                vec![
-                    1, 111, 77, 146, 94, 26, 21, 108, 19, 105, 94, 113, 193, 86, 140, 163, 125, 58,
-                    158, 229, 239, 218, 103, 56, 70, 114, 61, 183, 129, 167, 13, 98, 62, 129, 51
-                ]
-            ),
-            36 => generator_macro!(
-                36,
-                self,
-                vec![
-                    1, 200, 183, 98, 16, 172, 31, 246, 234, 60, 152, 115, 0, 167, 152, 113, 248,
-                    238, 107, 18, 63, 218, 37, 87, 210, 105, 177, 120, 74, 121, 196, 117, 251, 113,
-                    233, 30, 120
-                ]
-            ),
-            40 => generator_macro!(
-                40,
-                self,
-                vec![
-                    1, 59, 116, 79, 161, 252, 98, 128, 205, 128, 161, 247, 57, 163, 56, 235, 106,
-                    53, 26, 187, 174, 226, 104, 170, 7, 175, 35, 181, 114, 88, 41, 47, 163, 125,
-                    134, 72, 20, 232, 53, 35, 15
-                ]
-            ),
-            42 => generator_macro!(
-                42,
-                self,
-                vec![
-                    1, 250, 103, 221, 230, 25, 18, 137, 231, 0, 3, 58, 242, 221, 191, 110, 84, 230,
-                    8, 188, 106, 96, 147, 15, 131, 139, 34, 101, 223, 39, 101, 213, 199, 237, 254,
-                    201, 123, 171, 162, 194, 117, 50, 96
-                ]
-            ),
-            44 => generator_macro!(
-                44,
-                self,
-                vec![
-                    1, 190, 7, 61, 121, 71, 246, 69, 55, 168, 188, 89, 243, 191, 25, 72, 123, 9,
-                    145, 14, 247, 1, 238, 44, 78, 143, 62, 224, 126, 118, 114, 68, 163, 52, 194,
-                    217, 147, 204, 169, 37, 130, 113, 102, 73, 181
-                ]
-            ),
-            _ => None,
+        let coefficients: Vec<u8> = self.generator_coefficients(num_error_corr)?;
+        let mut polynomial = Polynomial::new(vec![]);
+        for (index, coefficient) in coefficients.iter().enumerate() {
+            let degree = (num_error_corr as usize - index) as i16;
+            polynomial.push(Indeterminate::new(*coefficient, degree));
         }
+        Some(polynomial)
     }
 
-    pub fn calculate_error_correction(
-        data_bytes: &Polynomial,
-        correction_polynomial: Polynomial,
-        galois_field: GaloisFields,
-    ) -> Polynomial {
-        // println!("given data: {}", data_bytes);
-        let mut work_data_bytes = data_bytes.clone();
-        let number_iterations =
-            data_bytes.get_function().len() - correction_polynomial.get_function().len();
-        let num_valid_operations = correction_polynomial.get_function().len();
-        // println!("num valid operations: {}", num_valid_operations);
-        // println!("number itertaions: {}", number_iterations);
-        for iteration_index in 0..=number_iterations {
-            // get highest degree
-            let mut highest_degree = 0;
-            for element in work_data_bytes.get_function().iter() {
-                if element.get_degree() > highest_degree && element.get_coefficient() != 0 {
-                    highest_degree = element.get_degree();
-                }
+    /// returns the num_error_corr error correction bytes of the data bytes
+    /// (the remainder of data * x^num_error_corr divided by the generator polynomial)
+    ///
+    /// the division is done by a linear feedback shift register: every data byte is added
+    /// to the byte shifted out of the register, and the generator polynomial multiplied with
+    /// that sum is added onto the shifted register
+    pub fn error_correction_bytes(
+        &self,
+        data: &[u8],
+        num_error_corr: u8,
+    ) -> Result<Vec<u8>, QrError> {
+        let Some(generator) = self.generator_coefficients(num_error_corr) else {
+            return Err(QrError::UnsupportedEcLength(num_error_corr));
+        };
+        let mut register: Vec<u8> = vec![0; num_error_corr as usize];
+        for byte in data.iter() {
+            let feedback: u8 = byte ^ register[0];
+            register.rotate_left(1);
+            register[num_error_corr as usize - 1] = 0;
+            if feedback == 0 {
+                continue;
             }
-            // println!("highest degree: {}", highest_degree);
-            // get value of highest degree
-            let mut highest_degree_value_option = None;
-            for element in work_data_bytes.get_function().iter() {
-                if element.get_degree() == highest_degree {
-                    highest_degree_value_option = Some(element.get_coefficient());
-                }
+            for (element, coefficient) in register.iter_mut().zip(generator[1..].iter()) {
+                *element ^= self.multiply_values(*coefficient, feedback);
             }
-            if highest_degree_value_option.is_none() {
-                panic!("highest degree couldn't be found even though is should exitst");
+        }
+        Ok(register)
+    }
+
+    /// returns a^exponent, negative exponents are the inverse values
+    fn alpha_power(&self, exponent: i32) -> u8 {
+        self.index_to_value(exponent.rem_euclid(self.order() as i32) as u8)
+    }
+
+    /// returns the coefficients of the polynomial with the degree as index
+    /// (the lowest degree first)
+    pub(crate) fn coefficients_by_degree(polynomial: &Polynomial) -> Vec<u8> {
+        let function = polynomial.get_function();
+        let highest_degree = function
+            .iter()
+            .map(|indeterminate| indeterminate.get_degree())
+            .max()
+            .unwrap_or(0);
+        let mut coefficients: Vec<u8> = vec![0; highest_degree as usize + 1];
+        for indeterminate in function.iter() {
+            assert!(indeterminate.get_degree() >= 0);
+            coefficients[indeterminate.get_degree() as usize] ^= indeterminate.get_coefficient();
+        }
+        coefficients
+    }
+
+    /// evaluates the polynomial given by its coefficients (lowest degree first) at x
+    fn evaluate(&self, coefficients: &[u8], x: u8) -> u8 {
+        let mut result = 0;
+        for coefficient in coefficients.iter().rev() {
+            result = self.multiply_values(result, x) ^ coefficient;
+        }
+        result
+    }
+
+    /// multiplies two polynomials given by their coefficients (lowest degree first)
+    fn multiply_coefficients(&self, lhs: &[u8], rhs: &[u8]) -> Vec<u8> {
+        let mut result: Vec<u8> = vec![0; lhs.len() + rhs.len() - 1];
+        for (lhs_degree, lhs_coefficient) in lhs.iter().enumerate() {
+            for (rhs_degree, rhs_coefficient) in rhs.iter().enumerate() {
+                result[lhs_degree + rhs_degree] ^=
+                    self.multiply_values(*lhs_coefficient, *rhs_coefficient);
             }
-            let highest_degree_value = highest_degree_value_option.unwrap();
-            // println!("highest degree value: {}", highest_degree_value);
-            // multiply everything with highest value
-            let mut offset = iteration_index;
-            let mut working_iterations = num_valid_operations;
-            // is the same for all following iterations
-            let highest_value_index = galois_field.value_to_index(highest_degree_value).unwrap();
-            // println!(
-            //     "index of the highest value in the data is {}",
-            //     highest_value_index
-            // );
-            for element in work_data_bytes.get_function_mut().iter_mut() {
-                if offset == 0 && working_iterations != 0 {
-                    // get alpha indices from correction and highest value
-                    let mut value_correction_value_option = None;
-                    for correction_element in correction_polynomial.clone() {
-                        if correction_element.get_degree()
-                            == (working_iterations - 1).try_into().unwrap()
-                        {
-                            value_correction_value_option =
-                                Some(correction_element.get_coefficient());
-                        }
-                    }
-                    let value_correction_index =
-                        if let Some(value_correction_value) = value_correction_value_option {
-                            galois_field.value_to_index(value_correction_value).unwrap()
-                        } else {
-                            panic!(
-                                "no index for value {} in galois field",
-                                value_correction_value_option.unwrap()
-                            );
-                        };
-                    // println!("--------------\ndata byte at index {}", _index);
-                    // add indices
-                    let new_alpha_index =
-                        ((highest_value_index as u16 + value_correction_index as u16) % 255) as u8;
-                    // println!(
-                    //     "added indices ({} + {}) % 255 = {}",
-                    //     highest_value_index, value_correction_index, new_alpha_index
-                    // );
-                    let new_alpha_value = galois_field.index_to_value(new_alpha_index);
-                    // XOR the values
-                    let final_value = new_alpha_value ^ element.get_coefficient();
-                    // println!(
-                    //     "XOR'ing {} (index {}) and {} to {}",
-                    //     new_alpha_value,
-                    //     new_alpha_index,
-                    //     element.get_coefficient(),
-                    //     final_value
-                    // );
-                    // assign new value
-                    element.set_coefficient(final_value);
-                }
-                if working_iterations != 0 && offset == 0 {
-                    working_iterations -= 1;
-                }
-                offset = offset.saturating_sub(1);
+        }
+        result
+    }
+
+    /// calculates the syndromes S_i = r(a^i) for i = 0..num_error_corr of the received
+    /// polynomial; they are all zero if the polynomial is a valid codeword
+    pub fn syndromes(&self, received: &Polynomial, num_error_corr: u8) -> Vec<u8> {
+        let coefficients = GaloisFields::coefficients_by_degree(received);
+        (0..num_error_corr)
+            .map(|index| self.evaluate(&coefficients, self.index_to_value(index)))
+            .collect()
+    }
+
+    /// corrects the received polynomial (data and error correction bytes, the first byte
+    /// having the highest degree) that was generated with num_error_corr error correction bytes;
+    /// erasures are the degrees of bytes that are known to be unreadable
+    ///
+    /// up to 2 * errors + erasures <= num_error_corr can be corrected:
+    /// the error locator is found with the Berlekamp-Massey algorithm (starting with the
+    /// erasure locator), its roots (the error locations) with the Chien search and
+    /// the error values with the Forney algorithm
+    pub fn correct_errors(
+        &self,
+        received: &Polynomial,
+        num_error_corr: u8,
+        erasures: &[i16],
+    ) -> Result<Polynomial, QrError> {
+        let mut codeword = GaloisFields::coefficients_by_degree(received);
+        let num_codewords = codeword.len();
+        let num_error_corr = num_error_corr as usize;
+        if num_codewords > 255 || num_error_corr >= num_codewords {
+            return Err(QrError::InvalidSymbol(format!(
+                "{num_codewords} bytes with {num_error_corr} error correction bytes can't be decoded"
+            )));
+        }
+        if erasures.len() > num_error_corr {
+            return Err(QrError::InvalidSymbol(format!(
+                "{} erasures are more than the {num_error_corr} error correction bytes can restore",
+                erasures.len()
+            )));
+        }
+        for degree in erasures.iter() {
+            if *degree < 0 || *degree as usize >= num_codewords {
+                return Err(QrError::InvalidSymbol(format!(
+                    "erasure at degree {degree} is outside of the codeword"
+                )));
             }
-            // println!("after iteration {}: {}", iteration_index, work_data_bytes);
         }
-        work_data_bytes
+        let syndromes = self.syndromes(received, num_error_corr as u8);
+        if syndromes.iter().all(|syndrome| *syndrome == 0) {
+            return Ok(received.clone());
+        }
+        // erasure locator (1 + a^j1 x) * (1 + a^j2 x) * ...
+        let mut locator: Vec<u8> = vec![1];
+        for degree in erasures.iter() {
+            locator = self.multiply_coefficients(&locator, &[1, self.alpha_power(*degree as i32)]);
+        }
+        // Berlekamp-Massey
+        let num_erasures = erasures.len();
+        let mut previous_locator: Vec<u8> = locator.clone();
+        let mut locator_length = num_erasures;
+        let mut previous_discrepancy: u8 = 1;
+        let mut shift: usize = 1;
+        for step in num_erasures..num_error_corr {
+            let mut discrepancy = syndromes[step];
+            for (degree, coefficient) in locator.iter().enumerate().skip(1) {
+                discrepancy ^= self.multiply_values(*coefficient, syndromes[step - degree]);
+            }
+            if discrepancy == 0 {
+                shift += 1;
+                continue;
+            }
+            // locator - discrepancy / previous discrepancy * x^shift * previous locator
+            let factor = self.divide_values(discrepancy, previous_discrepancy);
+            let mut next_locator = locator.clone();
+            next_locator.resize(locator.len().max(previous_locator.len() + shift), 0);
+            for (degree, coefficient) in previous_locator.iter().enumerate() {
+                next_locator[degree + shift] ^= self.multiply_values(factor, *coefficient);
+            }
+            if 2 * locator_length <= step + num_erasures {
+                previous_locator = locator;
+                locator_length = step + 1 + num_erasures - locator_length;
+                previous_discrepancy = discrepancy;
+                shift = 1;
+            } else {
+                shift += 1;
+            }
+            locator = next_locator;
+        }
+        while locator.len() > 1 && locator[locator.len() - 1] == 0 {
+            locator.pop();
+        }
+        let num_errata = locator.len() - 1;
+        if num_errata != locator_length || 2 * num_errata > num_error_corr + num_erasures {
+            return Err(QrError::TooManyErrors);
+        }
+        // Chien search: an error at degree j is a root a^-j of the locator
+        let error_degrees: Vec<usize> = (0..num_codewords)
+            .filter(|degree| self.evaluate(&locator, self.alpha_power(-(*degree as i32))) == 0)
+            .collect();
+        if error_degrees.len() != num_errata {
+            return Err(QrError::TooManyErrors);
+        }
+        // Forney: error evaluator (syndromes * locator) mod x^num_error_corr
+        // and the formal derivative of the locator
+        let mut evaluator = self.multiply_coefficients(&syndromes, &locator);
+        evaluator.truncate(num_error_corr);
+        let derivative: Vec<u8> = locator
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(degree, coefficient)| if degree % 2 == 1 { *coefficient } else { 0 })
+            .collect();
+        for degree in error_degrees.iter() {
+            let inverse_location = self.alpha_power(-(*degree as i32));
+            let denominator = self.evaluate(&derivative, inverse_location);
+            if denominator == 0 {
+                return Err(QrError::TooManyErrors);
+            }
+            let error_value = self.multiply_values(
+                self.alpha_power(*degree as i32),
+                self.divide_values(self.evaluate(&evaluator, inverse_location), denominator),
+            );
+            codeword[*degree] ^= error_value;
+        }
+        // the first byte has the highest degree
+        codeword.reverse();
+        let corrected = Polynomial::from(codeword);
+        if self
+            .syndromes(&corrected, num_error_corr as u8)
+            .iter()
+            .any(|syndrome| *syndrome != 0)
+        {
+            return Err(QrError::TooManyErrors);
+        }
+        Ok(corrected)
     }
 }
 
@@ -400,12 +361,13 @@ impl Display for GaloisFields {
             f,
             "--------   -----   -------------------------   ---------"
         )?;
-        // get the elements
-        let mut keys: Vec<u8> = self.galois_table.keys().copied().collect();
-        keys.sort();
-        for (index, key) in keys.iter().enumerate() {
+        // 0 followed by all powers of alpha
+        let values: Vec<u8> = std::iter::once(0)
+            .chain(self.exp_table[..self.order()].iter().copied())
+            .collect();
+        for (index, value) in values.iter().enumerate() {
             // index
-            write!(f, "{key:>8}   ")?;
+            write!(f, "{index:>8}   ")?;
             // alpha index
             if index == 0 {
                 write!(f, "  nan   ")?;
@@ -413,14 +375,10 @@ impl Display for GaloisFields {
                 write!(f, "{:>5}   ", index - 1)?;
             }
             // alpha value
-            let current_enum = self
-                .galois_table
-                .get_key_value(key)
-                .expect("key that was upposed to be in hashmap wasn't");
             let mut is_leading = true;
             for bit_index in (0..8).rev() {
                 let mask = 0b0000_0001 << bit_index;
-                if (current_enum.1 & mask) > 0 {
+                if (value & mask) > 0 {
                     write!(f, "1")?;
                     is_leading = false;
                 // only print leading zeroes within the set bit limit m
@@ -432,12 +390,34 @@ impl Display for GaloisFields {
             }
             write!(f, "                    ")?;
             // decimal value
-            writeln!(f, "{:>9}", current_enum.1)?;
+            writeln!(f, "{value:>9}")?;
         }
         write!(f, "")
     }
 }
 
+#[test]
+/// micro qr code M2-L with the text "01234567" from ISO/IEC 18004 annex I.3
+fn test_error_correction_bytes() {
+    let galois_field = GaloisFields::_new(
+        8,
+        Polynomial::new(vec![
+            Indeterminate::new(1, 4),
+            Indeterminate::new(1, 3),
+            Indeterminate::new(1, 2),
+            Indeterminate::new(1, 0),
+        ]),
+    );
+    assert_eq!(
+        galois_field.error_correction_bytes(&[0x40, 0x18, 0xac, 0xc3, 0x00], 5),
+        Ok(vec![0x86, 0x0d, 0x22, 0xae, 0x30])
+    );
+    assert_eq!(
+        galois_field.error_correction_bytes(&[0x40], 69),
+        Err(QrError::UnsupportedEcLength(69))
+    );
+}
+
 #[test]
 fn test_values_m_4() {
     let example = GaloisFields::_new(
@@ -446,29 +426,73 @@ fn test_values_m_4() {
     );
     // print value in case of panic!()
     eprintln!("{example}");
-    let mut correct_map = HashMap::new();
-    correct_map.insert(0, 0b0000_u8);
-    correct_map.insert(1, 0b0001_u8);
-    correct_map.insert(2, 0b0010_u8);
-    correct_map.insert(3, 0b0100_u8);
-    correct_map.insert(4, 0b1000_u8);
-    correct_map.insert(5, 0b0011_u8);
-    correct_map.insert(6, 0b0110_u8);
-    correct_map.insert(7, 0b1100_u8);
-    correct_map.insert(8, 0b1011_u8);
-    correct_map.insert(9, 0b0101_u8);
-    correct_map.insert(10, 0b1010_u8);
-    correct_map.insert(11, 0b0111_u8);
-    correct_map.insert(12, 0b1110_u8);
-    correct_map.insert(13, 0b1111_u8);
-    correct_map.insert(14, 0b1101_u8);
-    correct_map.insert(15, 0b1001_u8);
-    let correct_result = GaloisFields {
-        m: 4,
-        mod_poly: Polynomial::new(vec![Indeterminate::new(1, 1), Indeterminate::new(1, 0)]),
-        galois_table: correct_map,
+    // the values of a^0 to a^14
+    let correct_values: [u8; 15] = [
+        0b0001, 0b0010, 0b0100, 0b1000, 0b0011, 0b0110, 0b1100, 0b1011, 0b0101, 0b1010, 0b0111,
+        0b1110, 0b1111, 0b1101, 0b1001,
+    ];
+    for (alpha_index, value) in correct_values.iter().enumerate() {
+        assert_eq!(example.index_to_value(alpha_index as u8), *value);
+        assert_eq!(example._value_to_index(*value), Ok(alpha_index as u8));
+    }
+    // a^15 = a^0
+    assert_eq!(example.index_to_value(15), 1);
+    assert!(example._value_to_index(0).is_err());
+    assert!(example._value_to_index(16).is_err());
+}
+
+#[test]
+/// negative exponents wrap around the order of the field, which doesn't divide 255 for m = 3
+fn test_alpha_power_m_3() {
+    let example = GaloisFields::_new(
+        3,
+        Polynomial::new(vec![Indeterminate::new(1, 1), Indeterminate::new(1, 0)]),
+    );
+    // the values of a^0 to a^6
+    let correct_values: [u8; 7] = [0b001, 0b010, 0b100, 0b011, 0b110, 0b111, 0b101];
+    for (alpha_index, value) in correct_values.iter().enumerate() {
+        assert_eq!(example.alpha_power(alpha_index as i32), *value);
+        assert_eq!(example.alpha_power(alpha_index as i32 - 7), *value);
+    }
+    assert_eq!(example.multiply_values(example.alpha_power(-1), 0b010), 1);
+}
+
+#[test]
+/// multiplying and dividing with the tables is the same as
+/// multiplying the polynomials modulo x^8+x^4+x^3+x^2+1
+fn test_multiply_divide_values() {
+    let galois_field = GaloisFields::_new(
+        8,
+        Polynomial::new(vec![
+            Indeterminate::new(1, 4),
+            Indeterminate::new(1, 3),
+            Indeterminate::new(1, 2),
+            Indeterminate::new(1, 0),
+        ]),
+    );
+    let carryless_multiply = |lhs: u8, rhs: u8| -> u8 {
+        let mut product: u16 = 0;
+        for bit in 0..8 {
+            if rhs & (1 << bit) != 0 {
+                product ^= (lhs as u16) << bit;
+            }
+        }
+        for bit in (8..16).rev() {
+            if product & (1 << bit) != 0 {
+                product ^= 0x11d << (bit - 8);
+            }
+        }
+        product as u8
     };
-    assert_eq!(example, correct_result);
+    for lhs in 0..=255 {
+        for rhs in 0..=255 {
+            let product: u8 = galois_field.multiply_values(lhs, rhs);
+            assert_eq!(product, carryless_multiply(lhs, rhs));
+            if rhs != 0 {
+                assert_eq!(galois_field.divide_values(product, rhs), lhs);
+            }
+        }
+    }
 }
 
 #[test]
@@ -484,7 +508,7 @@ fn sanity_alpha_conversion() {
     );
     let alpha_index = 120;
     let alpha_value = galois_field.index_to_value(alpha_index);
-    let reconstructed_alpha_index = galois_field.value_to_index(alpha_value).unwrap();
+    let reconstructed_alpha_index = galois_field._value_to_index(alpha_value).unwrap();
     assert_eq!(alpha_index, reconstructed_alpha_index);
 }
 
@@ -501,70 +525,17 @@ fn test_calculate_error_correction() {
             Indeterminate::new(1, 0),
         ]),
     );
-    let Some(correction_polynomial) = galois_field.correction_polynomial(10) else {
-        panic!("test couldn't obtain generator polynomial for 10 error bytes");
-    };
-    let mut data = Polynomial::new(vec![
-        Indeterminate::new(16, 15),
-        Indeterminate::new(32, 14),
-        Indeterminate::new(12, 13),
-        Indeterminate::new(86, 12),
-        Indeterminate::new(97, 11),
-        Indeterminate::new(128, 10),
-        Indeterminate::new(236, 9),
-        Indeterminate::new(17, 8),
-        Indeterminate::new(236, 7),
-        Indeterminate::new(17, 6),
-        Indeterminate::new(236, 5),
-        Indeterminate::new(17, 4),
-        Indeterminate::new(236, 3),
-        Indeterminate::new(17, 2),
-        Indeterminate::new(236, 1),
-        Indeterminate::new(17, 0),
-    ]);
-    data = data * Polynomial::new(vec![Indeterminate::new(1, 10)]);
-    // append missing 0x^n's
-    for index in (0..=9).rev() {
-        data.get_function_mut().push(Indeterminate::new(0, index));
-    }
-    let result =
-        GaloisFields::calculate_error_correction(&data, correction_polynomial, galois_field);
-    let expected_result = Polynomial::new(vec![
-        Indeterminate::new(0, 25),
-        Indeterminate::new(0, 24),
-        Indeterminate::new(0, 23),
-        Indeterminate::new(0, 22),
-        Indeterminate::new(0, 21),
-        Indeterminate::new(0, 20),
-        Indeterminate::new(0, 19),
-        Indeterminate::new(0, 18),
-        Indeterminate::new(0, 17),
-        Indeterminate::new(0, 16),
-        Indeterminate::new(0, 15),
-        Indeterminate::new(0, 14),
-        Indeterminate::new(0, 13),
-        Indeterminate::new(0, 12),
-        Indeterminate::new(0, 11),
-        Indeterminate::new(0, 10),
-        Indeterminate::new(165, 9),
-        Indeterminate::new(36, 8),
-        Indeterminate::new(212, 7),
-        Indeterminate::new(193, 6),
-        Indeterminate::new(237, 5),
-        Indeterminate::new(54, 4),
-        Indeterminate::new(199, 3),
-        Indeterminate::new(135, 2),
-        Indeterminate::new(44, 1),
-        Indeterminate::new(85, 0),
-    ]);
-    println!("results\ncalculated: {result}\nexpected:   {expected_result}");
+    let data: Vec<u8> = vec![
+        16, 32, 12, 86, 97, 128, 236, 17, 236, 17, 236, 17, 236, 17, 236, 17,
+    ];
+    let result: Vec<u8> = galois_field.error_correction_bytes(&data, 10).unwrap();
+    let expected_result: Vec<u8> = vec![165, 36, 212, 193, 237, 54, 199, 135, 44, 85];
+    println!("results\ncalculated: {result:?}\nexpected:   {expected_result:?}");
     assert_eq!(result, expected_result);
 }
 
 #[test]
 fn test_len_correction_polynomial() {
-    // longest error correction polynomial
-    let max_correction = 68;
     let galois_field = GaloisFields::_new(
         8,
         Polynomial::new(vec![
@@ -574,9 +545,184 @@ fn test_len_correction_polynomial() {
             Indeterminate::new(1, 0),
         ]),
     );
+    assert!(galois_field.correction_polynomial(0).is_none());
+    assert!(galois_field
+        .correction_polynomial(MAXGENERATORDEGREE + 1)
+        .is_none());
+    for corr_index in 1..=MAXGENERATORDEGREE {
+        let Some(correction_polynomial) = galois_field.correction_polynomial(corr_index) else {
+            panic!("no generator polynomial for {corr_index} error bytes");
+        };
+        // every degree from the highest down to 0 must be present
+        let function = correction_polynomial.get_function();
+        assert_eq!(function.len(), corr_index as usize + 1);
+        assert_eq!(function[0].get_degree(), corr_index as i16);
+        assert_eq!(function[0].get_coefficient(), 1);
+        for indeterminate in function.iter() {
+            assert_ne!(indeterminate.get_coefficient(), 0);
+        }
+    }
+}
+
+#[test]
+/// generator polynomials from ISO/IEC 18004 annex A (given as alpha indices)
+fn test_correction_polynomial_values() {
+    let galois_field = GaloisFields::_new(
+        8,
+        Polynomial::new(vec![
+            Indeterminate::new(1, 4),
+            Indeterminate::new(1, 3),
+            Indeterminate::new(1, 2),
+            Indeterminate::new(1, 0),
+        ]),
+    );
+    let expected_indices: [Vec<u8>; 4] = [
+        vec![0, 25, 1],
+        vec![0, 87, 229, 146, 149, 238, 102, 21],
+        vec![
+            0, 8, 183, 61, 91, 202, 37, 51, 58, 58, 237, 140, 124, 5, 99, 105,
+        ],
+        vec![
+            0, 41, 173, 145, 152, 216, 31, 179, 182, 50, 48, 110, 86, 239, 96, 222, 125, 42, 173,
+            226, 193, 224, 130, 156, 37, 251, 216, 238, 40, 192, 180,
+        ],
+    ];
+    for indices in expected_indices.iter() {
+        let correction_polynomial = galois_field
+            .correction_polynomial((indices.len() - 1) as u8)
+            .unwrap();
+        let calculated_indices: Vec<u8> = correction_polynomial
+            .get_function()
+            .iter()
+            .map(|indeterminate| {
+                galois_field
+                    ._value_to_index(indeterminate.get_coefficient())
+                    .unwrap()
+            })
+            .collect();
+        assert_eq!(&calculated_indices, indices);
+    }
+}
+
+#[cfg(test)]
+/// the codeword of the error correction example (16 data bytes, 10 error correction bytes)
+fn example_codeword() -> Vec<u8> {
+    vec![
+        16, 32, 12, 86, 97, 128, 236, 17, 236, 17, 236, 17, 236, 17, 236, 17, 165, 36, 212, 193,
+        237, 54, 199, 135, 44, 85,
+    ]
+}
+
+#[test]
+fn test_syndromes() {
+    let galois_field = GaloisFields::_new(
+        8,
+        Polynomial::new(vec![
+            Indeterminate::new(1, 4),
+            Indeterminate::new(1, 3),
+            Indeterminate::new(1, 2),
+            Indeterminate::new(1, 0),
+        ]),
+    );
+    let mut codeword = example_codeword();
+    let syndromes = galois_field.syndromes(&Polynomial::from(codeword.clone()), 10);
+    assert_eq!(syndromes, vec![0; 10]);
+    codeword[3] ^= 0x5a;
+    let syndromes = galois_field.syndromes(&Polynomial::from(codeword), 10);
+    assert!(syndromes.iter().all(|syndrome| *syndrome != 0));
+}
 
-    // results can be ignored if a vector has the worng size the test fails
-    for corr_index in 0..=max_correction {
-        _ = galois_field.correction_polynomial(corr_index);
+#[test]
+fn test_correct_errors() {
+    let galois_field = GaloisFields::_new(
+        8,
+        Polynomial::new(vec![
+            Indeterminate::new(1, 4),
+            Indeterminate::new(1, 3),
+            Indeterminate::new(1, 2),
+            Indeterminate::new(1, 0),
+        ]),
+    );
+    let expected = Polynomial::from(example_codeword());
+    // no errors
+    assert_eq!(
+        galois_field.correct_errors(&expected, 10, &[]),
+        Ok(expected.clone())
+    );
+    // up to 5 errors can be corrected with 10 error correction bytes
+    for positions in [
+        vec![0],
+        vec![25, 2],
+        vec![1, 7, 13, 19, 24],
+        vec![0, 16, 17, 18, 25],
+    ] {
+        let mut codeword = example_codeword();
+        for position in positions.iter() {
+            codeword[*position] ^= 0xa7;
+        }
+        assert_eq!(
+            galois_field.correct_errors(&Polynomial::from(codeword), 10, &[]),
+            Ok(expected.clone()),
+            "errors at {positions:?}"
+        );
+    }
+    // 6 errors are too many
+    let mut codeword = example_codeword();
+    for position in [0, 3, 6, 9, 12, 15] {
+        codeword[position] = 0;
+    }
+    assert_ne!(
+        galois_field.correct_errors(&Polynomial::from(codeword), 10, &[]),
+        Ok(expected.clone())
+    );
+}
+
+#[test]
+fn test_correct_erasures() {
+    let galois_field = GaloisFields::_new(
+        8,
+        Polynomial::new(vec![
+            Indeterminate::new(1, 4),
+            Indeterminate::new(1, 3),
+            Indeterminate::new(1, 2),
+            Indeterminate::new(1, 0),
+        ]),
+    );
+    let expected = Polynomial::from(example_codeword());
+    // 10 erasures can be restored with 10 error correction bytes
+    let mut codeword = example_codeword();
+    let positions: Vec<usize> = (4..14).collect();
+    for position in positions.iter() {
+        codeword[*position] = 0;
+    }
+    // degree of the byte at a position
+    let erasures: Vec<i16> = positions
+        .iter()
+        .map(|position| (codeword.len() - 1 - position) as i16)
+        .collect();
+    assert_eq!(
+        galois_field.correct_errors(&Polynomial::from(codeword), 10, &erasures),
+        Ok(expected.clone())
+    );
+    // 2 erasures and 4 errors
+    let mut codeword = example_codeword();
+    for position in [0, 5, 10, 20, 22, 23] {
+        codeword[position] ^= 0x3c;
     }
+    assert_eq!(
+        galois_field.correct_errors(&Polynomial::from(codeword.clone()), 10, &[25, 20]),
+        Ok(expected.clone())
+    );
+    // erasures of correct bytes don't change anything
+    let codeword = example_codeword();
+    assert_eq!(
+        galois_field.correct_errors(&Polynomial::from(codeword), 10, &[1, 2, 3]),
+        Ok(expected.clone())
+    );
+    // too many erasures
+    let codeword = example_codeword();
+    let erasures: Vec<i16> = (0..11).collect();
+    assert!(galois_field
+        .correct_errors(&Polynomial::from(codeword), 10, &erasures)
+        .is_err());
 }
@@ -1,10 +1,9 @@
 use std::fmt::{self, Display};
-
-use clap::{Parser, Subcommand, ValueEnum};
+use std::ops::RangeInclusive;
 
 /// indicates the level of error correction
 /// desired from the generated QR Code
-#[derive(Debug, Subcommand, Clone, ValueEnum, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ErrorLevel {
     /// approx. 7% correction
     L,
@@ -27,17 +26,180 @@ impl Display for ErrorLevel {
     }
 }
 
+/// character sets that can be selected with an extended channel interpretation (ECI)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EciCharset {
+    /// ISO-8859-1 (Latin-1, western european)
+    Iso8859_1,
+    /// ISO-8859-2 (Latin-2, central european)
+    Iso8859_2,
+    /// ISO-8859-5 (cyrillic)
+    Iso8859_5,
+    /// ISO-8859-7 (greek)
+    Iso8859_7,
+    /// ISO-8859-15 (Latin-9, western european with euro sign)
+    Iso8859_15,
+    /// Windows-1250 (central european)
+    Windows1250,
+    /// Windows-1251 (cyrillic)
+    Windows1251,
+    /// Windows-1252 (western european)
+    Windows1252,
+    /// UTF-8
+    Utf8,
+}
+
+impl Display for EciCharset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EciCharset::Iso8859_1 => write!(f, "iso-8859-1"),
+            EciCharset::Iso8859_2 => write!(f, "iso-8859-2"),
+            EciCharset::Iso8859_5 => write!(f, "iso-8859-5"),
+            EciCharset::Iso8859_7 => write!(f, "iso-8859-7"),
+            EciCharset::Iso8859_15 => write!(f, "iso-8859-15"),
+            EciCharset::Windows1250 => write!(f, "windows-1250"),
+            EciCharset::Windows1251 => write!(f, "windows-1251"),
+            EciCharset::Windows1252 => write!(f, "windows-1252"),
+            EciCharset::Utf8 => write!(f, "utf-8"),
+        }
+    }
+}
+
+/// ways to print qr codes into the terminal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TerminalStyle {
+    /// three colored spaces per element
+    Ansi,
+    /// half block characters in black and white, two rows of elements per line
+    Compact,
+    /// half block characters without colors for terminals with dark text on a light background
+    Text,
+    /// half block characters without colors for terminals with light text on a dark background
+    Inverted,
+}
+
+/// kinds of symbols that can be generated
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SymbolType {
+    /// qr code of version 1 to 40 (21x21 to 177x177 elements)
+    Standard,
+    /// micro qr code of version M1 to M4 (11x11 to 17x17 elements) with a single finder pattern;
+    /// M1 only detects errors, level Q is only available in M4 and level H not at all
+    Micro,
+    /// rectangular micro qr code of version R7x43 to R17x139 (ISO/IEC 23941) with a fixed mask;
+    /// only has the levels M and H, L is raised to M and Q to H
+    Rmqr,
+}
+
+/// appearance of the qr codes written into image files
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageOptions {
+    /// width and height of a single element in pixels (default: 8)
+    pub module_size: u32,
+    /// width of the light border around the qr code in elements
+    /// (default: 4, 2 for micro qr codes)
+    pub quiet_zone: Option<u32>,
+    /// color of the dark elements (svg only)
+    pub foreground: String,
+    /// color of the light elements and the quiet zone (svg only)
+    pub background: String,
+}
+
+impl Default for ImageOptions {
+    fn default() -> Self {
+        ImageOptions {
+            module_size: 8,
+            quiet_zone: None,
+            foreground: String::from("#000000"),
+            background: String::from("#ffffff"),
+        }
+    }
+}
+
+/// restrictions on the version, mask and error correction level of the generated qr codes
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymbolOptions {
+    /// kind of symbol to generate; the version options count the versions of this kind
+    /// (1 to 4 for micro qr codes, 1 to 32 for rMQR codes ordered by height and width)
+    pub symbol_type: SymbolType,
+    /// generate a qr code of exactly this version (1 to 40)
+    pub version: Option<u8>,
+    /// smallest version the qr code may have
+    pub min_version: u8,
+    /// largest version the qr code may have
+    pub max_version: u8,
+    /// apply this mask (0 to 7, 0 to 3 for micro qr codes) instead of the best one
+    pub mask: Option<u8>,
+    /// raise the error correction level as long as the data still fits into the chosen version
+    pub boost_ecl: bool,
+    /// maximum number of qr codes a long text is split into with structured append
+    pub max_symbols: u8,
+}
+
+impl SymbolOptions {
+    /// returns the versions the qr code may have
+    pub fn versions(&self) -> RangeInclusive<u8> {
+        match self.version {
+            Some(version) => version..=version,
+            None => self.min_version..=self.max_version,
+        }
+    }
+}
+
+impl Default for SymbolOptions {
+    fn default() -> Self {
+        SymbolOptions {
+            symbol_type: SymbolType::Standard,
+            version: None,
+            min_version: 1,
+            max_version: 40,
+            mask: None,
+            boost_ecl: false,
+            max_symbols: 16,
+        }
+    }
+}
+
 /// stores the to be encoded text and the
 /// error correction level
-#[derive(Parser, Debug, Clone)]
-#[clap(about, long_about = None)]
-pub struct Settings {
+#[derive(Debug, Clone)]
+pub(crate) struct Settings {
     /// the text to be incoded into the qr code
     pub information: String,
     /// specifies the error correction level of the generated qr code
-    #[clap(default_value_t = ErrorLevel::L)]
     pub error_level: ErrorLevel,
-    /// Print debugging info to the terminal, while generating the code
-    #[arg(short, long)]
+    /// character set the text is written in behind an ECI header
+    pub eci: Option<EciCharset>,
+    /// the text is a GS1 element string and the qr code a GS1 qr code
+    pub gs1: bool,
+    /// application indicator of the industry application (FNC1 in second position)
+    pub fnc1_second: Option<String>,
+    /// restrictions on the version, mask and error correction level
+    pub symbol: SymbolOptions,
+    /// print debugging info to the terminal, while generating the code
     pub debugging: bool,
 }
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            information: String::new(),
+            error_level: ErrorLevel::L,
+            eci: None,
+            gs1: false,
+            fnc1_second: None,
+            symbol: SymbolOptions::default(),
+            debugging: false,
+        }
+    }
+}
+
+#[cfg(test)]
+/// generates the settings of a standard qr code of the text with the given error correction level
+pub(crate) fn test_settings(text: &str, error_level: ErrorLevel) -> Settings {
+    Settings {
+        information: String::from(text),
+        error_level,
+        ..Settings::default()
+    }
+}
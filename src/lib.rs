@@ -0,0 +1,19 @@
+//! converts text into QR codes following norm ISO/IEC CD 18004
+//! and reads them back from PBM and PGM images
+mod error;
+mod galois_field;
+mod input;
+mod micro_qr_code;
+mod polynomials;
+mod qr_code;
+mod render;
+mod rmqr_code;
+mod standard_qr_code;
+
+pub use error::QrError;
+pub use input::{EciCharset, ErrorLevel, ImageOptions, SymbolOptions, SymbolType, TerminalStyle};
+pub use qr_code::{QrCode, QrCodeBuilder};
+pub use standard_qr_code::capacity::{capacity, data_bits};
+pub use standard_qr_code::encoding_modes::EncodingMode;
+pub use standard_qr_code::qr_struct::MaskPenalty;
+pub use standard_qr_code::{read_image_file, DecodedSymbol};
@@ -1,15 +1,136 @@
 /// this program is supposed to be handed a string which gets converted into
 /// a QR code following norm ISO/IEC CD 18004
-mod galois_field;
-mod input;
-mod polynomials;
-mod standard_qr_code;
+mod cli;
+
 use clap::Parser;
-use input::*;
-use standard_qr_code::qr_code;
+use cli::{Arguments, Command};
+use qrcode::{
+    capacity, data_bits, read_image_file, DecodedSymbol, EncodingMode, ErrorLevel, ImageOptions,
+    QrCode, QrCodeBuilder, QrError,
+};
 
 fn main() {
     // get environment variables
-    let settings: Settings = Settings::parse();
-    _ = qr_code(settings.clone());
+    let arguments: Arguments = Arguments::parse();
+    let result = if let Some(Command::Capacity) = arguments.command {
+        print_capacity_table()
+    } else if arguments.read {
+        read_qr_code(&arguments)
+    } else {
+        print_qr_codes(&arguments)
+    };
+    if let Err(error) = result {
+        eprintln!("{error}");
+        std::process::exit(1);
+    }
+}
+
+/// generates the qr codes and prints them (or writes them into files)
+fn print_qr_codes(arguments: &Arguments) -> Result<(), QrError> {
+    let mut builder: QrCodeBuilder = QrCode::builder()
+        .data(arguments.information())
+        .ec_level(arguments.error_level.into())
+        .symbol_options((&arguments.symbol).into())
+        .gs1(arguments.gs1)
+        .debugging(arguments.debugging);
+    if let Some(charset) = arguments.eci {
+        builder = builder.eci(charset.into());
+    }
+    if let Some(application_indicator) = &arguments.fnc1_second {
+        builder = builder.fnc1_second(application_indicator.clone());
+    }
+    // texts that are too long for a single qr code are split up into multiple ones,
+    // unless the size of the qr code is restricted
+    let symbols: Vec<QrCode> =
+        if arguments.symbol.version.is_some() || arguments.symbol.max_version < 40 {
+            vec![builder.build()?]
+        } else {
+            builder.build_sequence()?
+        };
+    let total_symbols: usize = symbols.len();
+    for (index, symbol) in symbols.iter().enumerate() {
+        if total_symbols > 1 {
+            println!("qr code {} of {total_symbols}", index + 1);
+        }
+        if let Some(path) = &arguments.output {
+            let path: String = numbered_path(path, index, total_symbols);
+            write_image_file(symbol, &(&arguments.image).into(), &path)?;
+        } else if !arguments.debugging {
+            println!("{}", symbol.to_terminal(arguments.terminal.into()));
+        }
+    }
+    Ok(())
+}
+
+/// writes the qr code into a file of the image format given by the extension of the path
+fn write_image_file(symbol: &QrCode, options: &ImageOptions, path: &str) -> Result<(), QrError> {
+    let extension: String = path
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_lowercase())
+        .unwrap_or_default();
+    let contents: Vec<u8> = match extension.as_str() {
+        "svg" => symbol.to_svg(options).into_bytes(),
+        "png" => symbol.to_png(options),
+        _ => return Err(QrError::UnknownImageFormat(String::from(path))),
+    };
+    std::fs::write(path, contents)
+        .map_err(|error| QrError::Io(format!("couldn't write \"{path}\": {error}")))
+}
+
+/// inserts the number of the symbol in front of the file extension,
+/// if the text is split up into several symbols
+fn numbered_path(path: &str, index: usize, total_symbols: usize) -> String {
+    if total_symbols == 1 {
+        return String::from(path);
+    }
+    let number: usize = index + 1;
+    match path.rsplit_once('.') {
+        Some((stem, extension)) if !stem.is_empty() && !extension.contains('/') => {
+            format!("{stem}-{number}.{extension}")
+        }
+        _ => format!("{path}-{number}"),
+    }
+}
+
+/// reads the qr code in the PBM or PGM image whose path is given as information
+/// and prints its content
+fn read_qr_code(arguments: &Arguments) -> Result<(), QrError> {
+    if arguments.debugging {
+        println!("{arguments:?}");
+    }
+    let decoded: DecodedSymbol = read_image_file(arguments.information())?;
+    if arguments.debugging {
+        println!("decoded qr code: {decoded:?}");
+    }
+    println!("{}", decoded.text()?);
+    Ok(())
+}
+
+/// prints how many characters of every mode fit into every version and error correction level
+fn print_capacity_table() -> Result<(), QrError> {
+    let modes: [EncodingMode; 4] = [
+        EncodingMode::Numeric,
+        EncodingMode::Alphanumeric,
+        EncodingMode::Byte,
+        EncodingMode::Kanji,
+    ];
+    println!("version  level  data bits  numeric  alphanumeric  byte  kanji");
+    for version in 1..=40 {
+        for error_level in [ErrorLevel::L, ErrorLevel::M, ErrorLevel::Q, ErrorLevel::H] {
+            let mut characters: Vec<usize> = vec![];
+            for mode in modes {
+                characters.push(capacity(version, error_level, mode)?);
+            }
+            println!(
+                "{version:>7}  {:>5}  {:>9}  {:>7}  {:>12}  {:>4}  {:>5}",
+                format!("{error_level:?}"),
+                data_bits(version, error_level)?,
+                characters[0],
+                characters[1],
+                characters[2],
+                characters[3]
+            );
+        }
+    }
+    Ok(())
 }
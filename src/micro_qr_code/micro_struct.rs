@@ -0,0 +1,617 @@
+use std::fmt::{Display, Formatter, Result};
+use std::ops::RangeInclusive;
+
+use crate::error::QrError;
+use crate::galois_field::GaloisFields;
+use crate::input::{EciCharset, ErrorLevel, Settings};
+use crate::micro_qr_code::version_constants::{
+    micro_character_count_bits, micro_header_bits, micro_mode_indicator, micro_symbol_info,
+    micro_terminator_bits, MicroSymbolInfo, MAXMICROVERSION,
+};
+use crate::polynomials::{Indeterminate, Polynomial};
+use crate::standard_qr_code::bch::bch_15_5;
+use crate::standard_qr_code::encoding_modes::alphanumeric_value;
+use crate::standard_qr_code::qr_struct::{
+    mask_condition, SymbolStatus, BLACK, BRIGHTWHITE, COLORSTOP,
+};
+use crate::standard_qr_code::segmentation::{segment_text_with_headers, Segment, TextEncoding};
+
+/// the standard qr code masks that are used as micro qr code masks 0 to 3
+const MICROMASKS: [u8; 4] = [1, 4, 6, 7];
+/// mask that is applied onto the format information
+const MICROFORMATMASK: u16 = 0x4445;
+/// width of the quiet zone around a micro qr code in elements
+pub const MICROQUIETZONE: usize = 2;
+
+/// returns the 15 bit format information of the symbol number and the mask
+pub fn micro_format_information(symbol_number: u8, mask: u8) -> u16 {
+    // the same BCH(15,5) code as the format information of standard qr codes
+    bch_15_5((symbol_number << 2) | mask) ^ MICROFORMATMASK
+}
+
+/// returns the amount of bits all segments take up in the given version
+fn micro_segments_bits(segments: &[Segment], version: u8) -> usize {
+    segments
+        .iter()
+        .map(|segment| {
+            micro_header_bits(segment.mode, version).expect("mode isn't available in the version")
+                + segment.mode.data_bits(segment.character_count())
+        })
+        .sum()
+}
+
+/// appends the lowest size bits of value to the bit stream
+fn push_bits(bit_stream: &mut Vec<bool>, value: u16, size: u8) {
+    for bit in (0..size).rev() {
+        bit_stream.push((value >> bit) & 1 == 1);
+    }
+}
+
+/// encompasses all data required to generate a micro qr code
+#[derive(Debug, Clone)]
+pub struct MicroQRData {
+    /// the elements without quiet zone indexed with [x][y]
+    output_data: Vec<Vec<SymbolStatus>>,
+    /// whether the element belongs to a function pattern or the format information
+    is_function: Vec<Vec<bool>>,
+    version: u8,
+    symbol_info: MicroSymbolInfo,
+    segments: Vec<Segment>,
+    settings: Settings,
+    /// the mask that was applied (0 until the mask is chosen)
+    mask: u8,
+}
+
+impl MicroQRData {
+    /// selects the smallest version (M1 to M4) the text of the settings fits into;
+    /// fails for options micro qr codes don't have (ECI, FNC1, level H)
+    pub(crate) fn new(mut input: Settings) -> std::result::Result<MicroQRData, QrError> {
+        if input.eci.is_some() {
+            return Err(QrError::UnsupportedOption(String::from(
+                "micro qr codes can't contain an ECI header",
+            )));
+        }
+        if input.gs1 || input.fnc1_second.is_some() {
+            return Err(QrError::UnsupportedOption(String::from(
+                "micro qr codes can't be marked with FNC1",
+            )));
+        }
+        if let Some(mask) = input.symbol.mask {
+            if mask as usize >= MICROMASKS.len() {
+                return Err(QrError::InvalidMask(mask));
+            }
+        }
+        let versions: RangeInclusive<u8> = match input.symbol.version {
+            Some(version) => version..=version,
+            None if input.symbol.min_version > input.symbol.max_version => {
+                return Err(QrError::InvalidVersionRange {
+                    min_version: input.symbol.min_version,
+                    max_version: input.symbol.max_version,
+                })
+            }
+            // the default largest version belongs to standard qr codes
+            None => input.symbol.min_version..=input.symbol.max_version.min(MAXMICROVERSION),
+        };
+        for version in [*versions.start(), *versions.end()] {
+            if !(1..=MAXMICROVERSION).contains(&version) {
+                return Err(QrError::InvalidVersion(version));
+            }
+        }
+        // without ECI the text is written as UTF-8, just like in standard qr codes
+        let encoding: TextEncoding = TextEncoding::new(EciCharset::Utf8, false);
+        let mut last_error: Option<QrError> = None;
+        let mut selected: Option<(u8, MicroSymbolInfo, Vec<Segment>)> = None;
+        for version in versions.clone() {
+            let Some(symbol_info) = micro_symbol_info(version, input.error_level) else {
+                continue;
+            };
+            // smaller versions don't have all modes, so a text that can't be written
+            // in this version might fit into the next one
+            let segments: Vec<Segment> =
+                match segment_text_with_headers(&input.information, encoding, |mode| {
+                    micro_header_bits(mode, version)
+                }) {
+                    Ok(segments) => segments,
+                    // every character can be written in byte mode, so only the mode is missing
+                    Err(QrError::InvalidCharacter { character, .. }) => {
+                        let mode: &str = match alphanumeric_value(character) {
+                            Some(_) => "alphanumeric",
+                            None => "byte",
+                        };
+                        last_error = Some(QrError::UnsupportedOption(format!(
+                            "micro qr codes of version M{version} don't have the {mode} mode \
+                            needed for '{character}'"
+                        )));
+                        continue;
+                    }
+                    Err(error) => {
+                        last_error = Some(error);
+                        continue;
+                    }
+                };
+            let needed_bits: usize = micro_segments_bits(&segments, version);
+            if needed_bits <= symbol_info.num_data_bits as usize {
+                selected = Some((version, symbol_info, segments));
+                break;
+            }
+            last_error = Some(QrError::DataTooLong {
+                needed_bits,
+                available_bits: symbol_info.num_data_bits as usize,
+            });
+        }
+        let Some((version, mut symbol_info, segments)) = selected else {
+            return Err(last_error.unwrap_or(QrError::UnsupportedOption(format!(
+                "micro qr codes of version M{} to M{} don't have error correction level {}",
+                versions.start(),
+                versions.end(),
+                input.error_level
+            ))));
+        };
+        // raise the error correction level as long as the text still fits
+        if input.symbol.boost_ecl {
+            let needed_bits: usize = micro_segments_bits(&segments, version);
+            for error_level in [ErrorLevel::M, ErrorLevel::Q] {
+                match micro_symbol_info(version, error_level) {
+                    Some(info)
+                        if info.symbol_number > symbol_info.symbol_number
+                            && needed_bits <= info.num_data_bits as usize =>
+                    {
+                        symbol_info = info;
+                        input.error_level = error_level;
+                    }
+                    _ => (),
+                }
+            }
+        }
+        let width: usize = 2 * version as usize + 9;
+        Ok(MicroQRData {
+            output_data: vec![vec![SymbolStatus::Uninitialised; width]; width],
+            is_function: vec![vec![false; width]; width],
+            version,
+            symbol_info,
+            segments,
+            settings: input,
+            mask: 0,
+        })
+    }
+
+    /// returns the version (1 to 4 for M1 to M4)
+    pub fn get_version(&self) -> u8 {
+        self.version
+    }
+
+    /// returns the mask that was applied (0 to 3)
+    pub fn get_mask(&self) -> u8 {
+        self.mask
+    }
+
+    /// returns the number of elements per row and column (without quiet zone)
+    pub fn get_width(&self) -> usize {
+        self.output_data.len()
+    }
+
+    pub(crate) fn get_settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// marks the element as part of a function pattern with the given color
+    fn set_function(&mut self, x: usize, y: usize, is_dark: bool) {
+        self.output_data[x][y] = if is_dark {
+            SymbolStatus::LogicalTrue
+        } else {
+            SymbolStatus::LogicalFalse
+        };
+        self.is_function[x][y] = true;
+    }
+
+    /// draws the finder pattern in the top left corner with its separator, the timing patterns
+    /// along the top and left edge and reserves the elements for the format information
+    pub fn draw_function_patterns(&mut self) {
+        let width: usize = self.get_width();
+        // finder pattern; the ring at a distance of 2 elements from the centre is white
+        for x in 0..7_usize {
+            for y in 0..7_usize {
+                let distance: usize = x.abs_diff(3).max(y.abs_diff(3));
+                self.set_function(x, y, distance != 2);
+            }
+        }
+        // separator below and right of the finder pattern
+        for index in 0..8 {
+            self.set_function(index, 7, false);
+            self.set_function(7, index, false);
+        }
+        // timing patterns, starting with a black element at the finder pattern
+        for index in 8..width {
+            self.set_function(index, 0, index % 2 == 0);
+            self.set_function(0, index, index % 2 == 0);
+        }
+        // format information below and right of the separator
+        for index in 1..=8 {
+            self.set_function(index, 8, false);
+            self.set_function(8, index, false);
+        }
+    }
+
+    /// returns the data bits (including terminator and padding) and the data codewords;
+    /// the last codeword of M1 and M3 only holds 4 bits, which are stored in the high nibble
+    fn data_codewords(&self) -> (Vec<bool>, Vec<u8>) {
+        let mut bit_stream: Vec<bool> = vec![];
+        for segment in self.segments.iter() {
+            push_bits(
+                &mut bit_stream,
+                micro_mode_indicator(segment.mode) as u16,
+                self.version - 1,
+            );
+            push_bits(
+                &mut bit_stream,
+                segment.character_count() as u16,
+                micro_character_count_bits(segment.mode, self.version)
+                    .expect("mode isn't available in the version"),
+            );
+            for (value, size) in segment.value_groups() {
+                push_bits(&mut bit_stream, value, size);
+            }
+        }
+        let capacity: usize = self.symbol_info.num_data_bits as usize;
+        // the terminator is shortened if the data almost fills the symbol
+        let terminator_bits: usize =
+            (micro_terminator_bits(self.version) as usize).min(capacity - bit_stream.len());
+        bit_stream.resize(bit_stream.len() + terminator_bits, false);
+        // fill up the last codeword with zeroes
+        bit_stream.resize(bit_stream.len().next_multiple_of(8).min(capacity), false);
+        // alternating pad codewords, a remaining half codeword stays zero
+        let mut pad_codewords = [0xec, 0x11].iter().cycle();
+        while bit_stream.len() + 8 <= capacity {
+            push_bits(&mut bit_stream, *pad_codewords.next().unwrap(), 8);
+        }
+        bit_stream.resize(capacity, false);
+        let codewords: Vec<u8> = bit_stream
+            .chunks(8)
+            .map(|bits| {
+                bits.iter().enumerate().fold(0, |codeword, (index, bit)| {
+                    codeword | ((*bit as u8) << (7 - index))
+                })
+            })
+            .collect();
+        (bit_stream, codewords)
+    }
+
+    /// returns the coordinates of all data elements in the order the data is written into them:
+    /// two columns wide from the bottom right corner upwards, then downwards and so on
+    fn data_module_positions(&self) -> Vec<(usize, usize)> {
+        let width: usize = self.get_width();
+        let mut positions: Vec<(usize, usize)> = vec![];
+        let mut is_upwards: bool = true;
+        // the leftmost column only holds the timing pattern
+        for right_column in (1..width).rev().step_by(2) {
+            for step in 0..width {
+                let y: usize = if is_upwards { width - 1 - step } else { step };
+                for x in [right_column, right_column - 1] {
+                    if !self.is_function[x][y] {
+                        positions.push((x, y));
+                    }
+                }
+            }
+            is_upwards = !is_upwards;
+        }
+        positions
+    }
+
+    /// encodes the text, calculates the error correction bytes and writes both into the symbol
+    pub fn read_and_write(&mut self) -> std::result::Result<(), QrError> {
+        let (mut bit_stream, data_codewords) = self.data_codewords();
+        let galois_field = GaloisFields::_new(
+            8,
+            Polynomial::new(vec![
+                Indeterminate::new(1, 4),
+                Indeterminate::new(1, 3),
+                Indeterminate::new(1, 2),
+                Indeterminate::new(1, 0),
+            ]),
+        );
+        // a half codeword is treated as a full codeword with 4 zero bits
+        let error_codewords: Vec<u8> = galois_field
+            .error_correction_bytes(&data_codewords, self.symbol_info.num_error_bytes)?;
+        if self.settings.debugging {
+            println!(
+                "micro qr code M{}-{} (symbol number {})\ndata codewords: {data_codewords:02x?}\nerror correction codewords: {error_codewords:02x?}",
+                self.version, self.settings.error_level, self.symbol_info.symbol_number
+            );
+        }
+        for codeword in error_codewords {
+            push_bits(&mut bit_stream, codeword as u16, 8);
+        }
+        let positions: Vec<(usize, usize)> = self.data_module_positions();
+        assert_eq!(positions.len(), bit_stream.len());
+        for ((x, y), bit) in positions.into_iter().zip(bit_stream) {
+            self.output_data[x][y] = if bit {
+                SymbolStatus::LogicalTrue
+            } else {
+                SymbolStatus::LogicalFalse
+            };
+        }
+        Ok(())
+    }
+
+    /// inverts the data elements selected by the mask; applying it twice restores the data
+    fn apply_mask(&mut self, mask: u8) {
+        let width: usize = self.get_width();
+        for x in 0..width {
+            for y in 0..width {
+                if !self.is_function[x][y] && mask_condition(MICROMASKS[mask as usize], y, x) {
+                    self.output_data[x][y] = self.output_data[x][y] ^ SymbolStatus::LogicalTrue;
+                }
+            }
+        }
+    }
+
+    /// evaluates the masked symbol by the black elements along the right and the lower edge
+    /// (without the timing patterns); a higher score is better
+    fn mask_score(&self) -> u32 {
+        let width: usize = self.get_width();
+        let count_dark = |positions: &mut dyn Iterator<Item = (usize, usize)>| -> u32 {
+            positions
+                .filter(|(x, y)| self.output_data[*x][*y] == SymbolStatus::LogicalTrue)
+                .count() as u32
+        };
+        let right_edge: u32 = count_dark(&mut (1..width).map(|y| (width - 1, y)));
+        let lower_edge: u32 = count_dark(&mut (1..width).map(|x| (x, width - 1)));
+        if right_edge <= lower_edge {
+            right_edge * 16 + lower_edge
+        } else {
+            lower_edge * 16 + right_edge
+        }
+    }
+
+    /// applies the mask with the highest score (or the one given in the settings)
+    /// and writes the format information
+    pub fn masking_format_information(&mut self) {
+        let masks: Vec<u8> = match self.settings.symbol.mask {
+            Some(mask) => vec![mask],
+            None => (0..MICROMASKS.len() as u8).collect(),
+        };
+        let mut best_mask: u8 = masks[0];
+        let mut best_score: Option<u32> = None;
+        for mask in masks {
+            self.apply_mask(mask);
+            let score: u32 = self.mask_score();
+            self.apply_mask(mask);
+            if self.settings.debugging {
+                println!("mask {mask} (score: {score})");
+            }
+            if best_score.is_none_or(|best_score| score > best_score) {
+                best_mask = mask;
+                best_score = Some(score);
+            }
+        }
+        self.apply_mask(best_mask);
+        self.mask = best_mask;
+        // bit 14 is written next to the left edge, bit 0 next to the top edge
+        let format_information: u16 =
+            micro_format_information(self.symbol_info.symbol_number, best_mask);
+        for index in 1..=8 {
+            let is_dark: bool = (format_information >> (15 - index)) & 1 == 1;
+            self.set_function(index, 8, is_dark);
+        }
+        for index in 1..=7 {
+            let is_dark: bool = (format_information >> (index - 1)) & 1 == 1;
+            self.set_function(8, index, is_dark);
+        }
+        if self.settings.debugging {
+            println!("after applying the mask and format information");
+            print!("{self}");
+        }
+    }
+
+    /// returns whether the elements of the micro qr code (without quiet zone)
+    /// are black, indexed with [x][y]
+    pub fn get_modules(&self) -> Vec<Vec<bool>> {
+        self.output_data
+            .iter()
+            .map(|column| {
+                column
+                    .iter()
+                    .map(|element| *element == SymbolStatus::LogicalTrue)
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl Display for MicroQRData {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let width: usize = self.get_width() + 2 * MICROQUIETZONE;
+        for y in 0..width {
+            for x in 0..width {
+                let is_dark: bool = x >= MICROQUIETZONE
+                    && y >= MICROQUIETZONE
+                    && x - MICROQUIETZONE < self.get_width()
+                    && y - MICROQUIETZONE < self.get_width()
+                    && self.output_data[x - MICROQUIETZONE][y - MICROQUIETZONE]
+                        == SymbolStatus::LogicalTrue;
+                let color: &str = if is_dark { BLACK } else { BRIGHTWHITE };
+                write!(f, "{color}   {COLORSTOP}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+mod tests {
+    #[cfg(test)]
+    /// reads the data and error correction codewords back from the finished symbol
+    fn read_codewords(micro_qrdata: &super::MicroQRData) -> Vec<u8> {
+        use crate::standard_qr_code::qr_struct::SymbolStatus;
+
+        let mut masked: super::MicroQRData = micro_qrdata.clone();
+        masked.apply_mask(micro_qrdata.get_mask());
+        let bits: Vec<bool> = masked
+            .data_module_positions()
+            .into_iter()
+            .map(|(x, y)| masked.output_data[x][y] == SymbolStatus::LogicalTrue)
+            .collect();
+        let num_data_bits: usize = micro_qrdata.symbol_info.num_data_bits as usize;
+        // the half codeword of M1 and M3 is read as the high nibble of a full codeword
+        let mut codewords: Vec<u8> = vec![];
+        for chunk in bits[..num_data_bits]
+            .chunks(8)
+            .chain(bits[num_data_bits..].chunks(8))
+        {
+            let codeword: u8 = chunk.iter().enumerate().fold(0, |codeword, (index, bit)| {
+                codeword | ((*bit as u8) << (7 - index))
+            });
+            codewords.push(codeword);
+        }
+        codewords
+    }
+
+    #[cfg(test)]
+    /// generates the micro qr code of the text with the given error correction level
+    fn micro_settings(text: &str, error_level: crate::input::ErrorLevel) -> crate::input::Settings {
+        use crate::input::{test_settings, Settings, SymbolType};
+
+        let mut settings: Settings = test_settings(text, error_level);
+        settings.symbol.symbol_type = SymbolType::Micro;
+        settings
+    }
+
+    #[test]
+    /// example of ISO/IEC 18004 annex I.3
+    fn micro_codewords() {
+        use super::MicroQRData;
+        use crate::input::ErrorLevel;
+        use crate::micro_qr_code::micro_qr_code;
+
+        let micro_qrdata: MicroQRData =
+            micro_qr_code(micro_settings("01234567", ErrorLevel::L)).unwrap();
+        assert_eq!(micro_qrdata.get_version(), 2);
+        assert_eq!(micro_qrdata.get_width(), 13);
+        assert_eq!(
+            read_codewords(&micro_qrdata),
+            vec![0x40, 0x18, 0xac, 0xc3, 0x00, 0x86, 0x0d, 0x22, 0xae, 0x30]
+        );
+        // M1 ends with a half codeword: 3 bits character count and 17 bits for 5 digits
+        let micro_qrdata: MicroQRData =
+            micro_qr_code(micro_settings("12345", ErrorLevel::L)).unwrap();
+        assert_eq!(micro_qrdata.get_version(), 1);
+        let (bit_stream, codewords) = micro_qrdata.data_codewords();
+        assert_eq!(bit_stream.len(), 20);
+        assert_eq!(codewords, vec![0b1010_0011, 0b1101_1010, 0b1101_0000]);
+        assert_eq!(read_codewords(&micro_qrdata)[..3], codewords);
+        // M3 pads its last half codeword with zeroes
+        let micro_qrdata: MicroQRData =
+            micro_qr_code(micro_settings("hello", ErrorLevel::M)).unwrap();
+        assert_eq!(micro_qrdata.get_version(), 3);
+        let (_, codewords) = micro_qrdata.data_codewords();
+        assert_eq!(codewords.len(), 9);
+        assert_eq!(codewords[7..], [0xec, 0x00]);
+    }
+
+    #[test]
+    fn micro_version_selection() {
+        use super::MicroQRData;
+        use crate::error::QrError;
+        use crate::input::{EciCharset, ErrorLevel};
+
+        let version = |text: &str, error_level: ErrorLevel| {
+            MicroQRData::new(micro_settings(text, error_level)).map(|data| data.version)
+        };
+        assert_eq!(version("", ErrorLevel::L), Ok(1));
+        assert_eq!(version("123456", ErrorLevel::L), Ok(2));
+        // M1 only knows numeric mode and M2 has no byte mode
+        assert_eq!(version("HELLO", ErrorLevel::L), Ok(2));
+        assert_eq!(version("hello", ErrorLevel::L), Ok(3));
+        assert_eq!(version("1", ErrorLevel::M), Ok(2));
+        assert_eq!(version("1", ErrorLevel::Q), Ok(4));
+        assert!(matches!(
+            version("H", ErrorLevel::H),
+            Err(QrError::UnsupportedOption(_))
+        ));
+        // a forced version names the mode it lacks
+        let mut settings = micro_settings("a", ErrorLevel::L);
+        settings.symbol.version = Some(1);
+        assert_eq!(
+            MicroQRData::new(settings.clone()).map(|data| data.version),
+            Err(QrError::UnsupportedOption(String::from(
+                "micro qr codes of version M1 don't have the byte mode needed for 'a'"
+            )))
+        );
+        settings.information = String::from("A");
+        assert_eq!(
+            MicroQRData::new(settings.clone()).map(|data| data.version),
+            Err(QrError::UnsupportedOption(String::from(
+                "micro qr codes of version M1 don't have the alphanumeric mode needed for 'A'"
+            )))
+        );
+        settings.symbol.version = Some(2);
+        settings.information = String::from("Ab");
+        assert_eq!(
+            MicroQRData::new(settings).map(|data| data.version),
+            Err(QrError::UnsupportedOption(String::from(
+                "micro qr codes of version M2 don't have the byte mode needed for 'b'"
+            )))
+        );
+        assert_eq!(
+            version(&"1".repeat(36), ErrorLevel::L),
+            Err(QrError::DataTooLong {
+                needed_bits: 129,
+                available_bits: 128
+            })
+        );
+        let mut settings = micro_settings("1", ErrorLevel::L);
+        settings.symbol.version = Some(5);
+        assert_eq!(
+            MicroQRData::new(settings.clone()).map(|data| data.version),
+            Err(QrError::InvalidVersion(5))
+        );
+        settings.symbol.version = Some(3);
+        settings.symbol.boost_ecl = true;
+        let micro_qrdata: MicroQRData = MicroQRData::new(settings.clone()).unwrap();
+        assert_eq!(micro_qrdata.version, 3);
+        assert_eq!(micro_qrdata.settings.error_level, ErrorLevel::M);
+        settings.symbol.mask = Some(4);
+        assert_eq!(
+            MicroQRData::new(settings.clone()).map(|data| data.version),
+            Err(QrError::InvalidMask(4))
+        );
+        settings.symbol.mask = None;
+        settings.eci = Some(EciCharset::Iso8859_1);
+        assert!(matches!(
+            MicroQRData::new(settings),
+            Err(QrError::UnsupportedOption(_))
+        ));
+    }
+
+    #[test]
+    fn micro_function_patterns() {
+        use super::{micro_format_information, MicroQRData};
+        use crate::input::ErrorLevel;
+        use crate::micro_qr_code::micro_qr_code;
+
+        assert_eq!(micro_format_information(0, 0), 0x4445);
+        assert_eq!(micro_format_information(7, 3), 0x3bba);
+        let mut settings = micro_settings("MICRO", ErrorLevel::M);
+        settings.symbol.mask = Some(2);
+        let micro_qrdata: MicroQRData = micro_qr_code(settings).unwrap();
+        assert_eq!(micro_qrdata.get_mask(), 2);
+        let modules: Vec<Vec<bool>> = micro_qrdata.get_modules();
+        // finder pattern, separator and timing pattern along the top edge
+        let top_row: Vec<bool> = modules.iter().map(|column| column[0]).collect();
+        assert_eq!(
+            top_row,
+            vec![true, true, true, true, true, true, true, false, true, false, true, false, true]
+        );
+        assert!(!modules[1][1] && modules[2][2]);
+        // M2-M (symbol number 2) with mask 2
+        let format_information: u16 = micro_format_information(2, 2);
+        let mut read_format: u16 = 0;
+        for column in modules[1..=8].iter() {
+            read_format = (read_format << 1) | column[8] as u16;
+        }
+        for y in (1..=7).rev() {
+            read_format = (read_format << 1) | modules[8][y] as u16;
+        }
+        assert_eq!(read_format, format_information);
+    }
+}
@@ -0,0 +1,21 @@
+use crate::error::QrError;
+use crate::input::Settings;
+use crate::micro_qr_code::micro_struct::MicroQRData;
+pub mod micro_struct;
+mod version_constants;
+
+/// generates the micro qr code for the text of the settings; micro qr codes can't be
+/// split up into multiple ones, so texts that are too long for M4 fail
+pub(crate) fn micro_qr_code(input: Settings) -> Result<MicroQRData, QrError> {
+    if input.debugging {
+        println!("{input:?}");
+    }
+    let mut micro_qrdata: MicroQRData = MicroQRData::new(input)?;
+    // draw the finder pattern, the timing patterns and reserve the format information
+    micro_qrdata.draw_function_patterns();
+    // write the data and the error correction codewords into the remaining elements
+    micro_qrdata.read_and_write()?;
+    // apply the mask with the best score and write the format information
+    micro_qrdata.masking_format_information();
+    Ok(micro_qrdata)
+}
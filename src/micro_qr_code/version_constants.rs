@@ -0,0 +1,119 @@
+use crate::input::ErrorLevel;
+use crate::standard_qr_code::encoding_modes::EncodingMode;
+
+/// highest version of a micro qr code (M4)
+pub const MAXMICROVERSION: u8 = 4;
+
+/// describes a combination of version and error correction level of a micro qr code
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MicroSymbolInfo {
+    /// number of the combination, which is written into the format information
+    pub symbol_number: u8,
+    /// number of data bits; M1 and M3 end with a data codeword of only 4 bits
+    pub num_data_bits: u16,
+    /// number of error correction bytes (M1 can only detect errors with them)
+    pub num_error_bytes: u8,
+}
+
+impl MicroSymbolInfo {
+    pub fn new(symbol_number: u8, num_data_bits: u16, num_error_bytes: u8) -> MicroSymbolInfo {
+        MicroSymbolInfo {
+            symbol_number,
+            num_data_bits,
+            num_error_bytes,
+        }
+    }
+}
+
+/// returns the symbol information of the version and error correction level,
+/// or None if the version doesn't have that level
+pub fn micro_symbol_info(version: u8, error_level: ErrorLevel) -> Option<MicroSymbolInfo> {
+    match (version, error_level) {
+        (1, ErrorLevel::L) => Some(MicroSymbolInfo::new(0, 20, 2)),
+        (2, ErrorLevel::L) => Some(MicroSymbolInfo::new(1, 40, 5)),
+        (2, ErrorLevel::M) => Some(MicroSymbolInfo::new(2, 32, 6)),
+        (3, ErrorLevel::L) => Some(MicroSymbolInfo::new(3, 84, 6)),
+        (3, ErrorLevel::M) => Some(MicroSymbolInfo::new(4, 68, 8)),
+        (4, ErrorLevel::L) => Some(MicroSymbolInfo::new(5, 128, 8)),
+        (4, ErrorLevel::M) => Some(MicroSymbolInfo::new(6, 112, 10)),
+        (4, ErrorLevel::Q) => Some(MicroSymbolInfo::new(7, 80, 14)),
+        _ => None,
+    }
+}
+
+// only used in tests
+/// returns the number of codewords (data and error correction) of a version
+pub fn _micro_total_codewords(version: u8) -> u8 {
+    match version {
+        1 => 5,
+        2 => 10,
+        3 => 17,
+        4 => 24,
+        _ => panic!("invalid micro qr code version {version}"),
+    }
+}
+
+/// returns the mode indicator, which is version - 1 bits long (M1 has none)
+pub fn micro_mode_indicator(mode: EncodingMode) -> u8 {
+    match mode {
+        EncodingMode::Numeric => 0b000,
+        EncodingMode::Alphanumeric => 0b001,
+        EncodingMode::Byte => 0b010,
+        EncodingMode::Kanji => 0b011,
+        _ => panic!("mode {mode:?} doesn't exist in micro qr codes"),
+    }
+}
+
+/// returns the length of the character count indicator in bits,
+/// or None if the mode isn't available in the version
+pub fn micro_character_count_bits(mode: EncodingMode, version: u8) -> Option<u8> {
+    match (mode, version) {
+        (EncodingMode::Numeric, 1..=4) => Some(version + 2),
+        (EncodingMode::Alphanumeric, 2..=4) => Some(version + 1),
+        (EncodingMode::Byte, 3..=4) => Some(version + 1),
+        (EncodingMode::Kanji, 3..=4) => Some(version),
+        _ => None,
+    }
+}
+
+/// returns the length of the mode indicator and the character count indicator of a segment,
+/// or None if the mode isn't available in the version
+pub fn micro_header_bits(mode: EncodingMode, version: u8) -> Option<usize> {
+    micro_character_count_bits(mode, version).map(|bits| (version - 1 + bits) as usize)
+}
+
+/// returns the length of the terminator in bits
+pub fn micro_terminator_bits(version: u8) -> u8 {
+    2 * version + 1
+}
+
+#[test]
+#[cfg(test)]
+fn sanitycheck_micro_symbol_info() {
+    for version in 1..=MAXMICROVERSION {
+        let width: u16 = 2 * version as u16 + 9;
+        // finder pattern with separator, timing patterns and format information
+        let function_modules: u16 = 64 + 2 * (width - 8) + 15;
+        let mut symbol_numbers: Vec<u8> = vec![];
+        for error_level in [ErrorLevel::L, ErrorLevel::M, ErrorLevel::Q, ErrorLevel::H] {
+            let Some(info) = micro_symbol_info(version, error_level) else {
+                continue;
+            };
+            symbol_numbers.push(info.symbol_number);
+            // all codewords fill up the data modules, the last data codeword of
+            // M1 and M3 only has 4 bits
+            let data_codewords: u16 = info.num_data_bits.div_ceil(8);
+            assert_eq!(
+                data_codewords + info.num_error_bytes as u16,
+                _micro_total_codewords(version) as u16
+            );
+            assert_eq!(
+                info.num_data_bits + info.num_error_bytes as u16 * 8,
+                width * width - function_modules
+            );
+            assert_eq!(info.num_data_bits % 8 == 4, version == 1 || version == 3);
+        }
+        assert!(!symbol_numbers.is_empty());
+        assert!(micro_symbol_info(version, ErrorLevel::H).is_none());
+    }
+}
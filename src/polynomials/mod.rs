@@ -163,40 +163,6 @@ impl Polynomial {
         work_polynomial.reduce();
         work_polynomial
     }
-
-    pub fn galois_mul_x1(&self, mod_fx: Polynomial, m: u8) -> Polynomial {
-        let x1 = Polynomial::new(vec![Indeterminate::new(1, 1)]);
-        let mut result = self.clone() * x1;
-        let mut is_overflowing = false;
-        // remove
-        for indeterminate in result.get_function_mut().iter_mut() {
-            if indeterminate.get_degree() == m.into() {
-                indeterminate.coefficient = 0;
-                is_overflowing = true;
-            }
-        }
-        result.reduce();
-        if is_overflowing {
-            result = result + mod_fx;
-        }
-        result.reduce();
-        for indeterminate in result.get_function_mut().iter_mut() {
-            if indeterminate.coefficient == 2 {
-                indeterminate.coefficient = 0;
-            }
-        }
-        result.reduce();
-        result
-    }
-
-    pub fn is_degree_in_field(&self, degree: i16) -> bool {
-        for element in self.function.iter() {
-            if degree == element.get_degree() {
-                return true;
-            }
-        }
-        false
-    }
 }
 
 impl Display for Polynomial {
@@ -0,0 +1,372 @@
+use crate::error::QrError;
+use crate::input::{EciCharset, ErrorLevel, Settings, SymbolOptions, SymbolType};
+use crate::micro_qr_code::micro_qr_code;
+use crate::micro_qr_code::micro_struct::{MicroQRData, MICROQUIETZONE};
+use crate::rmqr_code::rmqr_code;
+use crate::rmqr_code::rmqr_struct::{RmqrData, RMQRQUIETZONE};
+use crate::standard_qr_code::qr_code;
+use crate::standard_qr_code::qr_struct::{MaskPenalty, QRData};
+
+/// width of the quiet zone around a standard qr code in elements
+const QUIETZONE: usize = 4;
+
+/// a finished qr code symbol
+#[derive(Debug, Clone, PartialEq)]
+pub struct QrCode {
+    symbol_type: SymbolType,
+    version: u8,
+    error_level: ErrorLevel,
+    mask: u8,
+    /// the elements without quiet zone indexed with [x][y], true is black
+    modules: Vec<Vec<bool>>,
+    /// the penalties the mask was chosen by
+    mask_penalties: Vec<MaskPenalty>,
+}
+
+impl QrCode {
+    /// starts the configuration of a new qr code
+    pub fn builder() -> QrCodeBuilder {
+        QrCodeBuilder::default()
+    }
+
+    /// returns the kind of symbol
+    pub fn symbol_type(&self) -> SymbolType {
+        self.symbol_type
+    }
+
+    /// returns the version (1 to 4 for micro qr codes M1 to M4,
+    /// 1 to 32 for rMQR codes R7x43 to R17x139)
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// returns the error correction level
+    pub fn error_level(&self) -> ErrorLevel {
+        self.error_level
+    }
+
+    /// returns the mask that was applied
+    pub fn mask(&self) -> u8 {
+        self.mask
+    }
+
+    /// returns the number of elements per row (without quiet zone)
+    pub fn width(&self) -> usize {
+        self.modules.len()
+    }
+
+    /// returns the number of elements per column (without quiet zone),
+    /// which only differs from the width for rMQR codes
+    pub fn height(&self) -> usize {
+        self.modules.first().map_or(0, |column| column.len())
+    }
+
+    /// returns the width of the light border the qr code needs around it in elements
+    pub fn quiet_zone(&self) -> usize {
+        match self.symbol_type {
+            SymbolType::Standard => QUIETZONE,
+            SymbolType::Micro => MICROQUIETZONE,
+            SymbolType::Rmqr => RMQRQUIETZONE,
+        }
+    }
+
+    /// returns the penalties of all eight masks of a standard qr code
+    /// (empty for micro qr codes and rMQR codes)
+    pub fn mask_penalties(&self) -> &[MaskPenalty] {
+        &self.mask_penalties
+    }
+
+    /// returns whether the element in column x and row y is black
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        self.modules[x][y]
+    }
+
+    /// returns all elements indexed with [x][y], true is black
+    pub fn modules(&self) -> &[Vec<bool>] {
+        &self.modules
+    }
+}
+
+impl From<&QRData> for QrCode {
+    fn from(qrdata: &QRData) -> Self {
+        QrCode {
+            symbol_type: SymbolType::Standard,
+            version: qrdata.get_version(),
+            error_level: qrdata.get_settings().error_level,
+            mask: qrdata.get_mask(),
+            modules: qrdata.get_modules(),
+            mask_penalties: qrdata.get_mask_penalties().to_vec(),
+        }
+    }
+}
+
+impl From<&MicroQRData> for QrCode {
+    fn from(micro_qrdata: &MicroQRData) -> Self {
+        QrCode {
+            symbol_type: SymbolType::Micro,
+            version: micro_qrdata.get_version(),
+            error_level: micro_qrdata.get_settings().error_level,
+            mask: micro_qrdata.get_mask(),
+            modules: micro_qrdata.get_modules(),
+            mask_penalties: vec![],
+        }
+    }
+}
+
+impl From<&RmqrData> for QrCode {
+    fn from(rmqr_data: &RmqrData) -> Self {
+        QrCode {
+            symbol_type: SymbolType::Rmqr,
+            version: rmqr_data.get_version(),
+            error_level: rmqr_data.get_settings().error_level,
+            mask: rmqr_data.get_mask(),
+            modules: rmqr_data.get_modules(),
+            mask_penalties: vec![],
+        }
+    }
+}
+
+/// generates the symbols of the kind selected in the settings
+fn generate_symbols(settings: Settings) -> Result<Vec<QrCode>, QrError> {
+    match settings.symbol.symbol_type {
+        SymbolType::Standard => {
+            let symbols: Vec<QRData> = qr_code(settings)?;
+            Ok(symbols.iter().map(QrCode::from).collect())
+        }
+        SymbolType::Micro => Ok(vec![QrCode::from(&micro_qr_code(settings)?)]),
+        SymbolType::Rmqr => Ok(vec![QrCode::from(&rmqr_code(settings)?)]),
+    }
+}
+
+/// collects the text and the options of a qr code
+#[derive(Debug, Clone, Default)]
+pub struct QrCodeBuilder {
+    settings: Settings,
+}
+
+impl QrCodeBuilder {
+    /// sets the text that is written into the qr code
+    pub fn data(mut self, text: impl Into<String>) -> Self {
+        self.settings.information = text.into();
+        self
+    }
+
+    /// sets the error correction level (default: L)
+    pub fn ec_level(mut self, error_level: ErrorLevel) -> Self {
+        self.settings.error_level = error_level;
+        self
+    }
+
+    /// writes the text in the given character set behind an ECI header
+    pub fn eci(mut self, charset: EciCharset) -> Self {
+        self.settings.eci = Some(charset);
+        self
+    }
+
+    /// reads the text as GS1 element string and marks the qr code as GS1 qr code
+    pub fn gs1(mut self, is_gs1: bool) -> Self {
+        self.settings.gs1 = is_gs1;
+        self
+    }
+
+    /// marks the qr code as formatted according to the industry application
+    /// with the given application indicator
+    pub fn fnc1_second(mut self, application_indicator: impl Into<String>) -> Self {
+        self.settings.fnc1_second = Some(application_indicator.into());
+        self
+    }
+
+    /// selects the kind of symbol (default: standard qr code)
+    pub fn symbol_type(mut self, symbol_type: SymbolType) -> Self {
+        self.settings.symbol.symbol_type = symbol_type;
+        self
+    }
+
+    /// generates a qr code of exactly this version
+    pub fn version(mut self, version: u8) -> Self {
+        self.settings.symbol.version = Some(version);
+        self
+    }
+
+    /// sets the smallest version the qr code may have (default: 1)
+    pub fn min_version(mut self, version: u8) -> Self {
+        self.settings.symbol.min_version = version;
+        self
+    }
+
+    /// sets the largest version the qr code may have (default: 40)
+    pub fn max_version(mut self, version: u8) -> Self {
+        self.settings.symbol.max_version = version;
+        self
+    }
+
+    /// applies the given mask (0 to 7) instead of the one with the lowest penalty
+    pub fn mask(mut self, mask: u8) -> Self {
+        self.settings.symbol.mask = Some(mask);
+        self
+    }
+
+    /// raises the error correction level as long as the data still fits into the version
+    /// chosen for the requested level
+    pub fn boost_ecl(mut self, is_boosted: bool) -> Self {
+        self.settings.symbol.boost_ecl = is_boosted;
+        self
+    }
+
+    /// replaces all restrictions on the version, mask and error correction level at once
+    pub fn symbol_options(mut self, options: SymbolOptions) -> Self {
+        self.settings.symbol = options;
+        self
+    }
+
+    /// prints debugging info to the terminal while generating the qr code
+    pub fn debugging(mut self, is_debugging: bool) -> Self {
+        self.settings.debugging = is_debugging;
+        self
+    }
+
+    /// generates a single qr code; fails if the text doesn't fit
+    pub fn build(mut self) -> Result<QrCode, QrError> {
+        self.settings.symbol.max_symbols = 1;
+        let mut symbols: Vec<QrCode> = generate_symbols(self.settings)?;
+        Ok(symbols.remove(0))
+    }
+
+    /// generates as many qr codes as are required to hold the text (up to 16),
+    /// which are connected with structured append headers; micro qr codes
+    /// can't be split up
+    pub fn build_sequence(self) -> Result<Vec<QrCode>, QrError> {
+        generate_symbols(self.settings)
+    }
+}
+
+mod tests {
+    #[test]
+    fn builder_options() {
+        use super::QrCode;
+        use crate::error::QrError;
+        use crate::input::ErrorLevel;
+
+        let symbol = QrCode::builder()
+            .data("Hello, world! 123")
+            .ec_level(ErrorLevel::M)
+            .build()
+            .unwrap();
+        assert_eq!(symbol.version(), 2);
+        assert_eq!(symbol.error_level(), ErrorLevel::M);
+        assert_eq!(symbol.width(), 25);
+        // top left corner of the finder pattern
+        assert!(symbol.is_dark(0, 0));
+        assert!(!symbol.is_dark(1, 1));
+        // the first mask with the lowest penalty is applied
+        let lowest_total: u32 = symbol
+            .mask_penalties()
+            .iter()
+            .map(|penalty| penalty.total())
+            .min()
+            .unwrap();
+        let first_lowest = symbol
+            .mask_penalties()
+            .iter()
+            .find(|penalty| penalty.total() == lowest_total)
+            .unwrap();
+        assert_eq!(first_lowest.mask, symbol.mask());
+        let symbol = QrCode::builder()
+            .data("Hello, world! 123")
+            .min_version(5)
+            .mask(3)
+            .build()
+            .unwrap();
+        assert_eq!(symbol.version(), 5);
+        assert_eq!(symbol.mask(), 3);
+        // all masks are rated even if the mask is given
+        assert_eq!(symbol.mask_penalties().len(), 8);
+        assert_eq!(symbol.width(), 37);
+        assert_eq!(
+            QrCode::builder().data("1").min_version(41).build(),
+            Err(QrError::InvalidVersion(41))
+        );
+        assert_eq!(
+            QrCode::builder().data("1").mask(8).build(),
+            Err(QrError::InvalidMask(8))
+        );
+    }
+
+    #[test]
+    fn version_constraints() {
+        use super::QrCode;
+        use crate::error::QrError;
+        use crate::input::ErrorLevel;
+
+        // 17 bytes fit into version 2-M, but not into version 1
+        let builder = QrCode::builder()
+            .data("Hello, world! 123")
+            .ec_level(ErrorLevel::M);
+        assert_eq!(builder.clone().version(7).build().unwrap().version(), 7);
+        assert_eq!(builder.clone().min_version(3).build().unwrap().version(), 3);
+        assert_eq!(
+            builder.clone().max_version(1).build(),
+            Err(QrError::DataTooLong {
+                needed_bits: 148,
+                available_bits: 128
+            })
+        );
+        assert_eq!(
+            builder.clone().min_version(5).max_version(4).build(),
+            Err(QrError::InvalidVersionRange {
+                min_version: 5,
+                max_version: 4
+            })
+        );
+        // version 2-Q holds 22 bytes, version 2-H 16 bytes
+        let boosted = builder.clone().boost_ecl(true).build().unwrap();
+        assert_eq!(boosted.version(), 2);
+        assert_eq!(boosted.error_level(), ErrorLevel::Q);
+        let boosted = builder.version(10).boost_ecl(true).build().unwrap();
+        assert_eq!(boosted.error_level(), ErrorLevel::H);
+    }
+
+    #[test]
+    fn builder_sequence() {
+        use super::QrCode;
+        use crate::error::QrError;
+
+        // version 40-L holds 2953 bytes
+        let text: String = "a".repeat(4000);
+        assert!(matches!(
+            QrCode::builder().data(text.clone()).build(),
+            Err(QrError::DataTooLong { .. })
+        ));
+        let symbols = QrCode::builder().data(text).build_sequence().unwrap();
+        assert_eq!(symbols.len(), 2);
+    }
+
+    #[test]
+    fn rectangular_symbols() {
+        use super::QrCode;
+        use crate::input::{ImageOptions, SymbolType, TerminalStyle};
+
+        let symbol = QrCode::builder()
+            .data("HELLO rMQR")
+            .symbol_type(SymbolType::Rmqr)
+            .version(2)
+            .build()
+            .unwrap();
+        assert_eq!((symbol.width(), symbol.height()), (59, 7));
+        assert_eq!(symbol.quiet_zone(), 2);
+        let svg: String = symbol.to_svg(&ImageOptions::default());
+        assert!(svg.contains("width=\"504\" height=\"88\" viewBox=\"0 0 63 11\""));
+        // two rows of elements per line
+        let text: String = symbol.to_terminal(TerminalStyle::Compact);
+        assert_eq!(text.lines().count(), 6);
+        assert_eq!(
+            text.lines()
+                .next()
+                .unwrap()
+                .chars()
+                .filter(|c| *c == ' ')
+                .count(),
+            63
+        );
+    }
+}
@@ -0,0 +1,3 @@
+mod png;
+mod svg;
+mod terminal;
@@ -0,0 +1,200 @@
+// writes qr codes as portable network graphics (PNG specification, third edition)
+// https://www.w3.org/TR/png/ and the zlib format of RFC 1950 and RFC 1951
+
+use crate::input::ImageOptions;
+use crate::qr_code::QrCode;
+
+/// every png file starts with these bytes
+const PNGSIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+/// maximum length of a stored (uncompressed) deflate block
+const MAXSTOREDBLOCK: usize = 0xFFFF;
+
+/// calculates the cyclic redundancy check of the chunks (polynomial 0xEDB88320, reflected)
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc: u32 = 0xFFFF_FFFF;
+    for byte in bytes.iter() {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// calculates the checksum at the end of a zlib stream
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut low, mut high): (u32, u32) = (1, 0);
+    for byte in bytes.iter() {
+        low = (low + *byte as u32) % 65521;
+        high = (high + low) % 65521;
+    }
+    (high << 16) | low
+}
+
+/// wraps the data into a zlib stream of stored deflate blocks; the rows of a qr code
+/// repeat too little for compression to be worth it
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // deflate with a window of 32 KiB and the lowest compression level
+    let mut stream: Vec<u8> = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAXSTOREDBLOCK).peekable();
+    if blocks.peek().is_none() {
+        // an empty stream still needs a final block
+        stream.extend([0b001, 0x00, 0x00, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        let is_final: bool = blocks.peek().is_none();
+        let length: u16 = block.len() as u16;
+        stream.push(is_final as u8);
+        stream.extend(length.to_le_bytes());
+        stream.extend((!length).to_le_bytes());
+        stream.extend(block);
+    }
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+/// appends a chunk with its length and checksum
+fn write_chunk(png: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    let start: usize = png.len();
+    png.extend(chunk_type);
+    png.extend(data);
+    // the checksum covers the type and the data
+    let crc: u32 = crc32(&png[start..]);
+    png.extend(crc.to_be_bytes());
+}
+
+impl QrCode {
+    /// converts the qr code into a png image with one bit per pixel (0 is black)
+    pub fn to_png(&self, options: &ImageOptions) -> Vec<u8> {
+        let modules: &[Vec<bool>] = self.modules();
+        let quiet_zone: usize = options
+            .quiet_zone
+            .map_or(self.quiet_zone(), |quiet_zone| quiet_zone as usize);
+        let scale: usize = options.module_size as usize;
+        let width_pixels: usize = (self.width() + 2 * quiet_zone) * scale;
+        let height_pixels: usize = (self.height() + 2 * quiet_zone) * scale;
+        // every row starts with the filter type (0: none) and is padded to full bytes
+        let row_bytes: usize = width_pixels.div_ceil(8);
+        let mut image_data: Vec<u8> = Vec::with_capacity((row_bytes + 1) * height_pixels);
+        for y in 0..height_pixels {
+            let mut row: Vec<u8> = vec![0xFF; row_bytes];
+            let module_y: usize = y / scale;
+            if module_y >= quiet_zone && module_y - quiet_zone < self.height() {
+                for x in 0..width_pixels {
+                    let module_x: usize = x / scale;
+                    if module_x >= quiet_zone
+                        && module_x - quiet_zone < self.width()
+                        && modules[module_x - quiet_zone][module_y - quiet_zone]
+                    {
+                        row[x / 8] &= !(0b1000_0000 >> (x % 8));
+                    }
+                }
+            }
+            image_data.push(0);
+            image_data.extend(row);
+        }
+        let mut header: Vec<u8> = vec![];
+        header.extend((width_pixels as u32).to_be_bytes());
+        header.extend((height_pixels as u32).to_be_bytes());
+        // bit depth 1, greyscale, deflate, adaptive filtering, no interlacing
+        header.extend([1, 0, 0, 0, 0]);
+        let mut png: Vec<u8> = PNGSIGNATURE.to_vec();
+        write_chunk(&mut png, b"IHDR", &header);
+        write_chunk(&mut png, b"IDAT", &zlib_stored(&image_data));
+        write_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+}
+
+mod tests {
+    #[test]
+    fn checksums() {
+        use super::{adler32, crc32};
+
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+        assert_eq!(adler32(b""), 1);
+    }
+
+    #[test]
+    fn stored_blocks() {
+        use super::zlib_stored;
+
+        assert_eq!(
+            zlib_stored(b"ab"),
+            vec![0x78, 0x01, 0x01, 0x02, 0x00, 0xFD, 0xFF, b'a', b'b', 0x01, 0x26, 0x00, 0xC4]
+        );
+        assert_eq!(
+            zlib_stored(b""),
+            vec![0x78, 0x01, 0x01, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x01]
+        );
+        // two blocks, only the second one is final
+        let stream: Vec<u8> = zlib_stored(&[7; 0x10000]);
+        assert_eq!(stream[2..7], [0x00, 0xFF, 0xFF, 0x00, 0x00]);
+        assert_eq!(stream[0x10006..0x1000B], [0x01, 0x01, 0x00, 0xFE, 0xFF]);
+        assert_eq!(stream.len(), 2 + 5 + 0xFFFF + 5 + 1 + 4);
+    }
+
+    #[test]
+    fn png_output() {
+        use super::{crc32, PNGSIGNATURE};
+        use crate::input::{ErrorLevel, ImageOptions};
+        use crate::qr_code::QrCode;
+
+        let symbol = QrCode::builder()
+            .data("Hello, world! 123")
+            .ec_level(ErrorLevel::M)
+            .build()
+            .unwrap();
+        let options = ImageOptions {
+            module_size: 3,
+            quiet_zone: Some(2),
+            ..ImageOptions::default()
+        };
+        let png: Vec<u8> = symbol.to_png(&options);
+        assert_eq!(png[..8], PNGSIGNATURE);
+        // split up the chunks and check their checksums
+        let mut chunks: Vec<(Vec<u8>, Vec<u8>)> = vec![];
+        let mut position: usize = 8;
+        while position < png.len() {
+            let length: usize =
+                u32::from_be_bytes(png[position..position + 4].try_into().unwrap()) as usize;
+            let chunk: &[u8] = &png[position + 4..position + 8 + length];
+            let crc: u32 = u32::from_be_bytes(
+                png[position + 8 + length..position + 12 + length]
+                    .try_into()
+                    .unwrap(),
+            );
+            assert_eq!(crc32(chunk), crc);
+            chunks.push((chunk[..4].to_vec(), chunk[4..].to_vec()));
+            position += 12 + length;
+        }
+        let types: Vec<&[u8]> = chunks
+            .iter()
+            .map(|(chunk_type, _)| &chunk_type[..])
+            .collect();
+        assert_eq!(types, [b"IHDR", b"IDAT", b"IEND"]);
+        // version 2 is 25 elements wide, with the quiet zone 29 elements of 3 pixels
+        assert_eq!(chunks[0].1, [0, 0, 0, 87, 0, 0, 0, 87, 1, 0, 0, 0, 0]);
+        // the image data is stored in a single block after the zlib header
+        let image_data: &[u8] = &chunks[1].1[7..chunks[1].1.len() - 4];
+        let modules = symbol.modules();
+        for (y, row) in image_data.chunks(12).enumerate() {
+            assert_eq!(row[0], 0);
+            for x in 0..87 {
+                let is_black: bool = (row[1 + x / 8] << (x % 8)) & 0b1000_0000 == 0;
+                let (module_x, module_y) = (x / 3, y / 3);
+                let expected: bool = (2..27).contains(&module_x)
+                    && (2..27).contains(&module_y)
+                    && modules[module_x - 2][module_y - 2];
+                assert_eq!(is_black, expected, "pixel x: {x}, y: {y}");
+            }
+        }
+    }
+}
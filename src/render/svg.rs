@@ -0,0 +1,142 @@
+// writes qr codes as scalable vector graphics
+// https://www.w3.org/TR/SVG11/paths.html
+
+use crate::input::ImageOptions;
+use crate::qr_code::QrCode;
+
+/// replaces the characters that can't appear inside of an attribute value
+fn escape_attribute(value: &str) -> String {
+    let mut escaped: String = String::new();
+    for character in value.chars() {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(character),
+        }
+    }
+    escaped
+}
+
+/// describes all black elements as a single path; every horizontal run of black elements
+/// is one rectangle, the coordinates are given in elements
+fn module_path(modules: &[Vec<bool>], quiet_zone: usize) -> String {
+    let width: usize = modules.len();
+    let height: usize = modules.first().map_or(0, |column| column.len());
+    let mut path: String = String::new();
+    for y in 0..height {
+        let row: Vec<bool> = modules.iter().map(|column| column[y]).collect();
+        let mut x: usize = 0;
+        while x < width {
+            if !row[x] {
+                x += 1;
+                continue;
+            }
+            let start: usize = x;
+            while x < width && row[x] {
+                x += 1;
+            }
+            let length: usize = x - start;
+            path.push_str(&format!(
+                "M{} {}h{length}v1h-{length}z",
+                start + quiet_zone,
+                y + quiet_zone
+            ));
+        }
+    }
+    path
+}
+
+impl QrCode {
+    /// converts the qr code into an svg image
+    pub fn to_svg(&self, options: &ImageOptions) -> String {
+        let modules: &[Vec<bool>] = self.modules();
+        let quiet_zone: usize = options
+            .quiet_zone
+            .map_or(self.quiet_zone(), |quiet_zone| quiet_zone as usize);
+        // size in elements and in pixels
+        let width: usize = self.width() + 2 * quiet_zone;
+        let height: usize = self.height() + 2 * quiet_zone;
+        let (width_pixels, height_pixels): (usize, usize) = (
+            width * options.module_size as usize,
+            height * options.module_size as usize,
+        );
+        let mut svg: String = String::new();
+        svg.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        svg.push_str(&format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" version=\"1.1\" width=\"{width_pixels}\" \
+             height=\"{height_pixels}\" viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">\n"
+        ));
+        svg.push_str(&format!(
+            "<rect width=\"{width}\" height=\"{height}\" fill=\"{}\"/>\n",
+            escape_attribute(&options.background)
+        ));
+        svg.push_str(&format!(
+            "<path fill=\"{}\" d=\"{}\"/>\n",
+            escape_attribute(&options.foreground),
+            module_path(modules, quiet_zone)
+        ));
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+mod tests {
+    #[test]
+    fn module_paths() {
+        use super::module_path;
+
+        let modules: Vec<Vec<bool>> = vec![
+            vec![true, false, true],
+            vec![true, false, false],
+            vec![false, false, true],
+        ];
+        assert_eq!(
+            module_path(&modules, 0),
+            "M0 0h2v1h-2zM0 2h1v1h-1zM2 2h1v1h-1z"
+        );
+        assert_eq!(
+            module_path(&modules, 4),
+            "M4 4h2v1h-2zM4 6h1v1h-1zM6 6h1v1h-1z"
+        );
+    }
+
+    #[test]
+    fn svg_output() {
+        use crate::input::{ErrorLevel, ImageOptions};
+        use crate::qr_code::QrCode;
+
+        let symbol = QrCode::builder()
+            .data("Hello, world! 123")
+            .ec_level(ErrorLevel::M)
+            .build()
+            .unwrap();
+        let options = ImageOptions {
+            module_size: 3,
+            quiet_zone: Some(2),
+            foreground: String::from("navy"),
+            background: String::from("\"><script>"),
+        };
+        let svg: String = symbol.to_svg(&options);
+        // version 2 is 25 elements wide
+        assert!(svg.contains("width=\"87\" height=\"87\" viewBox=\"0 0 29 29\""));
+        assert!(svg.contains("fill=\"navy\""));
+        assert!(svg.contains("fill=\"&quot;&gt;&lt;script&gt;\""));
+        // a single path, which starts with the top row of the top left finder pattern
+        assert_eq!(svg.matches("<path").count(), 1);
+        assert!(svg.contains("d=\"M2 2h7v1h-7z"));
+        // every black element is covered by exactly one run
+        let path: &str = svg.split("d=\"").nth(1).unwrap().split('"').next().unwrap();
+        let mut black_elements: usize = 0;
+        for run in path.split('h').skip(1).step_by(2) {
+            black_elements += run.split('v').next().unwrap().parse::<usize>().unwrap();
+        }
+        let expected: usize = symbol
+            .modules()
+            .iter()
+            .map(|column| column.iter().filter(|module| **module).count())
+            .sum();
+        assert_eq!(black_elements, expected);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::input::TerminalStyle;
+use crate::qr_code::QrCode;
+use crate::standard_qr_code::qr_struct::{BLACK, BRIGHTWHITE, COLORSTOP};
+
+/// returns the character that fills the upper and/or the lower half of a line
+fn half_block(is_upper_drawn: bool, is_lower_drawn: bool) -> char {
+    match (is_upper_drawn, is_lower_drawn) {
+        (true, true) => '█',
+        (true, false) => '▀',
+        (false, true) => '▄',
+        (false, false) => ' ',
+    }
+}
+
+impl QrCode {
+    /// converts the qr code (with quiet zone) into text for the terminal
+    pub fn to_terminal(&self, style: TerminalStyle) -> String {
+        let quiet_zone: usize = self.quiet_zone();
+        let width: usize = self.width() + 2 * quiet_zone;
+        let height: usize = self.height() + 2 * quiet_zone;
+        let is_dark = |x: usize, y: usize| -> bool {
+            (quiet_zone..width - quiet_zone).contains(&x)
+                && (quiet_zone..height - quiet_zone).contains(&y)
+                && self.is_dark(x - quiet_zone, y - quiet_zone)
+        };
+        let mut text: String = String::new();
+        if style == TerminalStyle::Ansi {
+            for y in 0..height {
+                for x in 0..width {
+                    let color: &str = if is_dark(x, y) { BLACK } else { BRIGHTWHITE };
+                    text.push_str(&format!("{color}   {COLORSTOP}"));
+                }
+                text.push('\n');
+            }
+            return text;
+        }
+        // the characters draw the black elements, unless the terminal draws light text
+        let is_drawn = |x: usize, y: usize| -> bool {
+            y < height && is_dark(x, y) != (style == TerminalStyle::Inverted)
+        };
+        // two rows of elements per line
+        for y in (0..height).step_by(2) {
+            if style == TerminalStyle::Compact {
+                text.push_str(BRIGHTWHITE);
+            }
+            for x in 0..width {
+                text.push(half_block(is_drawn(x, y), is_drawn(x, y + 1)));
+            }
+            if style == TerminalStyle::Compact {
+                text.push_str(COLORSTOP);
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+mod tests {
+    #[test]
+    fn half_block_lines() {
+        use crate::input::{ErrorLevel, TerminalStyle};
+        use crate::qr_code::QrCode;
+
+        let symbol = QrCode::builder()
+            .data("Hello, world! 123")
+            .ec_level(ErrorLevel::M)
+            .build()
+            .unwrap();
+        // 25 elements and the quiet zone are 33 columns and 17 lines
+        let text: String = symbol.to_terminal(TerminalStyle::Text);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 17);
+        assert!(lines.iter().all(|line| line.chars().count() == 33));
+        assert_eq!(lines[0], " ".repeat(33));
+        // top of the finder patterns
+        assert!(lines[2].starts_with("    █▀▀▀▀▀█ "));
+        // the last line only contains the lowest row of the quiet zone
+        let inverted: String = symbol.to_terminal(TerminalStyle::Inverted);
+        assert_eq!(inverted.lines().next(), Some(&*"█".repeat(33)));
+        assert_eq!(inverted.lines().last(), Some(&*"▀".repeat(33)));
+        assert!(inverted.lines().nth(2).unwrap().starts_with("████ ▄▄▄▄▄ █"));
+        let compact: String = symbol.to_terminal(TerminalStyle::Compact);
+        assert_eq!(
+            compact.lines().nth(2),
+            Some(&*format!("\x1b[30;107m{}\x1b[m", lines[2]))
+        );
+    }
+
+    #[test]
+    fn half_block_round_trip() {
+        use crate::input::{ErrorLevel, TerminalStyle};
+        use crate::qr_code::QrCode;
+        use crate::standard_qr_code::decoder::decode;
+        use crate::standard_qr_code::qr_struct::SymbolStatus;
+
+        let text: &str = "https://EXAMPLE.COM/ORDER/000123456789";
+        let symbol = QrCode::builder()
+            .data(text)
+            .ec_level(ErrorLevel::L)
+            .build()
+            .unwrap();
+        let width: usize = symbol.width() + 8;
+        for style in [
+            TerminalStyle::Compact,
+            TerminalStyle::Text,
+            TerminalStyle::Inverted,
+        ] {
+            // read the elements back from the characters
+            let output: String = symbol
+                .to_terminal(style)
+                .replace("\x1b[30;107m", "")
+                .replace("\x1b[m", "");
+            let mut grid: Vec<Vec<SymbolStatus>> =
+                vec![vec![SymbolStatus::LogicalFalse; width]; width];
+            for (line_index, line) in output.lines().enumerate() {
+                for (x, character) in line.chars().enumerate() {
+                    let (upper, lower) = match character {
+                        '█' => (true, true),
+                        '▀' => (true, false),
+                        '▄' => (false, true),
+                        _ => (false, false),
+                    };
+                    for (y, is_drawn) in [(2 * line_index, upper), (2 * line_index + 1, lower)] {
+                        if y < width && is_drawn != (style == TerminalStyle::Inverted) {
+                            grid[x][y] = SymbolStatus::LogicalTrue;
+                        }
+                    }
+                }
+            }
+            let decoded = decode(&grid, 4).unwrap();
+            assert_eq!(decoded.text(), Ok(String::from(text)), "style: {style:?}");
+        }
+    }
+}
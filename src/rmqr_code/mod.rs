@@ -0,0 +1,22 @@
+use crate::error::QrError;
+use crate::input::Settings;
+use crate::rmqr_code::rmqr_struct::RmqrData;
+pub mod rmqr_struct;
+mod version_constants;
+
+/// generates the rMQR code for the text of the settings; like micro qr codes they
+/// can't be split up into multiple ones, so texts that are too long for R17x139 fail
+pub(crate) fn rmqr_code(input: Settings) -> Result<RmqrData, QrError> {
+    if input.debugging {
+        println!("{input:?}");
+    }
+    let mut rmqr_data: RmqrData = RmqrData::new(input)?;
+    // draw the finder pattern, the finder sub pattern, the corner finder patterns,
+    // the alignment patterns, the timing patterns and reserve the format information
+    rmqr_data.draw_function_patterns();
+    // write the data and the error correction codewords into the remaining elements
+    rmqr_data.read_and_write()?;
+    // apply the only mask rMQR codes have and write both format informations
+    rmqr_data.masking_format_information();
+    Ok(rmqr_data)
+}
@@ -0,0 +1,705 @@
+use std::fmt::{Display, Formatter, Result};
+use std::ops::RangeInclusive;
+
+use crate::error::QrError;
+use crate::galois_field::GaloisFields;
+use crate::input::{ErrorLevel, Settings};
+use crate::polynomials::{Indeterminate, Polynomial};
+use crate::rmqr_code::version_constants::{
+    get_rmqr_version_info, rmqr_alignment_columns, rmqr_character_count_bits, rmqr_mode_indicator,
+    MAXRMQRVERSION,
+};
+use crate::standard_qr_code::bch::bch_18_6;
+use crate::standard_qr_code::qr_struct::{
+    header_segments, mask_condition, ErrorBlockInfo, SymbolStatus, BLACK, BRIGHTWHITE, COLORSTOP,
+};
+use crate::standard_qr_code::segmentation::{segment_text_with_headers, Segment, TextEncoding};
+
+/// the standard qr code mask that is applied onto every rMQR code
+const RMQRMASK: u8 = 4;
+/// mask that is applied onto the format information next to the finder pattern
+const FINDERSIDEFORMATMASK: u32 = 0x1FAB2;
+/// mask that is applied onto the format information next to the finder sub pattern
+const SUBPATTERNSIDEFORMATMASK: u32 = 0x20A7B;
+/// length of the mode indicator in bits
+const MODEINDICATORBITS: u8 = 3;
+/// length of the terminator in bits
+const TERMINATORBITS: usize = 3;
+/// width of the quiet zone around a rMQR code in elements
+pub const RMQRQUIETZONE: usize = 2;
+
+/// returns the 18 bit format information of the version and the error correction level
+/// (before it is masked)
+pub fn rmqr_format_information(version: u8, error_level: ErrorLevel) -> u32 {
+    // the same BCH(18,6) code as the version information of standard qr codes
+    bch_18_6((((error_level == ErrorLevel::H) as u8) << 5) | (version - 1))
+}
+
+/// returns the amount of bits all segments take up with the given character count indicators
+fn rmqr_segments_bits(segments: &[Segment], character_count_bits: &[u8; 4]) -> usize {
+    segments
+        .iter()
+        .map(|segment| {
+            (MODEINDICATORBITS + rmqr_character_count_bits(segment.mode, character_count_bits))
+                as usize
+                + segment.mode.data_bits(segment.character_count())
+        })
+        .sum()
+}
+
+/// returns the number of data bits of all error blocks
+fn data_bits(error_blocks: &[ErrorBlockInfo]) -> usize {
+    error_blocks
+        .iter()
+        .map(|block| block.num_block as usize * block.num_data_bytes as usize * 8)
+        .sum()
+}
+
+/// appends the lowest size bits of value to the bit stream
+fn push_bits(bit_stream: &mut Vec<bool>, value: u16, size: u8) {
+    for bit in (0..size).rev() {
+        bit_stream.push((value >> bit) & 1 == 1);
+    }
+}
+
+/// encompasses all data required to generate a rectangular micro qr code (rMQR)
+#[derive(Debug, Clone)]
+pub struct RmqrData {
+    /// the elements without quiet zone indexed with [x][y]
+    output_data: Vec<Vec<SymbolStatus>>,
+    /// whether the element belongs to a function pattern or the format information
+    is_function: Vec<Vec<bool>>,
+    version: u8,
+    /// lengths of the character count indicators of numeric, alphanumeric, byte and kanji mode
+    character_count_bits: [u8; 4],
+    /// error blocks of the selected error correction level
+    error_blocks: Vec<ErrorBlockInfo>,
+    segments: Vec<Segment>,
+    settings: Settings,
+}
+
+impl RmqrData {
+    /// selects the version with the fewest elements the text of the settings fits into;
+    /// L is raised to M and Q to H, as rMQR codes only have these two levels
+    pub(crate) fn new(mut input: Settings) -> std::result::Result<RmqrData, QrError> {
+        if input.symbol.mask.is_some() {
+            return Err(QrError::UnsupportedOption(String::from(
+                "rMQR codes always use the same mask",
+            )));
+        }
+        input.error_level = match input.error_level {
+            ErrorLevel::L | ErrorLevel::M => ErrorLevel::M,
+            ErrorLevel::Q | ErrorLevel::H => ErrorLevel::H,
+        };
+        let versions: RangeInclusive<u8> = match input.symbol.version {
+            Some(version) => version..=version,
+            None if input.symbol.min_version > input.symbol.max_version => {
+                return Err(QrError::InvalidVersionRange {
+                    min_version: input.symbol.min_version,
+                    max_version: input.symbol.max_version,
+                })
+            }
+            // the default largest version belongs to standard qr codes
+            None => input.symbol.min_version..=input.symbol.max_version.min(MAXRMQRVERSION),
+        };
+        for version in [*versions.start(), *versions.end()] {
+            if !(1..=MAXRMQRVERSION).contains(&version) {
+                return Err(QrError::InvalidVersion(version));
+            }
+        }
+        let (header, encoding): (Vec<Segment>, TextEncoding) = header_segments(&input)?;
+        // the versions aren't ordered by size, R11x27 is the smallest one
+        let mut version_info = get_rmqr_version_info();
+        version_info.retain(|(version, ..)| versions.contains(version));
+        version_info.sort_by_key(|(_, (height, width), ..)| *height as u16 * *width as u16);
+        let mut last_error: Option<QrError> = None;
+        let mut selected = None;
+        for (version, (height, width), _, character_count_bits, error_info) in version_info {
+            let mut segments: Vec<Segment> = header.clone();
+            // all modes are available in all versions, only their headers differ
+            segments.extend(segment_text_with_headers(
+                &input.information,
+                encoding,
+                |mode| {
+                    Some(
+                        (MODEINDICATORBITS + rmqr_character_count_bits(mode, &character_count_bits))
+                            as usize,
+                    )
+                },
+            )?);
+            let needed_bits: usize = rmqr_segments_bits(&segments, &character_count_bits);
+            let block_info = |error_level: ErrorLevel| -> Vec<ErrorBlockInfo> {
+                error_info
+                    .iter()
+                    .find(|(level, _)| *level == error_level)
+                    .map(|(_, blocks)| blocks.clone())
+                    .expect("every rMQR version has the levels M and H")
+            };
+            let mut error_blocks: Vec<ErrorBlockInfo> = block_info(input.error_level);
+            let available_bits: usize = data_bits(&error_blocks);
+            if needed_bits > available_bits {
+                last_error = Some(QrError::DataTooLong {
+                    needed_bits,
+                    available_bits,
+                });
+                continue;
+            }
+            // raise the error correction level if the text still fits
+            if input.symbol.boost_ecl
+                && input.error_level == ErrorLevel::M
+                && needed_bits <= data_bits(&block_info(ErrorLevel::H))
+            {
+                error_blocks = block_info(ErrorLevel::H);
+                input.error_level = ErrorLevel::H;
+            }
+            selected = Some((
+                version,
+                (height, width),
+                character_count_bits,
+                error_blocks,
+                segments,
+            ));
+            break;
+        }
+        let Some((version, (height, width), character_count_bits, error_blocks, segments)) =
+            selected
+        else {
+            return Err(last_error.expect("at least one version was tried"));
+        };
+        let (height, width): (usize, usize) = (height as usize, width as usize);
+        Ok(RmqrData {
+            output_data: vec![vec![SymbolStatus::Uninitialised; height]; width],
+            is_function: vec![vec![false; height]; width],
+            version,
+            character_count_bits,
+            error_blocks,
+            segments,
+            settings: input,
+        })
+    }
+
+    /// returns the version (1 to 32 for R7x43 to R17x139)
+    pub fn get_version(&self) -> u8 {
+        self.version
+    }
+
+    /// returns the mask that was applied, which is always mask 4 of standard qr codes
+    pub fn get_mask(&self) -> u8 {
+        RMQRMASK
+    }
+
+    /// returns the number of elements per row (without quiet zone)
+    pub fn get_width(&self) -> usize {
+        self.output_data.len()
+    }
+
+    /// returns the number of elements per column (without quiet zone)
+    pub fn get_height(&self) -> usize {
+        self.output_data[0].len()
+    }
+
+    pub(crate) fn get_settings(&self) -> &Settings {
+        &self.settings
+    }
+
+    /// marks the element as part of a function pattern with the given color
+    fn set_function(&mut self, x: usize, y: usize, is_dark: bool) {
+        self.output_data[x][y] = if is_dark {
+            SymbolStatus::LogicalTrue
+        } else {
+            SymbolStatus::LogicalFalse
+        };
+        self.is_function[x][y] = true;
+    }
+
+    /// draws the finder pattern in the top left corner with its separator, the finder sub
+    /// pattern in the bottom right corner, the corner finder patterns in the other two corners,
+    /// the alignment patterns, the timing patterns and reserves the format information
+    pub fn draw_function_patterns(&mut self) {
+        let (width, height): (usize, usize) = (self.get_width(), self.get_height());
+        // finder pattern; the ring at a distance of 2 elements from the centre is white
+        for x in 0..7_usize {
+            for y in 0..7_usize {
+                let distance: usize = x.abs_diff(3).max(y.abs_diff(3));
+                self.set_function(x, y, distance != 2);
+            }
+        }
+        // separator right of (and below, unless the symbol is only 7 elements high)
+        // the finder pattern
+        for y in 0..height.min(8) {
+            self.set_function(7, y, false);
+        }
+        if height >= 9 {
+            for x in 0..8 {
+                self.set_function(x, 7, false);
+            }
+        }
+        // finder sub pattern; the ring at a distance of 1 element from the centre is white
+        for x in width - 5..width {
+            for y in height - 5..height {
+                let distance: usize = x.abs_diff(width - 3).max(y.abs_diff(height - 3));
+                self.set_function(x, y, distance != 1);
+            }
+        }
+        // corner finder pattern in the top right corner
+        self.set_function(width - 1, 0, true);
+        self.set_function(width - 2, 0, true);
+        self.set_function(width - 1, 1, true);
+        self.set_function(width - 2, 1, false);
+        // corner finder pattern in the bottom left corner (part of the finder pattern
+        // in symbols that are 7 elements high)
+        if height > 7 {
+            for x in 0..3 {
+                self.set_function(x, height - 1, true);
+            }
+        }
+        if height >= 11 {
+            self.set_function(0, height - 2, true);
+            self.set_function(1, height - 2, false);
+        }
+        // alignment patterns at the top and bottom edge with a white centre
+        let alignment_columns: Vec<usize> = rmqr_alignment_columns(width as u8)
+            .into_iter()
+            .map(|column| column as usize)
+            .collect();
+        for column in alignment_columns.iter() {
+            for centre_y in [1, height - 2] {
+                for x in column - 1..=column + 1 {
+                    for y in centre_y - 1..=centre_y + 1 {
+                        self.set_function(x, y, x != *column || y != centre_y);
+                    }
+                }
+            }
+        }
+        // format information right of the finder pattern and left of the finder sub pattern
+        for bit in 0..18 {
+            self.set_function(8 + bit / 5, 1 + bit % 5, false);
+        }
+        for x in width - 8..=width - 6 {
+            for y in height - 6..=height - 2 {
+                self.set_function(x, y, false);
+            }
+        }
+        for x in width - 5..=width - 3 {
+            self.set_function(x, height - 6, false);
+        }
+        // horizontal timing patterns along the top and bottom edge and vertical timing patterns
+        // along the left and right edge and through the alignment patterns,
+        // starting with a black element at even coordinates
+        for x in 0..width {
+            for y in [0, height - 1] {
+                if !self.is_function[x][y] {
+                    self.set_function(x, y, x % 2 == 0);
+                }
+            }
+        }
+        for x in [0, width - 1].into_iter().chain(alignment_columns) {
+            for y in 0..height {
+                if !self.is_function[x][y] {
+                    self.set_function(x, y, y % 2 == 0);
+                }
+            }
+        }
+    }
+
+    /// returns the data codewords including terminator and padding
+    fn data_codewords(&self) -> Vec<u8> {
+        let mut bit_stream: Vec<bool> = vec![];
+        for segment in self.segments.iter() {
+            push_bits(
+                &mut bit_stream,
+                rmqr_mode_indicator(segment.mode) as u16,
+                MODEINDICATORBITS,
+            );
+            push_bits(
+                &mut bit_stream,
+                segment.character_count() as u16,
+                rmqr_character_count_bits(segment.mode, &self.character_count_bits),
+            );
+            for (value, size) in segment.value_groups() {
+                push_bits(&mut bit_stream, value, size);
+            }
+        }
+        let capacity: usize = data_bits(&self.error_blocks);
+        // the terminator is shortened if the data almost fills the symbol
+        let terminator_bits: usize = TERMINATORBITS.min(capacity - bit_stream.len());
+        bit_stream.resize(bit_stream.len() + terminator_bits, false);
+        // fill up the last codeword with zeroes and the rest with alternating pad codewords
+        bit_stream.resize(bit_stream.len().next_multiple_of(8), false);
+        let mut pad_codewords = [0xec, 0x11].iter().cycle();
+        while bit_stream.len() < capacity {
+            push_bits(&mut bit_stream, *pad_codewords.next().unwrap(), 8);
+        }
+        bit_stream
+            .chunks(8)
+            .map(|bits| {
+                bits.iter().enumerate().fold(0, |codeword, (index, bit)| {
+                    codeword | ((*bit as u8) << (7 - index))
+                })
+            })
+            .collect()
+    }
+
+    /// returns the coordinates of all data elements in the order the data is written into them:
+    /// two columns wide from the bottom right corner upwards, then downwards and so on
+    fn data_module_positions(&self) -> Vec<(usize, usize)> {
+        let (width, height): (usize, usize) = (self.get_width(), self.get_height());
+        let mut positions: Vec<(usize, usize)> = vec![];
+        let mut is_upwards: bool = true;
+        // the leftmost and the rightmost column only hold function patterns
+        for right_column in (1..width - 1).rev().step_by(2) {
+            for step in 0..height {
+                let y: usize = if is_upwards { height - 1 - step } else { step };
+                for x in [right_column, right_column - 1] {
+                    if !self.is_function[x][y] {
+                        positions.push((x, y));
+                    }
+                }
+            }
+            is_upwards = !is_upwards;
+        }
+        positions
+    }
+
+    /// encodes the text, splits it into the error blocks, calculates their error correction
+    /// bytes and writes the interleaved codewords into the symbol
+    pub fn read_and_write(&mut self) -> std::result::Result<(), QrError> {
+        let data_codewords: Vec<u8> = self.data_codewords();
+        let galois_field = GaloisFields::_new(
+            8,
+            Polynomial::new(vec![
+                Indeterminate::new(1, 4),
+                Indeterminate::new(1, 3),
+                Indeterminate::new(1, 2),
+                Indeterminate::new(1, 0),
+            ]),
+        );
+        let mut data_blocks: Vec<&[u8]> = vec![];
+        let mut error_blocks: Vec<Vec<u8>> = vec![];
+        let mut remaining: &[u8] = &data_codewords;
+        for block_info in self.error_blocks.iter() {
+            for _ in 0..block_info.num_block {
+                let (block, rest) = remaining.split_at(block_info.num_data_bytes as usize);
+                error_blocks
+                    .push(galois_field.error_correction_bytes(block, block_info.num_error_bytes)?);
+                data_blocks.push(block);
+                remaining = rest;
+            }
+        }
+        if self.settings.debugging {
+            println!(
+                "rMQR code R{}x{}-{} (version {})\ndata blocks: {data_blocks:02x?}\nerror correction blocks: {error_blocks:02x?}",
+                self.get_height(),
+                self.get_width(),
+                self.settings.error_level,
+                self.version
+            );
+        }
+        // the codewords are interleaved like in standard qr codes, the longer blocks come last
+        let mut bit_stream: Vec<bool> = vec![];
+        let longest_data_block: usize = data_blocks.iter().map(|block| block.len()).max().unwrap();
+        for index in 0..longest_data_block {
+            for block in data_blocks.iter().filter(|block| index < block.len()) {
+                push_bits(&mut bit_stream, block[index] as u16, 8);
+            }
+        }
+        for index in 0..error_blocks[0].len() {
+            for block in error_blocks.iter() {
+                push_bits(&mut bit_stream, block[index] as u16, 8);
+            }
+        }
+        let positions: Vec<(usize, usize)> = self.data_module_positions();
+        // the remainder bits are white
+        assert!(positions.len() - bit_stream.len() < 8);
+        bit_stream.resize(positions.len(), false);
+        for ((x, y), bit) in positions.into_iter().zip(bit_stream) {
+            self.output_data[x][y] = if bit {
+                SymbolStatus::LogicalTrue
+            } else {
+                SymbolStatus::LogicalFalse
+            };
+        }
+        Ok(())
+    }
+
+    /// inverts the data elements selected by the mask; applying it twice restores the data
+    fn apply_mask(&mut self) {
+        for x in 0..self.get_width() {
+            for y in 0..self.get_height() {
+                if !self.is_function[x][y] && mask_condition(RMQRMASK, y, x) {
+                    self.output_data[x][y] = self.output_data[x][y] ^ SymbolStatus::LogicalTrue;
+                }
+            }
+        }
+    }
+
+    /// applies the mask and writes the format information next to the finder pattern
+    /// and the finder sub pattern, each masked differently
+    pub fn masking_format_information(&mut self) {
+        let (width, height): (usize, usize) = (self.get_width(), self.get_height());
+        self.apply_mask();
+        let format_information: u32 =
+            rmqr_format_information(self.version, self.settings.error_level);
+        // bit 0 is written into the top left element of both areas, the areas
+        // are filled column by column
+        let finder_side: u32 = format_information ^ FINDERSIDEFORMATMASK;
+        for bit in 0..18 {
+            let is_dark: bool = (finder_side >> bit) & 1 == 1;
+            self.set_function(8 + bit / 5, 1 + bit % 5, is_dark);
+        }
+        let sub_pattern_side: u32 = format_information ^ SUBPATTERNSIDEFORMATMASK;
+        for bit in 0..18 {
+            let is_dark: bool = (sub_pattern_side >> bit) & 1 == 1;
+            // the last three bits are written above the finder sub pattern
+            let (x, y): (usize, usize) = if bit < 15 {
+                (width - 8 + bit / 5, height - 6 + bit % 5)
+            } else {
+                (width - 20 + bit, height - 6)
+            };
+            self.set_function(x, y, is_dark);
+        }
+        if self.settings.debugging {
+            println!("after applying the mask and format information");
+            print!("{self}");
+        }
+    }
+
+    /// returns whether the elements of the rMQR code (without quiet zone)
+    /// are black, indexed with [x][y]
+    pub fn get_modules(&self) -> Vec<Vec<bool>> {
+        self.output_data
+            .iter()
+            .map(|column| {
+                column
+                    .iter()
+                    .map(|element| *element == SymbolStatus::LogicalTrue)
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl Display for RmqrData {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        let width: usize = self.get_width() + 2 * RMQRQUIETZONE;
+        let height: usize = self.get_height() + 2 * RMQRQUIETZONE;
+        for y in 0..height {
+            for x in 0..width {
+                let is_dark: bool = x >= RMQRQUIETZONE
+                    && y >= RMQRQUIETZONE
+                    && x - RMQRQUIETZONE < self.get_width()
+                    && y - RMQRQUIETZONE < self.get_height()
+                    && self.output_data[x - RMQRQUIETZONE][y - RMQRQUIETZONE]
+                        == SymbolStatus::LogicalTrue;
+                let color: &str = if is_dark { BLACK } else { BRIGHTWHITE };
+                write!(f, "{color}   {COLORSTOP}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+mod tests {
+    #[cfg(test)]
+    /// generates the settings of a rMQR code of the text with the given error correction level
+    fn rmqr_settings(text: &str, error_level: crate::input::ErrorLevel) -> crate::input::Settings {
+        use crate::input::{test_settings, Settings, SymbolType};
+
+        let mut settings: Settings = test_settings(text, error_level);
+        settings.symbol.symbol_type = SymbolType::Rmqr;
+        settings
+    }
+
+    #[cfg(test)]
+    /// reads the interleaved codewords back from the finished symbol
+    fn read_codewords(rmqr_data: &super::RmqrData) -> Vec<u8> {
+        use crate::standard_qr_code::qr_struct::SymbolStatus;
+
+        let mut unmasked: super::RmqrData = rmqr_data.clone();
+        unmasked.apply_mask();
+        let bits: Vec<bool> = unmasked
+            .data_module_positions()
+            .into_iter()
+            .map(|(x, y)| unmasked.output_data[x][y] == SymbolStatus::LogicalTrue)
+            .collect();
+        bits.chunks_exact(8)
+            .map(|chunk| {
+                chunk.iter().enumerate().fold(0, |codeword, (index, bit)| {
+                    codeword | ((*bit as u8) << (7 - index))
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rmqr_codewords() {
+        use super::RmqrData;
+        use crate::input::ErrorLevel;
+        use crate::rmqr_code::rmqr_code;
+
+        let mut settings = rmqr_settings("123456", ErrorLevel::M);
+        settings.symbol.version = Some(1);
+        let rmqr_data: RmqrData = rmqr_code(settings).unwrap();
+        assert_eq!((rmqr_data.get_width(), rmqr_data.get_height()), (43, 7));
+        // mode 001, 4 bits character count, 2 groups of 10 bits, terminator and padding
+        let data_codewords: Vec<u8> = vec![0x2c, 0x3d, 0xb9, 0x00, 0xec, 0x11];
+        assert_eq!(rmqr_data.data_codewords(), data_codewords);
+        let codewords: Vec<u8> = read_codewords(&rmqr_data);
+        assert_eq!(codewords.len(), 13);
+        assert_eq!(codewords[..6], data_codewords);
+        // R7x139-H has two blocks of 12 data codewords, which are interleaved
+        let mut settings = rmqr_settings(&"A".repeat(30), ErrorLevel::H);
+        settings.symbol.version = Some(5);
+        let rmqr_data: RmqrData = rmqr_code(settings).unwrap();
+        let data_codewords: Vec<u8> = rmqr_data.data_codewords();
+        assert_eq!(data_codewords.len(), 24);
+        let codewords: Vec<u8> = read_codewords(&rmqr_data);
+        assert_eq!(codewords.len(), 68);
+        for index in 0..12 {
+            assert_eq!(codewords[2 * index], data_codewords[index]);
+            assert_eq!(codewords[2 * index + 1], data_codewords[12 + index]);
+        }
+    }
+
+    #[test]
+    fn rmqr_version_selection() {
+        use super::RmqrData;
+        use crate::error::QrError;
+        use crate::input::{EciCharset, ErrorLevel};
+
+        let version = |text: &str, error_level: ErrorLevel| {
+            RmqrData::new(rmqr_settings(text, error_level))
+                .map(|data| (data.version, data.settings.error_level))
+        };
+        // R11x27 has fewer elements than R7x43
+        assert_eq!(version("", ErrorLevel::M), Ok((11, ErrorLevel::M)));
+        assert_eq!(version("", ErrorLevel::L), Ok((11, ErrorLevel::M)));
+        assert_eq!(version("", ErrorLevel::Q), Ok((11, ErrorLevel::H)));
+        // R11x27-M holds 7 data codewords, the next larger R7x43-M only 6
+        assert_eq!(version("hello", ErrorLevel::M), Ok((11, ErrorLevel::M)));
+        assert_eq!(version("hello!!", ErrorLevel::M), Ok((17, ErrorLevel::M)));
+        // R17x139-M holds 361 digits
+        assert_eq!(
+            version(&"1".repeat(361), ErrorLevel::M),
+            Ok((32, ErrorLevel::M))
+        );
+        assert_eq!(
+            version(&"1".repeat(362), ErrorLevel::M),
+            Err(QrError::DataTooLong {
+                needed_bits: 1219,
+                available_bits: 1216
+            })
+        );
+        let mut settings = rmqr_settings("1", ErrorLevel::M);
+        settings.symbol.version = Some(33);
+        assert_eq!(
+            RmqrData::new(settings.clone()).map(|data| data.version),
+            Err(QrError::InvalidVersion(33))
+        );
+        settings.symbol.version = Some(2);
+        settings.symbol.boost_ecl = true;
+        let rmqr_data: RmqrData = RmqrData::new(settings.clone()).unwrap();
+        assert_eq!(rmqr_data.version, 2);
+        assert_eq!(rmqr_data.settings.error_level, ErrorLevel::H);
+        settings.symbol.mask = Some(4);
+        assert!(matches!(
+            RmqrData::new(settings.clone()),
+            Err(QrError::UnsupportedOption(_))
+        ));
+        // rMQR codes know ECI and FNC1 just like standard qr codes
+        settings.symbol.mask = None;
+        settings.eci = Some(EciCharset::Iso8859_1);
+        let rmqr_data: RmqrData = RmqrData::new(settings).unwrap();
+        assert_eq!(rmqr_data.data_codewords()[0] >> 5, 0b111);
+    }
+
+    #[test]
+    fn rmqr_function_patterns() {
+        use super::{rmqr_format_information, RmqrData};
+        use crate::input::ErrorLevel;
+        use crate::rmqr_code::rmqr_code;
+
+        // the data bits 7 to 40 share their codewords with the version information
+        // of standard qr codes (ISO/IEC 18004 table D.1)
+        for (version, error_level, expected) in [
+            (1, ErrorLevel::M, 0x00000),
+            (8, ErrorLevel::M, 0x07C94),
+            (9, ErrorLevel::M, 0x085BC),
+            (32, ErrorLevel::M, 0x1F250),
+            (8, ErrorLevel::H, 0x27541),
+            (9, ErrorLevel::H, 0x28C69),
+        ] {
+            assert_eq!(rmqr_format_information(version, error_level), expected);
+        }
+        for version in 1..=32 {
+            for error_level in [ErrorLevel::M, ErrorLevel::H] {
+                assert_eq!(
+                    rmqr_format_information(version, error_level) >> 12,
+                    (version as u32 - 1) | ((error_level == ErrorLevel::H) as u32) << 5
+                );
+            }
+            // the codewords and up to 7 remainder bits fill up the symbol
+            let mut settings = rmqr_settings("1", ErrorLevel::H);
+            settings.symbol.version = Some(version);
+            let rmqr_data: RmqrData = rmqr_code(settings).unwrap();
+            let (width, height): (usize, usize) = (rmqr_data.get_width(), rmqr_data.get_height());
+            let modules: Vec<Vec<bool>> = rmqr_data.get_modules();
+            // finder pattern, finder sub pattern and corner finder patterns
+            let top_row: Vec<bool> = modules[..9].iter().map(|column| column[0]).collect();
+            assert_eq!(
+                top_row,
+                vec![true, true, true, true, true, true, true, false, true]
+            );
+            assert!(!modules[1][1] && modules[2][2]);
+            assert!(modules[width - 1][height - 1] && !modules[width - 2][height - 2]);
+            assert!(modules[width - 3][height - 3]);
+            assert!(modules[width - 2][0] && !modules[width - 2][1]);
+            assert!(modules[0][height - 1] && modules[2][height - 1]);
+            // the format information next to the finder pattern and the finder sub pattern
+            let format_information: u32 = rmqr_format_information(version, ErrorLevel::H);
+            let mut finder_side: u32 = 0;
+            let mut sub_pattern_side: u32 = 0;
+            for bit in 0..18 {
+                finder_side |= (modules[8 + bit / 5][1 + bit % 5] as u32) << bit;
+                let (x, y) = if bit < 15 {
+                    (width - 8 + bit / 5, height - 6 + bit % 5)
+                } else {
+                    (width - 20 + bit, height - 6)
+                };
+                sub_pattern_side |= (modules[x][y] as u32) << bit;
+            }
+            assert_eq!(finder_side ^ 0x1FAB2, format_information);
+            assert_eq!(sub_pattern_side ^ 0x20A7B, format_information);
+        }
+    }
+
+    #[test]
+    fn rmqr_reference_symbol() {
+        use super::RmqrData;
+        use crate::input::ErrorLevel;
+        use crate::rmqr_code::rmqr_code;
+
+        // R7x43-M of "123456", drawn module by module from ISO/IEC 23941 by a separate
+        // implementation with the data placed in column pairs from the second rightmost column
+        let expected: [&str; 7] = [
+            "#######.#.#.#.#.#.#.###.#.#.#.#.#.#.#.#.###",
+            "#.....#..#.#.....#..#.##....##..##.##...#.#",
+            "#.###.#.#.###...#######.##...##.#.#########",
+            "#.###.#..##...#..#.##.###..#######....#...#",
+            "#.###.#...#.#..####.###...#...###..#..#.#.#",
+            "#.....#.####...###.##.######..#.#####.#...#",
+            "#######.#.#.#.#.#.#.###.#.#.#.#.#.#.#.#####",
+        ];
+        let mut settings = rmqr_settings("123456", ErrorLevel::M);
+        settings.symbol.version = Some(1);
+        let rmqr_data: RmqrData = rmqr_code(settings).unwrap();
+        let modules: Vec<Vec<bool>> = rmqr_data.get_modules();
+        for (y, row) in expected.iter().enumerate() {
+            let drawn: String = (0..rmqr_data.get_width())
+                .map(|x| if modules[x][y] { '#' } else { '.' })
+                .collect();
+            assert_eq!(&drawn, row, "row {}", y);
+        }
+    }
+}
@@ -0,0 +1,572 @@
+// based on ISO/IEC 23941:2022 (rectangular micro qr code)
+
+use crate::input::ErrorLevel;
+use crate::standard_qr_code::encoding_modes::EncodingMode;
+use crate::standard_qr_code::qr_struct::ErrorBlockInfo;
+
+/// highest version of a rMQR code (R17x139)
+pub const MAXRMQRVERSION: u8 = 32;
+
+type RmqrVersionInfo = (
+    u8,
+    (u8, u8),
+    u16,
+    [u8; 4],
+    Vec<(ErrorLevel, Vec<ErrorBlockInfo>)>,
+);
+
+/// delivers a vector containing tuples which contain (version number, (height, width),
+/// codewords available, character count indicator lengths of numeric, alphanumeric,
+/// byte and kanji mode, error block information); the versions are ordered by height
+/// and then by width, the version number - 1 is written into the format information
+pub fn get_rmqr_version_info() -> Vec<RmqrVersionInfo> {
+    let all_version_info: Vec<RmqrVersionInfo> = vec![
+        // R7x43
+        (
+            1,
+            (7, 43),
+            13,
+            [4, 3, 3, 2],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 13, 6)]),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(1, 13, 3)]),
+            ],
+        ),
+        // R7x59
+        (
+            2,
+            (7, 59),
+            21,
+            [5, 5, 4, 3],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 21, 12)]),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(1, 21, 7)]),
+            ],
+        ),
+        // R7x77
+        (
+            3,
+            (7, 77),
+            32,
+            [6, 5, 5, 4],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 32, 20)]),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(1, 32, 10)]),
+            ],
+        ),
+        // R7x99
+        (
+            4,
+            (7, 99),
+            44,
+            [7, 6, 5, 5],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 44, 28)]),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(1, 44, 14)]),
+            ],
+        ),
+        // R7x139
+        (
+            5,
+            (7, 139),
+            68,
+            [7, 6, 6, 5],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 68, 44)]),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(2, 34, 12)]),
+            ],
+        ),
+        // R9x43
+        (
+            6,
+            (9, 43),
+            21,
+            [5, 5, 4, 3],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 21, 12)]),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(1, 21, 7)]),
+            ],
+        ),
+        // R9x59
+        (
+            7,
+            (9, 59),
+            33,
+            [6, 5, 5, 4],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 33, 21)]),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(1, 33, 11)]),
+            ],
+        ),
+        // R9x77
+        (
+            8,
+            (9, 77),
+            49,
+            [7, 6, 5, 5],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 49, 31)]),
+                (
+                    ErrorLevel::H,
+                    vec![ErrorBlockInfo::new(1, 24, 8), ErrorBlockInfo::new(1, 25, 9)],
+                ),
+            ],
+        ),
+        // R9x99
+        (
+            9,
+            (9, 99),
+            66,
+            [7, 6, 6, 5],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 66, 42)]),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(2, 33, 11)]),
+            ],
+        ),
+        // R9x139
+        (
+            10,
+            (9, 139),
+            99,
+            [8, 7, 6, 6],
+            vec![
+                (
+                    ErrorLevel::M,
+                    vec![
+                        ErrorBlockInfo::new(1, 49, 31),
+                        ErrorBlockInfo::new(1, 50, 32),
+                    ],
+                ),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(3, 33, 11)]),
+            ],
+        ),
+        // R11x27
+        (
+            11,
+            (11, 27),
+            15,
+            [4, 4, 3, 2],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 15, 7)]),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(1, 15, 5)]),
+            ],
+        ),
+        // R11x43
+        (
+            12,
+            (11, 43),
+            31,
+            [6, 5, 5, 4],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 31, 19)]),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(1, 31, 11)]),
+            ],
+        ),
+        // R11x59
+        (
+            13,
+            (11, 59),
+            47,
+            [7, 6, 5, 5],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 47, 31)]),
+                (
+                    ErrorLevel::H,
+                    vec![ErrorBlockInfo::new(1, 23, 7), ErrorBlockInfo::new(1, 24, 8)],
+                ),
+            ],
+        ),
+        // R11x77
+        (
+            14,
+            (11, 77),
+            67,
+            [7, 6, 6, 5],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 67, 43)]),
+                (
+                    ErrorLevel::H,
+                    vec![
+                        ErrorBlockInfo::new(1, 33, 11),
+                        ErrorBlockInfo::new(1, 34, 12),
+                    ],
+                ),
+            ],
+        ),
+        // R11x99
+        (
+            15,
+            (11, 99),
+            89,
+            [8, 7, 6, 6],
+            vec![
+                (
+                    ErrorLevel::M,
+                    vec![
+                        ErrorBlockInfo::new(1, 44, 28),
+                        ErrorBlockInfo::new(1, 45, 29),
+                    ],
+                ),
+                (
+                    ErrorLevel::H,
+                    vec![
+                        ErrorBlockInfo::new(1, 44, 14),
+                        ErrorBlockInfo::new(1, 45, 15),
+                    ],
+                ),
+            ],
+        ),
+        // R11x139
+        (
+            16,
+            (11, 139),
+            132,
+            [8, 7, 7, 6],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(3, 44, 28)]),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(3, 44, 14)]),
+            ],
+        ),
+        // R13x27
+        (
+            17,
+            (13, 27),
+            21,
+            [5, 5, 4, 3],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 21, 12)]),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(1, 21, 7)]),
+            ],
+        ),
+        // R13x43
+        (
+            18,
+            (13, 43),
+            41,
+            [6, 6, 5, 5],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 41, 27)]),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(1, 41, 13)]),
+            ],
+        ),
+        // R13x59
+        (
+            19,
+            (13, 59),
+            60,
+            [7, 6, 6, 5],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 60, 38)]),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(2, 30, 10)]),
+            ],
+        ),
+        // R13x77
+        (
+            20,
+            (13, 77),
+            85,
+            [7, 7, 6, 6],
+            vec![
+                (
+                    ErrorLevel::M,
+                    vec![
+                        ErrorBlockInfo::new(1, 42, 26),
+                        ErrorBlockInfo::new(1, 43, 27),
+                    ],
+                ),
+                (
+                    ErrorLevel::H,
+                    vec![
+                        ErrorBlockInfo::new(1, 42, 14),
+                        ErrorBlockInfo::new(1, 43, 15),
+                    ],
+                ),
+            ],
+        ),
+        // R13x99
+        (
+            21,
+            (13, 99),
+            113,
+            [8, 7, 7, 6],
+            vec![
+                (
+                    ErrorLevel::M,
+                    vec![
+                        ErrorBlockInfo::new(1, 56, 36),
+                        ErrorBlockInfo::new(1, 57, 37),
+                    ],
+                ),
+                (
+                    ErrorLevel::H,
+                    vec![
+                        ErrorBlockInfo::new(1, 37, 11),
+                        ErrorBlockInfo::new(2, 38, 12),
+                    ],
+                ),
+            ],
+        ),
+        // R13x139
+        (
+            22,
+            (13, 139),
+            166,
+            [8, 8, 7, 7],
+            vec![
+                (
+                    ErrorLevel::M,
+                    vec![
+                        ErrorBlockInfo::new(2, 55, 35),
+                        ErrorBlockInfo::new(1, 56, 36),
+                    ],
+                ),
+                (
+                    ErrorLevel::H,
+                    vec![
+                        ErrorBlockInfo::new(2, 41, 13),
+                        ErrorBlockInfo::new(2, 42, 14),
+                    ],
+                ),
+            ],
+        ),
+        // R15x43
+        (
+            23,
+            (15, 43),
+            51,
+            [7, 6, 6, 5],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 51, 33)]),
+                (
+                    ErrorLevel::H,
+                    vec![ErrorBlockInfo::new(1, 25, 7), ErrorBlockInfo::new(1, 26, 8)],
+                ),
+            ],
+        ),
+        // R15x59
+        (
+            24,
+            (15, 59),
+            74,
+            [7, 7, 6, 5],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(1, 74, 48)]),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(2, 37, 13)]),
+            ],
+        ),
+        // R15x77
+        (
+            25,
+            (15, 77),
+            103,
+            [8, 7, 7, 6],
+            vec![
+                (
+                    ErrorLevel::M,
+                    vec![
+                        ErrorBlockInfo::new(1, 51, 33),
+                        ErrorBlockInfo::new(1, 52, 34),
+                    ],
+                ),
+                (
+                    ErrorLevel::H,
+                    vec![
+                        ErrorBlockInfo::new(2, 34, 10),
+                        ErrorBlockInfo::new(1, 35, 11),
+                    ],
+                ),
+            ],
+        ),
+        // R15x99
+        (
+            26,
+            (15, 99),
+            136,
+            [8, 7, 7, 6],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(2, 68, 44)]),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(4, 34, 12)]),
+            ],
+        ),
+        // R15x139
+        (
+            27,
+            (15, 139),
+            199,
+            [9, 8, 7, 7],
+            vec![
+                (
+                    ErrorLevel::M,
+                    vec![
+                        ErrorBlockInfo::new(2, 66, 42),
+                        ErrorBlockInfo::new(1, 67, 43),
+                    ],
+                ),
+                (
+                    ErrorLevel::H,
+                    vec![
+                        ErrorBlockInfo::new(1, 39, 13),
+                        ErrorBlockInfo::new(4, 40, 14),
+                    ],
+                ),
+            ],
+        ),
+        // R17x43
+        (
+            28,
+            (17, 43),
+            61,
+            [7, 6, 6, 5],
+            vec![
+                (
+                    ErrorLevel::M,
+                    vec![
+                        ErrorBlockInfo::new(1, 30, 18),
+                        ErrorBlockInfo::new(1, 31, 19),
+                    ],
+                ),
+                (
+                    ErrorLevel::H,
+                    vec![
+                        ErrorBlockInfo::new(1, 30, 10),
+                        ErrorBlockInfo::new(1, 31, 11),
+                    ],
+                ),
+            ],
+        ),
+        // R17x59
+        (
+            29,
+            (17, 59),
+            88,
+            [8, 7, 6, 6],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(2, 44, 28)]),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(2, 44, 14)]),
+            ],
+        ),
+        // R17x77
+        (
+            30,
+            (17, 77),
+            122,
+            [8, 7, 7, 6],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(2, 61, 39)]),
+                (
+                    ErrorLevel::H,
+                    vec![
+                        ErrorBlockInfo::new(1, 40, 12),
+                        ErrorBlockInfo::new(2, 41, 13),
+                    ],
+                ),
+            ],
+        ),
+        // R17x99
+        (
+            31,
+            (17, 99),
+            160,
+            [8, 8, 7, 6],
+            vec![
+                (
+                    ErrorLevel::M,
+                    vec![
+                        ErrorBlockInfo::new(2, 53, 33),
+                        ErrorBlockInfo::new(1, 54, 34),
+                    ],
+                ),
+                (ErrorLevel::H, vec![ErrorBlockInfo::new(4, 40, 14)]),
+            ],
+        ),
+        // R17x139
+        (
+            32,
+            (17, 139),
+            232,
+            [9, 8, 8, 7],
+            vec![
+                (ErrorLevel::M, vec![ErrorBlockInfo::new(4, 58, 38)]),
+                (
+                    ErrorLevel::H,
+                    vec![
+                        ErrorBlockInfo::new(2, 38, 12),
+                        ErrorBlockInfo::new(4, 39, 13),
+                    ],
+                ),
+            ],
+        ),
+    ];
+    all_version_info
+}
+
+/// returns the columns of the alignment patterns at the top and the bottom edge,
+/// which are connected by a vertical timing pattern
+pub fn rmqr_alignment_columns(width: u8) -> Vec<u8> {
+    match width {
+        27 => vec![],
+        43 => vec![21],
+        59 => vec![19, 39],
+        77 => vec![25, 51],
+        99 => vec![23, 49, 75],
+        139 => vec![27, 55, 83, 111],
+        _ => panic!("rMQR codes can't be {width} elements wide"),
+    }
+}
+
+/// returns the 3 bit mode indicator
+pub fn rmqr_mode_indicator(mode: EncodingMode) -> u8 {
+    match mode {
+        EncodingMode::Numeric => 0b001,
+        EncodingMode::Alphanumeric => 0b010,
+        EncodingMode::Byte => 0b011,
+        EncodingMode::Kanji => 0b100,
+        EncodingMode::Fnc1First => 0b101,
+        EncodingMode::Fnc1Second => 0b110,
+        EncodingMode::Eci => 0b111,
+        EncodingMode::StructuredAppend => panic!("rMQR codes can't be structured appended"),
+    }
+}
+
+/// returns the length of the character count indicator of the mode in bits
+pub fn rmqr_character_count_bits(mode: EncodingMode, character_count_bits: &[u8; 4]) -> u8 {
+    match mode {
+        EncodingMode::Numeric => character_count_bits[0],
+        EncodingMode::Alphanumeric => character_count_bits[1],
+        EncodingMode::Byte => character_count_bits[2],
+        EncodingMode::Kanji => character_count_bits[3],
+        _ => 0,
+    }
+}
+
+#[test]
+#[cfg(test)]
+fn sanitycheck_rmqr_version_information() {
+    let info: Vec<RmqrVersionInfo> = get_rmqr_version_info();
+    assert_eq!(info.len(), MAXRMQRVERSION as usize);
+    for (index, (version, (height, width), codewords, _, error_info)) in info.iter().enumerate() {
+        assert_eq!(*version as usize, index + 1);
+        assert!([7, 9, 11, 13, 15, 17].contains(height));
+        // the alignment patterns are spread evenly, a version exists for every width
+        assert!(!rmqr_alignment_columns(*width).contains(&0));
+        assert_eq!(error_info.len(), 2);
+        for (_, blocks) in error_info.iter() {
+            let mut current_memory: u16 = 0;
+            for block in blocks.iter() {
+                current_memory +=
+                    block.num_block as u16 * (block.num_data_bytes + block.num_error_bytes) as u16;
+                // all blocks have the same number of error correction bytes
+                assert_eq!(block.num_error_bytes, blocks[0].num_error_bytes);
+            }
+            assert_eq!(current_memory, *codewords);
+        }
+    }
+    // the character count indicators grow with the width of the symbol
+    for pair in info.windows(2) {
+        if pair[0].1 .0 == pair[1].1 .0 {
+            for (previous, bits) in pair[0].3.iter().zip(pair[1].3.iter()) {
+                assert!(previous <= bits);
+            }
+        }
+    }
+}
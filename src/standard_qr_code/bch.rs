@@ -0,0 +1,112 @@
+/// generator polynomial of the BCH(15,5) code of the format information
+const FORMATGENERATOR: u32 = 0x537;
+/// generator polynomial of the BCH(18,6) code of the version information
+const VERSIONGENERATOR: u32 = 0x1F25;
+/// mask that is applied onto the format information, so it is never all white
+const FORMATMASK: u16 = 0x5412;
+/// maximum number of wrong bits in the format and version information that can be corrected
+const MAXINFORMATIONERRORS: u32 = 3;
+
+/// returns the data followed by the remainder of data * x^n divided by the generator
+/// polynomial of degree n
+pub(crate) fn bch_code(data: u32, generator: u32) -> u32 {
+    let check_bits: u32 = generator.ilog2();
+    let mut remainder: u32 = data << check_bits;
+    while remainder != 0 && remainder.ilog2() >= check_bits {
+        remainder ^= generator << (remainder.ilog2() - check_bits);
+    }
+    (data << check_bits) | remainder
+}
+
+/// returns the 15 bit BCH(15,5) codeword of the 5 data bits (without mask)
+pub fn bch_15_5(data: u8) -> u16 {
+    bch_code((data & 0b1_1111) as u32, FORMATGENERATOR) as u16
+}
+
+/// returns the 18 bit BCH(18,6) codeword of the 6 data bits
+pub fn bch_18_6(data: u8) -> u32 {
+    bch_code((data & 0b11_1111) as u32, VERSIONGENERATOR)
+}
+
+/// returns the masked format information of the error correction level bits
+/// followed by the 3 mask bits
+pub fn encode_format_information(data: u8) -> u16 {
+    bch_15_5(data) ^ FORMATMASK
+}
+
+/// returns the version information of the version (only written for version 7 and up)
+pub fn encode_version_information(version: u8) -> u32 {
+    bch_18_6(version)
+}
+
+/// returns the candidate with the codeword closest to the bits and the number of bits
+/// that differ, if it can be corrected
+fn nearest_codeword(bits: u32, candidates: impl Iterator<Item = (u8, u32)>) -> Option<(u8, u32)> {
+    candidates
+        .map(|(data, codeword)| (data, (bits ^ codeword).count_ones()))
+        .min_by_key(|(_, distance)| *distance)
+        .filter(|(_, distance)| *distance <= MAXINFORMATIONERRORS)
+}
+
+/// returns the 5 data bits of the read format information and the number of corrected bits
+/// (up to 3)
+pub fn decode_format_information(bits: u16) -> Option<(u8, u32)> {
+    nearest_codeword(
+        bits as u32,
+        (0..32).map(|data| (data, encode_format_information(data) as u32)),
+    )
+}
+
+/// returns the version of the read version information and the number of corrected bits
+/// (up to 3)
+pub fn decode_version_information(bits: u32) -> Option<(u8, u32)> {
+    nearest_codeword(
+        bits,
+        (7..=40).map(|version| (version, encode_version_information(version))),
+    )
+}
+
+mod tests {
+    #[test]
+    fn format_information_table() {
+        use super::{decode_format_information, encode_format_information};
+        use crate::standard_qr_code::version_constants::_information_sequences;
+
+        for data in 0..32 {
+            let codeword: u16 = encode_format_information(data);
+            assert_eq!(codeword, _information_sequences(data));
+            assert_eq!(decode_format_information(codeword), Some((data, 0)));
+            // up to 3 wrong bits are corrected
+            for errors in [0b1_u16, 0b100_0000_0000_0001, 0b010_0100_0010_0000] {
+                let corrected: u32 = errors.count_ones();
+                assert_eq!(
+                    decode_format_information(codeword ^ errors),
+                    Some((data, corrected))
+                );
+            }
+        }
+        // the mask keeps the format information from being all white
+        assert_eq!(encode_format_information(0), 0x5412);
+    }
+
+    #[test]
+    fn version_information_table() {
+        use super::{decode_version_information, encode_version_information};
+        use crate::standard_qr_code::version_constants::_version_info;
+
+        for version in 7..=40 {
+            let codeword: u32 = encode_version_information(version);
+            assert_eq!(codeword, _version_info(version));
+            assert_eq!(decode_version_information(codeword), Some((version, 0)));
+            assert_eq!(
+                decode_version_information(codeword ^ 0b10_0000_1000_0000_0001),
+                Some((version, 3))
+            );
+        }
+        // version 1 to 6 don't have version information
+        assert_eq!(
+            decode_version_information(encode_version_information(3)),
+            None
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use crate::error::QrError;
+use crate::input::ErrorLevel;
+use crate::standard_qr_code::encoding_modes::EncodingMode;
+use crate::standard_qr_code::qr_struct::ErrorBlockInfo;
+use crate::standard_qr_code::version_constants::get_error_block_info;
+
+/// returns the number of elements of the version that hold codewords and remainder bits,
+/// which is everything but the function patterns, the format and the version information
+pub fn raw_data_modules(version: u8) -> usize {
+    let version: usize = version as usize;
+    // all elements without the finder patterns, separators, timing patterns,
+    // format information and the black element
+    let mut modules: usize = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        // alignment patterns without the parts overlapping the timing patterns
+        let alignments_per_line: usize = version / 7 + 2;
+        modules -= (25 * alignments_per_line - 10) * alignments_per_line - 55;
+        // both copies of the version information
+        if version >= 7 {
+            modules -= 36;
+        }
+    }
+    modules
+}
+
+/// returns the number of error correction codewords of the version and level
+fn error_codewords(version: u8, ec_level: ErrorLevel) -> Result<usize, QrError> {
+    let error_blocks: Vec<ErrorBlockInfo> = get_error_block_info()
+        .into_iter()
+        .find(|version_info| version_info.0 == version)
+        .and_then(|version_info| {
+            version_info
+                .2
+                .into_iter()
+                .find(|(error_enum, _)| *error_enum == ec_level)
+        })
+        .map(|(_, error_blocks)| error_blocks)
+        .ok_or(QrError::InvalidVersion(version))?;
+    Ok(error_blocks
+        .iter()
+        .map(|block| block.num_block as usize * block.num_error_bytes as usize)
+        .sum())
+}
+
+/// returns the number of bits the version holds at the error correction level
+/// (including the mode indicators and character count indicators)
+pub fn data_bits(version: u8, ec_level: ErrorLevel) -> Result<usize, QrError> {
+    if !(1..=40).contains(&version) {
+        return Err(QrError::InvalidVersion(version));
+    }
+    // the remainder bits don't make up a whole codeword
+    let total_codewords: usize = raw_data_modules(version) / 8;
+    Ok((total_codewords - error_codewords(version, ec_level)?) * 8)
+}
+
+/// returns how many characters fit into the version at the error correction level,
+/// if they are written in a single segment of the mode
+pub fn capacity(version: u8, ec_level: ErrorLevel, mode: EncodingMode) -> Result<usize, QrError> {
+    if !mode.is_data() {
+        return Err(QrError::UnsupportedOption(format!(
+            "{mode:?} mode doesn't hold any characters"
+        )));
+    }
+    let character_count_bits: u8 = mode.character_count_bits(version);
+    let header_bits: usize = 4 + character_count_bits as usize;
+    let Some(bits) = data_bits(version, ec_level)?.checked_sub(header_bits) else {
+        return Ok(0);
+    };
+    // the reverse of EncodingMode::data_bits
+    let characters: usize = match mode {
+        EncodingMode::Numeric => {
+            (bits / 10) * 3
+                + match bits % 10 {
+                    7.. => 2,
+                    4.. => 1,
+                    _ => 0,
+                }
+        }
+        EncodingMode::Alphanumeric => (bits / 11) * 2 + (bits % 11 >= 6) as usize,
+        EncodingMode::Kanji => bits / 13,
+        _ => bits / 8,
+    };
+    // the character count indicator limits the characters as well
+    Ok(characters.min((1 << character_count_bits) - 1))
+}
+
+mod tests {
+    #[test]
+    fn raw_module_formula() {
+        use super::{data_bits, raw_data_modules};
+        use crate::standard_qr_code::version_constants::get_error_block_info;
+
+        // remainder bits of the versions 1 to 40
+        let remainder_bits: [usize; 40] = [
+            0, 7, 7, 7, 7, 7, 0, 0, 0, 0, 0, 0, 0, 3, 3, 3, 3, 3, 3, 3, 4, 4, 4, 4, 4, 4, 4, 3, 3,
+            3, 3, 3, 3, 3, 0, 0, 0, 0, 0, 0,
+        ];
+        for (version, total_codewords, levels) in get_error_block_info() {
+            let raw_modules: usize = raw_data_modules(version);
+            assert_eq!(raw_modules / 8, total_codewords as usize);
+            assert_eq!(raw_modules % 8, remainder_bits[version as usize - 1]);
+            // the data codewords of the table are all codewords without error correction
+            for (error_level, error_blocks) in levels {
+                let table_bits: usize = error_blocks
+                    .iter()
+                    .map(|block| block.num_block as usize * block.num_data_bytes as usize * 8)
+                    .sum();
+                assert_eq!(data_bits(version, error_level), Ok(table_bits));
+            }
+        }
+    }
+
+    #[test]
+    fn capacity_table() {
+        use super::{capacity, data_bits};
+        use crate::error::QrError;
+        use crate::input::ErrorLevel;
+        use crate::standard_qr_code::encoding_modes::EncodingMode;
+
+        let modes: [EncodingMode; 4] = [
+            EncodingMode::Numeric,
+            EncodingMode::Alphanumeric,
+            EncodingMode::Byte,
+            EncodingMode::Kanji,
+        ];
+        for (version, error_level, expected) in [
+            (1, ErrorLevel::L, [41, 25, 17, 10]),
+            (1, ErrorLevel::H, [17, 10, 7, 4]),
+            (10, ErrorLevel::M, [513, 311, 213, 131]),
+            (40, ErrorLevel::L, [7089, 4296, 2953, 1817]),
+            (40, ErrorLevel::H, [3057, 1852, 1273, 784]),
+        ] {
+            for (mode, characters) in modes.iter().zip(expected) {
+                assert_eq!(capacity(version, error_level, *mode), Ok(characters));
+                // the characters fit, one more doesn't
+                let available_bits: usize = data_bits(version, error_level).unwrap();
+                assert!(mode.total_bits(characters, version) <= available_bits);
+                assert!(mode.total_bits(characters + 1, version) > available_bits);
+            }
+        }
+        assert_eq!(data_bits(1, ErrorLevel::M), Ok(128));
+        assert_eq!(
+            data_bits(41, ErrorLevel::M),
+            Err(QrError::InvalidVersion(41))
+        );
+        assert!(capacity(1, ErrorLevel::L, EncodingMode::Eci).is_err());
+    }
+}
@@ -0,0 +1,644 @@
+use crate::error::QrError;
+use crate::galois_field::GaloisFields;
+use crate::input::{EciCharset, ErrorLevel};
+use crate::polynomials::{Indeterminate, Polynomial};
+use crate::standard_qr_code::bch::{decode_format_information, decode_version_information};
+use crate::standard_qr_code::eci::MAXECIASSIGNMENT;
+use crate::standard_qr_code::encoding_modes::{
+    shift_jis_character, shift_jis_from_kanji, EncodingMode, ALPHANUMERICTABLE, CHARACTERBITS,
+    KANJIBITS,
+};
+use crate::standard_qr_code::gs1::GROUPSEPARATOR;
+use crate::standard_qr_code::qr_struct::{mask_condition, ErrorBlockInfo, QRData, SymbolStatus};
+use crate::standard_qr_code::segmentation::Segment;
+
+use super::draw_function_patterns;
+
+/// everything read from a symbol
+#[derive(Debug, Clone, PartialEq)]
+pub struct DecodedSymbol {
+    /// version of the symbol
+    pub version: u8,
+    /// error correction level of the symbol
+    pub error_level: ErrorLevel,
+    /// the mask that was applied to the symbol
+    pub mask: u8,
+    /// the segments the data consists of
+    pub segments: Vec<Segment>,
+    /// number of codewords that had to be corrected
+    pub corrected_errors: usize,
+}
+
+impl DecodedSymbol {
+    /// converts the segments back into the text; byte mode data is read as UTF-8
+    /// unless an ECI selects another character set
+    pub fn text(&self) -> Result<String, QrError> {
+        let mut text: String = String::new();
+        let mut charset: EciCharset = EciCharset::Utf8;
+        let mut is_fnc1: bool = false;
+        for segment in self.segments.iter() {
+            match segment.mode {
+                EncodingMode::Numeric => text.extend(segment.data.iter().map(|byte| *byte as char)),
+                EncodingMode::Alphanumeric if is_fnc1 => {
+                    // '%' is a group separator and "%%" a literal '%'
+                    let mut characters = segment.data.iter().peekable();
+                    while let Some(character) = characters.next() {
+                        if *character != b'%' {
+                            text.push(*character as char);
+                        } else if characters.peek() == Some(&&b'%') {
+                            characters.next();
+                            text.push('%');
+                        } else {
+                            text.push(GROUPSEPARATOR);
+                        }
+                    }
+                }
+                EncodingMode::Alphanumeric => {
+                    text.extend(segment.data.iter().map(|byte| *byte as char))
+                }
+                EncodingMode::Byte => text.push_str(&charset.decode_bytes(&segment.data)?),
+                EncodingMode::Kanji => {
+                    for shift_jis in segment.data.chunks(2) {
+                        let value: u16 = u16::from_be_bytes([shift_jis[0], shift_jis[1]]);
+                        match shift_jis_character(value) {
+                            Some(character) => text.push(character),
+                            None => {
+                                return Err(QrError::InvalidSymbol(format!(
+                                    "invalid kanji {value:#06x}"
+                                )))
+                            }
+                        }
+                    }
+                }
+                EncodingMode::Eci => {
+                    let assignment_number: u32 = eci_assignment_number(&segment.data);
+                    match EciCharset::from_assignment_number(assignment_number) {
+                        Some(eci_charset) => charset = eci_charset,
+                        None => return Err(QrError::UnsupportedEci(assignment_number)),
+                    }
+                }
+                EncodingMode::Fnc1First | EncodingMode::Fnc1Second => is_fnc1 = true,
+                EncodingMode::StructuredAppend => (),
+            }
+        }
+        Ok(text)
+    }
+}
+
+/// reads the bits of the data codewords one after another
+struct BitReader<'a> {
+    data: &'a [u8],
+    /// index of the next bit
+    position: usize,
+}
+
+impl BitReader<'_> {
+    /// returns the number of bits that haven't been read yet
+    fn remaining(&self) -> usize {
+        self.data.len() * 8 - self.position
+    }
+
+    /// reads the next bits as a number (most significant bit first)
+    fn read(&mut self, size: u8) -> Result<u32, QrError> {
+        if self.remaining() < size as usize {
+            return Err(QrError::InvalidSymbol(String::from(
+                "data ends in the middle of a segment",
+            )));
+        }
+        let mut value: u32 = 0;
+        for _ in 0..size {
+            let bit: u8 = (self.data[self.position / 8] << (self.position % 8)) & 0b1000_0000;
+            value = (value << 1) | (bit > 0) as u32;
+            self.position += 1;
+        }
+        Ok(value)
+    }
+}
+
+/// returns the assignment number stored in the designator of an ECI segment
+fn eci_assignment_number(designator: &[u8]) -> u32 {
+    let mut value: u32 = 0;
+    for byte in designator.iter() {
+        value = (value << 8) | *byte as u32;
+    }
+    // remove the length bits in front of the number
+    match designator.len() {
+        1 => value,
+        2 => value & 0x3FFF,
+        _ => value & 0x1F_FFFF,
+    }
+}
+
+/// reads the bits at the given positions as a number (first position is the most significant bit)
+fn read_bits(modules: &[Vec<bool>], positions: &[(usize, usize)]) -> u32 {
+    let mut value: u32 = 0;
+    for (x, y) in positions.iter() {
+        value = (value << 1) | modules[*x][*y] as u32;
+    }
+    value
+}
+
+/// reads both copies of the format information and returns the error correction level and mask
+fn read_format_information(modules: &[Vec<bool>]) -> Result<(ErrorLevel, u8), QrError> {
+    let width: usize = modules.len();
+    // bit 14 to bit 0 next to the top left finder pattern
+    let top_left: [(usize, usize); 15] = [
+        (0, 8),
+        (1, 8),
+        (2, 8),
+        (3, 8),
+        (4, 8),
+        (5, 8),
+        (7, 8),
+        (8, 8),
+        (8, 7),
+        (8, 5),
+        (8, 4),
+        (8, 3),
+        (8, 2),
+        (8, 1),
+        (8, 0),
+    ];
+    // bit 14 to bit 0 next to the other two finder patterns
+    let mut other: Vec<(usize, usize)> = (0..7).map(|index| (8, width - 1 - index)).collect();
+    other.extend((0..8).map(|index| (width - 8 + index, 8)));
+    // the copy with fewer wrong bits is used
+    let best: Option<(u8, u32)> = [read_bits(modules, &top_left), read_bits(modules, &other)]
+        .into_iter()
+        .filter_map(|copy| decode_format_information(copy as u16))
+        .min_by_key(|(_, corrected)| *corrected);
+    match best {
+        Some((data, _)) => {
+            let error_level: ErrorLevel = match data >> 3 {
+                0b01 => ErrorLevel::L,
+                0b00 => ErrorLevel::M,
+                0b11 => ErrorLevel::Q,
+                _ => ErrorLevel::H,
+            };
+            Ok((error_level, data & 0b111))
+        }
+        _ => Err(QrError::InvalidSymbol(String::from(
+            "format information couldn't be read",
+        ))),
+    }
+}
+
+/// reads both copies of the version information (version 7 and up) and checks them
+/// against the version calculated from the width
+fn check_version_information(modules: &[Vec<bool>], version: u8) -> Result<(), QrError> {
+    if version < 7 {
+        return Ok(());
+    }
+    let width: usize = modules.len();
+    // bit 17 to bit 0 above the bottom left and left of the top right finder pattern
+    let bottom_left: Vec<(usize, usize)> = (0..18)
+        .rev()
+        .map(|bit| (bit / 3, width - 11 + bit % 3))
+        .collect();
+    let top_right: Vec<(usize, usize)> = bottom_left.iter().map(|(x, y)| (*y, *x)).collect();
+    for copy in [
+        read_bits(modules, &bottom_left),
+        read_bits(modules, &top_right),
+    ] {
+        if let Some((candidate, _)) = decode_version_information(copy) {
+            if candidate != version {
+                return Err(QrError::InvalidSymbol(format!(
+                    "version information ({candidate}) doesn't match the width (version {version})"
+                )));
+            }
+            return Ok(());
+        }
+    }
+    Err(QrError::InvalidSymbol(String::from(
+        "version information couldn't be read",
+    )))
+}
+
+/// corrects a block of data and error correction codewords (the first codeword having
+/// the highest degree) and returns all of its codewords
+fn correct_block(
+    galois_field: &GaloisFields,
+    block: &[u8],
+    num_error_bytes: u8,
+) -> Result<Vec<u8>, QrError> {
+    let corrected: Polynomial =
+        galois_field.correct_errors(&Polynomial::from(block.to_vec()), num_error_bytes, &[])?;
+    // codewords of value 0 keep their place, as the coefficients are read by degree
+    let mut codewords: Vec<u8> = GaloisFields::coefficients_by_degree(&corrected);
+    codewords.resize(block.len(), 0);
+    codewords.reverse();
+    Ok(codewords)
+}
+
+/// splits the codewords up into the blocks they were interleaved from
+fn deinterleave(codewords: &[u8], error_blocks: &[ErrorBlockInfo]) -> Vec<Vec<u8>> {
+    let mut block_infos: Vec<ErrorBlockInfo> = vec![];
+    for block in error_blocks.iter() {
+        for _ in 0..block.num_block {
+            block_infos.push(*block);
+        }
+    }
+    let mut blocks: Vec<Vec<u8>> = vec![vec![]; block_infos.len()];
+    let mut codeword_iter = codewords.iter();
+    // the data codewords of all blocks come first (shorter blocks are skipped at the end),
+    // followed by the error correction codewords
+    let max_data_bytes: u8 = block_infos
+        .iter()
+        .map(|block| block.num_data_bytes)
+        .max()
+        .unwrap_or(0);
+    for index in 0..max_data_bytes {
+        for (block, info) in blocks.iter_mut().zip(block_infos.iter()) {
+            if index < info.num_data_bytes {
+                block.extend(codeword_iter.next());
+            }
+        }
+    }
+    let num_error_bytes: u8 = block_infos[0].num_error_bytes;
+    for _ in 0..num_error_bytes {
+        for block in blocks.iter_mut() {
+            block.extend(codeword_iter.next());
+        }
+    }
+    blocks
+}
+
+/// reads the segments from the data codewords
+fn parse_segments(data: &[u8], version: u8) -> Result<Vec<Segment>, QrError> {
+    let mut reader: BitReader = BitReader { data, position: 0 };
+    let mut segments: Vec<Segment> = vec![];
+    // the terminator may be shortened or left out at the end of the data
+    while reader.remaining() >= 4 {
+        let indicator: u8 = reader.read(4)? as u8;
+        let mode: EncodingMode = match indicator {
+            0b0000 => break,
+            0b0001 => EncodingMode::Numeric,
+            0b0010 => EncodingMode::Alphanumeric,
+            0b0100 => EncodingMode::Byte,
+            0b1000 => EncodingMode::Kanji,
+            0b0111 => EncodingMode::Eci,
+            0b0011 => EncodingMode::StructuredAppend,
+            0b0101 => EncodingMode::Fnc1First,
+            0b1001 => EncodingMode::Fnc1Second,
+            _ => {
+                return Err(QrError::InvalidSymbol(format!(
+                    "unknown mode indicator {indicator:04b}"
+                )))
+            }
+        };
+        let num_characters: u32 = reader.read(mode.character_count_bits(version))?;
+        let segment: Segment = match mode {
+            EncodingMode::Numeric => {
+                let mut digits: Vec<u8> = vec![];
+                let mut remaining: u32 = num_characters;
+                while remaining > 0 {
+                    let (group_size, group_bits): (u32, u8) = match remaining {
+                        1 => (1, 4),
+                        2 => (2, 7),
+                        _ => (3, 10),
+                    };
+                    let group: u32 = reader.read(group_bits)?;
+                    if group >= 10_u32.pow(group_size) {
+                        return Err(QrError::InvalidSymbol(format!(
+                            "invalid digits {group} in numeric mode"
+                        )));
+                    }
+                    let group_text: String =
+                        format!("{group:0width$}", width = group_size as usize);
+                    digits.extend(group_text.bytes());
+                    remaining -= group_size;
+                }
+                Segment::new(mode, digits)
+            }
+            EncodingMode::Alphanumeric => {
+                let table: &[u8] = ALPHANUMERICTABLE.as_bytes();
+                let character = |value: u32| -> Result<u8, QrError> {
+                    table
+                        .get(value as usize)
+                        .copied()
+                        .ok_or(QrError::InvalidSymbol(format!(
+                            "invalid alphanumeric value {value}"
+                        )))
+                };
+                let mut characters: Vec<u8> = vec![];
+                let mut remaining: u32 = num_characters;
+                while remaining > 0 {
+                    if remaining == 1 {
+                        characters.push(character(reader.read(6)?)?);
+                        remaining -= 1;
+                    } else {
+                        let value: u32 = reader.read(11)?;
+                        characters.push(character(value / 45)?);
+                        characters.push(character(value % 45)?);
+                        remaining -= 2;
+                    }
+                }
+                Segment::new(mode, characters)
+            }
+            EncodingMode::Byte => {
+                let mut bytes: Vec<u8> = vec![];
+                for _ in 0..num_characters {
+                    bytes.push(reader.read(CHARACTERBITS)? as u8);
+                }
+                Segment::new(mode, bytes)
+            }
+            EncodingMode::Kanji => {
+                let mut bytes: Vec<u8> = vec![];
+                for _ in 0..num_characters {
+                    let kanji: u16 = reader.read(KANJIBITS)? as u16;
+                    bytes.extend(shift_jis_from_kanji(kanji).to_be_bytes());
+                }
+                Segment::new(mode, bytes)
+            }
+            EncodingMode::Eci => {
+                // the leading bits of the first byte tell the length of the designator
+                let first: u32 = reader.read(CHARACTERBITS)?;
+                let assignment_number: u32 = if first & 0b1000_0000 == 0 {
+                    first
+                } else if first & 0b0100_0000 == 0 {
+                    ((first & 0b0011_1111) << 8) | reader.read(8)?
+                } else {
+                    ((first & 0b0001_1111) << 16) | reader.read(16)?
+                };
+                if assignment_number > MAXECIASSIGNMENT {
+                    return Err(QrError::UnsupportedEci(assignment_number));
+                }
+                Segment::eci(assignment_number)
+            }
+            EncodingMode::StructuredAppend => {
+                let index: u8 = reader.read(4)? as u8;
+                let total_symbols: u8 = reader.read(4)? as u8 + 1;
+                let parity: u8 = reader.read(8)? as u8;
+                if index >= total_symbols {
+                    return Err(QrError::InvalidSymbol(format!(
+                        "symbol {index} of a structured append with {total_symbols} symbols"
+                    )));
+                }
+                Segment::structured_append(index, total_symbols, parity)
+            }
+            EncodingMode::Fnc1First => Segment::fnc1_first(),
+            EncodingMode::Fnc1Second => Segment::fnc1_second(reader.read(8)? as u8),
+        };
+        segments.push(segment);
+    }
+    Ok(segments)
+}
+
+/// decodes a symbol given as elements indexed with [x][y] (true is black),
+/// which is surrounded by a quiet zone of the given width
+pub fn decode(grid: &[Vec<SymbolStatus>], quiet_zone: usize) -> Result<DecodedSymbol, QrError> {
+    if grid.len() < 2 * quiet_zone + 21 {
+        return Err(QrError::InvalidSymbol(String::from("symbol is too small")));
+    }
+    let width: usize = grid.len() - 2 * quiet_zone;
+    if !(width - 17).is_multiple_of(4) || width > 177 {
+        return Err(QrError::InvalidSymbol(format!(
+            "width {width} doesn't belong to any version"
+        )));
+    }
+    let version: u8 = ((width - 17) / 4) as u8;
+    // strip the quiet zone
+    let mut modules: Vec<Vec<bool>> = vec![vec![false; width]; width];
+    for (x, column) in modules.iter_mut().enumerate() {
+        if grid[x + quiet_zone].len() != grid.len() {
+            return Err(QrError::InvalidSymbol(String::from("symbol isn't square")));
+        }
+        for (y, module) in column.iter_mut().enumerate() {
+            *module = match grid[x + quiet_zone][y + quiet_zone] {
+                SymbolStatus::LogicalTrue => true,
+                SymbolStatus::LogicalFalse => false,
+                SymbolStatus::Uninitialised => {
+                    return Err(QrError::InvalidSymbol(format!(
+                        "element at x: {x}, y: {y} is uninitialised"
+                    )))
+                }
+            };
+        }
+    }
+    let (error_level, mask) = read_format_information(&modules)?;
+    check_version_information(&modules, version)?;
+    // the empty symbol of the same version tells which elements contain data
+    let mut template: QRData = QRData::empty(version, error_level)?;
+    draw_function_patterns(&mut template);
+    let error_blocks: Vec<ErrorBlockInfo> = template.get_error_info().clone();
+    let total_codewords: usize = error_blocks
+        .iter()
+        .map(|block| {
+            block.num_block as usize * (block.num_data_bytes + block.num_error_bytes) as usize
+        })
+        .sum();
+    // read the codewords in the order they were written and remove the mask
+    let mut codewords: Vec<u8> = vec![0; total_codewords];
+    for (bit_index, (x, y)) in template
+        .data_module_positions()
+        .into_iter()
+        .take(total_codewords * 8)
+        .enumerate()
+    {
+        if modules[x][y] ^ mask_condition(mask, y, x) {
+            codewords[bit_index / 8] |= 0b1000_0000 >> (bit_index % 8);
+        }
+    }
+    // correct every block and collect the data codewords
+    let galois_field = GaloisFields::_new(
+        8,
+        Polynomial::new(vec![
+            Indeterminate::new(1, 4),
+            Indeterminate::new(1, 3),
+            Indeterminate::new(1, 2),
+            Indeterminate::new(1, 0),
+        ]),
+    );
+    let mut data: Vec<u8> = vec![];
+    let mut corrected_errors: usize = 0;
+    let mut block_infos = error_blocks
+        .iter()
+        .flat_map(|block| std::iter::repeat_n(*block, block.num_block as usize));
+    for block in deinterleave(&codewords, &error_blocks) {
+        let info: ErrorBlockInfo = block_infos.next().expect("block without information");
+        let corrected: Vec<u8> = correct_block(&galois_field, &block, info.num_error_bytes)?;
+        corrected_errors += block
+            .iter()
+            .zip(corrected.iter())
+            .filter(|(received, correct)| received != correct)
+            .count();
+        data.extend(&corrected[..info.num_data_bytes as usize]);
+    }
+    Ok(DecodedSymbol {
+        version,
+        error_level,
+        mask,
+        segments: parse_segments(&data, version)?,
+        corrected_errors,
+    })
+}
+
+mod tests {
+    #[test]
+    fn decode_segments() {
+        use super::parse_segments;
+        use crate::error::QrError;
+        use crate::standard_qr_code::encoding_modes::EncodingMode;
+        use crate::standard_qr_code::segmentation::Segment;
+
+        // "01234567" in version 1-M (ISO/IEC 18004 annex I)
+        let data: [u8; 16] = [
+            0x10, 0x20, 0x0c, 0x56, 0x61, 0x80, 0xec, 0x11, 0xec, 0x11, 0xec, 0x11, 0xec, 0x11,
+            0xec, 0x11,
+        ];
+        assert_eq!(
+            parse_segments(&data, 1),
+            Ok(vec![Segment::new(
+                EncodingMode::Numeric,
+                b"01234567".to_vec()
+            )])
+        );
+        // "点茗" in kanji mode
+        let data: [u8; 6] = [0x80, 0x26, 0xcf, 0xea, 0xa8, 0x00];
+        assert_eq!(
+            parse_segments(&data, 1),
+            Ok(vec![Segment::new(
+                EncodingMode::Kanji,
+                vec![0x93, 0x5F, 0xE4, 0xAA]
+            )])
+        );
+        // unknown mode indicator
+        assert!(parse_segments(&[0b1111_0000], 1).is_err());
+        // ECI assignment numbers above 999999 can't be written
+        assert_eq!(
+            parse_segments(&[0x7D, 0xFF, 0xFF, 0xF0], 1),
+            Err(QrError::UnsupportedEci(0x1F_FFFF))
+        );
+    }
+
+    #[test]
+    fn correct_block_with_zero_bytes() {
+        use super::correct_block;
+        use crate::galois_field::GaloisFields;
+        use crate::polynomials::{Indeterminate, Polynomial};
+
+        let galois_field = GaloisFields::_new(
+            8,
+            Polynomial::new(vec![
+                Indeterminate::new(1, 4),
+                Indeterminate::new(1, 3),
+                Indeterminate::new(1, 2),
+                Indeterminate::new(1, 0),
+            ]),
+        );
+        // codewords of value 0 at the start and in between must keep their place
+        let data: Vec<u8> = vec![0x00, 0x00, 0x40, 0x00, 0x00, 0xec, 0x00, 0x00];
+        let mut block: Vec<u8> = data.clone();
+        block.extend(galois_field.error_correction_bytes(&data, 10).unwrap());
+        assert_eq!(correct_block(&galois_field, &block, 10), Ok(block.clone()));
+        let mut damaged: Vec<u8> = block.clone();
+        for position in [0, 3, 7, 12] {
+            damaged[position] ^= 0x55;
+        }
+        assert_eq!(correct_block(&galois_field, &damaged, 10), Ok(block));
+    }
+
+    #[test]
+    fn decode_text() {
+        use super::DecodedSymbol;
+        use crate::input::ErrorLevel;
+        use crate::standard_qr_code::encoding_modes::EncodingMode;
+        use crate::standard_qr_code::segmentation::Segment;
+
+        let decoded = DecodedSymbol {
+            version: 1,
+            error_level: ErrorLevel::L,
+            mask: 0,
+            segments: vec![
+                Segment::fnc1_first(),
+                Segment::new(EncodingMode::Numeric, b"10".to_vec()),
+                Segment::new(EncodingMode::Alphanumeric, b"AB%%1%21".to_vec()),
+                Segment::eci(7),
+                Segment::new(EncodingMode::Byte, vec![0xB6, 0xD6]),
+            ],
+            corrected_errors: 0,
+        };
+        assert_eq!(decoded.text(), Ok(String::from("10AB%1\u{1D}21Жж")));
+    }
+
+    #[test]
+    fn decode_foreign_symbol() {
+        use super::decode;
+        use crate::input::ErrorLevel;
+        use crate::standard_qr_code::qr_struct::SymbolStatus;
+
+        // drawn by the qrcodegen crate (version 1.8) with its own segmentation
+        // and mask 3 forced, one string per row (# is black)
+        let rows: [&str; 45] = [
+            "#######.#...#.##.....#.#.######.....#.#######",
+            "#.....#.#.####...#....#...#######..#..#.....#",
+            "#.###.#..###....##..####.#.#.....#.#..#.###.#",
+            "#.###.#.###.##.##.#.#.####.#.#.....##.#.###.#",
+            "#.###.#..#.####.#..######.#..#.##.###.#.###.#",
+            "#.....#..##.##..#####...#..#..##......#.....#",
+            "#######.#.#.#.#.#.#.#.#.#.#.#.#.#.#.#.#######",
+            "........#.#...##.##.#...##..###.....#........",
+            "#.##.###..##..#..##############...#.#.#..#.##",
+            "..##.#.#####.##...#.##.###....###...##..#.##.",
+            "#.#####..#....#..#.###.#...#.##.#.#....###.##",
+            "#....#.#####..#..#.#..##.##.###.######..##...",
+            ".##.#.#.....#.#..###....#.#.#..#......#.##...",
+            "#....#.##.##.#....#.####..#.#.####...##.##..#",
+            "########...#..#.#..#...#####...#....####....#",
+            ".#.#.#.####.##..#.###.#........##.#.##.####.#",
+            "....########..#..#......###..#.##..#####.###.",
+            "#.#..#.#..######.##.#.####.###..#.#........##",
+            ".##.#.#..####.##.#..######.#...#####..#..##..",
+            "....#..#...##.########...#..##..#..#..#.##..#",
+            "..#.#####......####.#########...##########..#",
+            "#####...#.#...###.###...####.##.##..#...#.#.#",
+            "....#.#.###..#.###.##.#.#.###.##.#.##.#.###.#",
+            "...##...#.#..#.#.#..#...##......##..#...#....",
+            "#...######....#...#.#######...##..#######..#.",
+            "###....#....####...#.##..##..#.###.#.###...##",
+            ".#...#####.#.#.#####..#.###.####.....#..#....",
+            "###..#.#...###.##.#...###.....#.##...#.#.##.#",
+            "......##.#...####...##.#..#....###.#.##.####.",
+            "...##....##.##.#.#.##..#...##..#.##..#.####..",
+            "#.###.#..#...##.####..#.####.##..##..#..#.#..",
+            "###..#..##...#...#.#..#.##..........#......##",
+            "#..######.##..##.#..##..####.#....#....##.##.",
+            ".#.###.#..###.....#..#.#.######.....##.#.#..#",
+            "....#.#..##.#..#..#.......###.#.#.###.##.##.#",
+            ".####.....##..#...##.##..#...#...#.####.###.#",
+            "#..##.#.#.##.##.....######...#...##.#####.##.",
+            "........#..#.#....#.#...#.##...####.#...##...",
+            "#######.#...##..#.#.#.#.#..##.##..#.#.#.#....",
+            "#.....#.##.####.#####...#####.#.#..##...###.#",
+            "#.###.#..###..#..##.######....###.###########",
+            "#.###.#.#...#.#...#...##..........#...##.###.",
+            "#.###.#.#..##.#...#.#...#.##..######.....#.#.",
+            "#.....#..#.#.#...##....####...#..#.##.##.#..#",
+            "#######.##....##.#.#.#.#.###..##..#######.#..",
+        ];
+        let mut grid: Vec<Vec<SymbolStatus>> = (0..rows.len())
+            .map(|x| {
+                rows.iter()
+                    .map(|row| match row.as_bytes()[x] {
+                        b'#' => SymbolStatus::LogicalTrue,
+                        _ => SymbolStatus::LogicalFalse,
+                    })
+                    .collect()
+            })
+            .collect();
+        let text: &str =
+            "QR codes of other encoders have to be read as well: version 7, level M, mask 3";
+        let decoded = decode(&grid, 0).unwrap();
+        assert_eq!(
+            (decoded.version, decoded.error_level, decoded.mask),
+            (7, ErrorLevel::M, 3)
+        );
+        assert_eq!(decoded.corrected_errors, 0);
+        assert_eq!(decoded.text(), Ok(String::from(text)));
+        // the first data codeword starts in the bottom right corner
+        grid[44][44] = grid[44][44] ^ SymbolStatus::LogicalTrue;
+        let decoded = decode(&grid, 0).unwrap();
+        assert_eq!(decoded.corrected_errors, 1);
+        assert_eq!(decoded.text(), Ok(String::from(text)));
+    }
+}
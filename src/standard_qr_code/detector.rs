@@ -0,0 +1,688 @@
+// locates a qr code in an image and samples its elements,
+// following the reference decode algorithm of ISO/IEC 18004 (section 11)
+
+use crate::error::QrError;
+use crate::standard_qr_code::decoder::{decode, DecodedSymbol};
+use crate::standard_qr_code::netpbm::GrayImage;
+use crate::standard_qr_code::qr_struct::SymbolStatus;
+use crate::standard_qr_code::version_constants::alignment_pattern_data;
+
+/// below this standard deviation of the brightness around a pixel the area is treated
+/// as uniform and the threshold of the whole image is used instead of the local one
+const MINLOCALCONTRAST: f64 = 16.0;
+/// maximum number of finder pattern candidates that are combined into triples
+const MAXFINDERCANDIDATES: usize = 12;
+/// minimum number of matching elements of the 5x5 elements of the alignment pattern
+const MINALIGNMENTSCORE: usize = 21;
+
+/// a point in the image, where pixel (x, y) covers the area from (x, y) to (x + 1, y + 1)
+type Point = (f64, f64);
+
+/// returns the distance between two points
+fn distance(lhs: Point, rhs: Point) -> f64 {
+    ((lhs.0 - rhs.0).powi(2) + (lhs.1 - rhs.1).powi(2)).sqrt()
+}
+
+/// calculates the threshold between black and white of the whole image with Otsu's method
+fn global_threshold(image: &GrayImage) -> f64 {
+    let mut histogram: [usize; 256] = [0; 256];
+    for pixel in image.pixels.iter() {
+        histogram[*pixel as usize] += 1;
+    }
+    let total: f64 = image.pixels.len() as f64;
+    let total_sum: f64 = (0..256)
+        .map(|value| (value * histogram[value]) as f64)
+        .sum();
+    // maximise the variance between the dark and the bright pixels
+    let mut best: (f64, usize) = (0.0, 128);
+    let mut dark_count: f64 = 0.0;
+    let mut dark_sum: f64 = 0.0;
+    for (value, count) in histogram.iter().enumerate() {
+        dark_count += *count as f64;
+        dark_sum += (value * count) as f64;
+        let bright_count: f64 = total - dark_count;
+        if dark_count == 0.0 || bright_count == 0.0 {
+            continue;
+        }
+        let mean_difference: f64 = dark_sum / dark_count - (total_sum - dark_sum) / bright_count;
+        let variance: f64 = dark_count * bright_count * mean_difference.powi(2);
+        if variance > best.0 {
+            best = (variance, value);
+        }
+    }
+    best.1 as f64 + 0.5
+}
+
+/// converts the image into black (true) and white elements indexed with [x][y];
+/// every pixel is compared with the mean brightness of its surrounding,
+/// so uneven lighting doesn't turn whole areas black or white
+fn binarise(image: &GrayImage) -> Vec<Vec<bool>> {
+    let (width, height) = (image.width, image.height);
+    // summed area tables of the brightness and the squared brightness
+    let mut sums: Vec<Vec<u64>> = vec![vec![0; height + 1]; width + 1];
+    let mut squared_sums: Vec<Vec<u64>> = vec![vec![0; height + 1]; width + 1];
+    for x in 0..width {
+        for y in 0..height {
+            let pixel: u64 = image.get(x, y) as u64;
+            sums[x + 1][y + 1] = pixel + sums[x][y + 1] + sums[x + 1][y] - sums[x][y];
+            squared_sums[x + 1][y + 1] =
+                pixel * pixel + squared_sums[x][y + 1] + squared_sums[x + 1][y]
+                    - squared_sums[x][y];
+        }
+    }
+    let global: f64 = global_threshold(image);
+    let radius: usize = (width.max(height) / 16).max(8);
+    let mut bits: Vec<Vec<bool>> = vec![vec![false; height]; width];
+    for (x, column) in bits.iter_mut().enumerate() {
+        let (low_x, high_x) = (x.saturating_sub(radius), (x + radius + 1).min(width));
+        for (y, bit) in column.iter_mut().enumerate() {
+            let (low_y, high_y) = (y.saturating_sub(radius), (y + radius + 1).min(height));
+            let area: f64 = ((high_x - low_x) * (high_y - low_y)) as f64;
+            let sum: f64 = (sums[high_x][high_y] + sums[low_x][low_y]
+                - sums[low_x][high_y]
+                - sums[high_x][low_y]) as f64;
+            let squared_sum: f64 = (squared_sums[high_x][high_y] + squared_sums[low_x][low_y]
+                - squared_sums[low_x][high_y]
+                - squared_sums[high_x][low_y]) as f64;
+            let mean: f64 = sum / area;
+            let deviation: f64 = (squared_sum / area - mean * mean).max(0.0).sqrt();
+            let threshold: f64 = if deviation < MINLOCALCONTRAST {
+                global
+            } else {
+                mean
+            };
+            *bit = (image.get(x, y) as f64) < threshold;
+        }
+    }
+    bits
+}
+
+/// a sequence of pixels with the same color
+#[derive(Debug, Clone, Copy)]
+struct Run {
+    start: usize,
+    length: usize,
+    is_black: bool,
+}
+
+/// splits a line of pixels into runs of the same color
+fn runs(line: impl Iterator<Item = bool>) -> Vec<Run> {
+    let mut runs: Vec<Run> = vec![];
+    for (index, is_black) in line.enumerate() {
+        match runs.last_mut() {
+            Some(run) if run.is_black == is_black => run.length += 1,
+            _ => runs.push(Run {
+                start: index,
+                length: 1,
+                is_black,
+            }),
+        }
+    }
+    runs
+}
+
+/// checks whether five runs (black, white, black, white, black) have the 1:1:3:1:1 ratio
+/// of a finder pattern and returns the size of an element
+fn finder_module_size(runs: &[Run]) -> Option<f64> {
+    let total: usize = runs.iter().map(|run| run.length).sum();
+    let module_size: f64 = total as f64 / 7.0;
+    let tolerance: f64 = module_size / 2.0;
+    let expected: [f64; 5] = [1.0, 1.0, 3.0, 1.0, 1.0];
+    let is_finder: bool = total >= 7
+        && runs[0].is_black
+        && runs.iter().zip(expected).all(|(run, ratio)| {
+            (run.length as f64 - ratio * module_size).abs() < ratio * tolerance
+        });
+    is_finder.then_some(module_size)
+}
+
+/// checks whether the black run at the given position is the center of a finder pattern
+/// and returns the center of the run and the size of an element
+fn finder_center(runs: &[Run], position: usize) -> Option<(f64, f64)> {
+    let index: usize = runs
+        .iter()
+        .position(|run| run.start <= position && position < run.start + run.length)?;
+    if index < 2 || index + 3 > runs.len() {
+        return None;
+    }
+    let module_size: f64 = finder_module_size(&runs[index - 2..index + 3])?;
+    let center: f64 = runs[index].start as f64 + runs[index].length as f64 / 2.0;
+    Some((center, module_size))
+}
+
+/// a possible finder pattern, which was found in several lines of the image
+#[derive(Debug, Clone, Copy)]
+struct FinderCandidate {
+    center: Point,
+    module_size: f64,
+    /// number of lines the pattern was found in
+    count: usize,
+}
+
+/// searches every row for the 1:1:3:1:1 ratio of the finder patterns
+/// and confirms the finds in the column through their center
+fn find_finder_candidates(bits: &[Vec<bool>]) -> Vec<FinderCandidate> {
+    let (width, height) = (bits.len(), bits[0].len());
+    let mut candidates: Vec<FinderCandidate> = vec![];
+    for y in 0..height {
+        let row: Vec<Run> = runs((0..width).map(|x| bits[x][y]));
+        for window in row.windows(5) {
+            if finder_module_size(window).is_none() {
+                continue;
+            }
+            let center_x: f64 = window[2].start as f64 + window[2].length as f64 / 2.0;
+            // cross check the column and then the row through the center again
+            let column: Vec<Run> = runs(bits[center_x as usize].iter().copied());
+            let Some((center_y, vertical_size)) = finder_center(&column, y) else {
+                continue;
+            };
+            let row: Vec<Run> = runs((0..width).map(|x| bits[x][center_y as usize]));
+            let Some((center_x, horizontal_size)) = finder_center(&row, center_x as usize) else {
+                continue;
+            };
+            let module_size: f64 = (vertical_size + horizontal_size) / 2.0;
+            // the same pattern is found in many rows
+            match candidates.iter_mut().find(|candidate| {
+                distance(candidate.center, (center_x, center_y)) < 2.0 * module_size
+                    && (candidate.module_size - module_size).abs() < module_size / 2.0
+            }) {
+                Some(candidate) => {
+                    let count: f64 = candidate.count as f64;
+                    candidate.center = (
+                        (candidate.center.0 * count + center_x) / (count + 1.0),
+                        (candidate.center.1 * count + center_y) / (count + 1.0),
+                    );
+                    candidate.module_size =
+                        (candidate.module_size * count + module_size) / (count + 1.0);
+                    candidate.count += 1;
+                }
+                None => candidates.push(FinderCandidate {
+                    center: (center_x, center_y),
+                    module_size,
+                    count: 1,
+                }),
+            }
+        }
+    }
+    candidates
+}
+
+/// sorts three finder patterns into top left, top right and bottom left
+fn order_finders(finders: [FinderCandidate; 3]) -> [FinderCandidate; 3] {
+    let [first, second, third] = finders;
+    // the top left pattern lies opposite of the longest side
+    let sides: [f64; 3] = [
+        distance(second.center, third.center),
+        distance(first.center, third.center),
+        distance(first.center, second.center),
+    ];
+    let (top_left, mut top_right, mut bottom_left) = if sides[0] >= sides[1] && sides[0] >= sides[2]
+    {
+        (first, second, third)
+    } else if sides[1] >= sides[2] {
+        (second, first, third)
+    } else {
+        (third, first, second)
+    };
+    // going from top right to bottom left must turn clockwise (the y axis points down)
+    let cross_product: f64 = (top_right.center.0 - top_left.center.0)
+        * (bottom_left.center.1 - top_left.center.1)
+        - (top_right.center.1 - top_left.center.1) * (bottom_left.center.0 - top_left.center.0);
+    if cross_product < 0.0 {
+        std::mem::swap(&mut top_right, &mut bottom_left);
+    }
+    [top_left, top_right, bottom_left]
+}
+
+/// picks the three candidates that form the best right isosceles triangle
+/// with finder patterns of the same size
+fn select_finders(mut candidates: Vec<FinderCandidate>) -> Result<[FinderCandidate; 3], QrError> {
+    // patterns that were only found in a single line are probably noise
+    if candidates
+        .iter()
+        .filter(|candidate| candidate.count > 1)
+        .count()
+        >= 3
+    {
+        candidates.retain(|candidate| candidate.count > 1);
+    }
+    candidates.sort_by_key(|candidate| std::cmp::Reverse(candidate.count));
+    candidates.truncate(MAXFINDERCANDIDATES);
+    let mut best: Option<(f64, [FinderCandidate; 3])> = None;
+    for first in 0..candidates.len() {
+        for second in first + 1..candidates.len() {
+            for third in second + 1..candidates.len() {
+                let finders: [FinderCandidate; 3] =
+                    order_finders([candidates[first], candidates[second], candidates[third]]);
+                let sizes: Vec<f64> = finders.iter().map(|finder| finder.module_size).collect();
+                let mean_size: f64 = sizes.iter().sum::<f64>() / 3.0;
+                let size_spread: f64 = (sizes.iter().copied().fold(0.0, f64::max)
+                    - sizes.iter().copied().fold(f64::MAX, f64::min))
+                    / mean_size;
+                let top: f64 = distance(finders[0].center, finders[1].center);
+                let left: f64 = distance(finders[0].center, finders[2].center);
+                let diagonal: f64 = distance(finders[1].center, finders[2].center);
+                // the centers of the finder patterns are at least 14 elements apart
+                if size_spread > 0.5 || top.min(left) < 10.0 * mean_size {
+                    continue;
+                }
+                let score: f64 = (top - left).abs() / top.max(left)
+                    + (diagonal - top.hypot(left)).abs() / diagonal
+                    + size_spread;
+                if best.is_none_or(|(best_score, _)| score < best_score) {
+                    best = Some((score, finders));
+                }
+            }
+        }
+    }
+    match best {
+        Some((_, finders)) => Ok(finders),
+        None => Err(QrError::SymbolNotFound(String::from(
+            "couldn't find the three finder patterns",
+        ))),
+    }
+}
+
+/// maps points of one quadrilateral onto another, e.g. positions in the symbol onto the image
+#[derive(Debug, Clone, Copy)]
+struct PerspectiveTransform {
+    /// coefficients a to h of x' = (ax + by + c) / (gx + hy + 1), y' = (dx + ey + f) / (gx + hy + 1)
+    coefficients: [f64; 8],
+}
+
+impl PerspectiveTransform {
+    /// calculates the transformation that maps every point of source onto the same point of target
+    fn new(source: [Point; 4], target: [Point; 4]) -> Result<PerspectiveTransform, QrError> {
+        // two linear equations per pair of points
+        let mut equations: [[f64; 9]; 8] = [[0.0; 9]; 8];
+        for (index, ((x, y), (target_x, target_y))) in source.into_iter().zip(target).enumerate() {
+            equations[2 * index] = [
+                x,
+                y,
+                1.0,
+                0.0,
+                0.0,
+                0.0,
+                -x * target_x,
+                -y * target_x,
+                target_x,
+            ];
+            equations[2 * index + 1] = [
+                0.0,
+                0.0,
+                0.0,
+                x,
+                y,
+                1.0,
+                -x * target_y,
+                -y * target_y,
+                target_y,
+            ];
+        }
+        // gaussian elimination with partial pivoting
+        for column in 0..8 {
+            let pivot: usize = (column..8)
+                .max_by(|lhs, rhs| {
+                    equations[*lhs][column]
+                        .abs()
+                        .total_cmp(&equations[*rhs][column].abs())
+                })
+                .expect("range isn't empty");
+            if equations[pivot][column].abs() < 1e-9 {
+                return Err(QrError::SymbolNotFound(String::from(
+                    "the corners of the qr code lie on a line",
+                )));
+            }
+            equations.swap(column, pivot);
+            let pivot_row: [f64; 9] = equations[column];
+            for (row, equation) in equations.iter_mut().enumerate() {
+                if row != column {
+                    let factor: f64 = equation[column] / pivot_row[column];
+                    for (value, pivot_value) in equation.iter_mut().zip(pivot_row).skip(column) {
+                        *value -= factor * pivot_value;
+                    }
+                }
+            }
+        }
+        let mut coefficients: [f64; 8] = [0.0; 8];
+        for (index, coefficient) in coefficients.iter_mut().enumerate() {
+            *coefficient = equations[index][8] / equations[index][index];
+        }
+        Ok(PerspectiveTransform { coefficients })
+    }
+
+    /// maps a point
+    fn apply(&self, (x, y): Point) -> Point {
+        let [a, b, c, d, e, f, g, h] = self.coefficients;
+        let denominator: f64 = g * x + h * y + 1.0;
+        (
+            (a * x + b * y + c) / denominator,
+            (d * x + e * y + f) / denominator,
+        )
+    }
+}
+
+/// checks whether the line through the center in the direction of one element crosses
+/// the alignment pattern in the 1:1:1:1:1 ratio (black, white, black, white, black)
+/// and returns the vector of one element along the line as it appears in the image
+fn alignment_cross_check(bits: &[Vec<bool>], center: Point, module: Point) -> Option<Point> {
+    let (width, height) = (bits.len() as f64, bits[0].len() as f64);
+    let module_size: f64 = module.0.hypot(module.1);
+    // sample the line pixel by pixel up to an element beyond the pattern on both sides
+    let reach: i32 = (3.5 * module_size).ceil() as i32;
+    let mut line: Vec<bool> = vec![];
+    for step in -reach..=reach {
+        let x: f64 = center.0 + step as f64 * module.0 / module_size;
+        let y: f64 = center.1 + step as f64 * module.1 / module_size;
+        if x < 0.0 || y < 0.0 || x >= width || y >= height {
+            return None;
+        }
+        line.push(bits[x as usize][y as usize]);
+    }
+    let runs: Vec<Run> = runs(line.into_iter());
+    let position: usize = reach as usize;
+    let index: usize = runs
+        .iter()
+        .position(|run| run.start <= position && position < run.start + run.length)?;
+    if !runs[index].is_black || index < 2 || index + 3 > runs.len() {
+        return None;
+    }
+    // the outer ring can touch black elements of the data, so only the white ring
+    // and the center have to be exactly one element wide
+    let size: f64 = runs[index - 1..index + 2]
+        .iter()
+        .map(|run| run.length as f64)
+        .sum::<f64>()
+        / 3.0;
+    let is_pattern: bool = runs[index - 1..index + 2]
+        .iter()
+        .all(|run| (run.length as f64 - size).abs() < size / 2.0)
+        && runs[index - 2].length as f64 >= size / 2.0
+        && runs[index + 2].length as f64 >= size / 2.0;
+    // the center has to be in the middle of the pattern
+    let middle: f64 = runs[index].start as f64 + runs[index].length as f64 / 2.0;
+    let is_centered: bool = (middle - position as f64).abs() < size / 2.0;
+    let is_scaled: bool = (size - module_size).abs() < module_size / 2.0;
+    (is_pattern && is_centered && is_scaled)
+        .then_some((module.0 * size / module_size, module.1 * size / module_size))
+}
+
+/// searches for the alignment pattern around the estimated center; module_x and module_y are
+/// the vectors of one element, which can be off by a lot in distorted images
+fn find_alignment_pattern(
+    bits: &[Vec<bool>],
+    estimate: Point,
+    module_x: Point,
+    module_y: Point,
+) -> Option<Point> {
+    let (width, height) = (bits.len() as f64, bits[0].len() as f64);
+    let module_size: f64 = (module_x.0.hypot(module_x.1) + module_y.0.hypot(module_y.1)) / 2.0;
+    let radius: i32 = (5.0 * module_size) as i32;
+    let step: usize = ((module_size / 4.0) as usize).max(1);
+    // compares the 5x5 elements around the center with the pattern
+    let score = |center: Point, module_x: Point, module_y: Point| -> usize {
+        let mut matches: usize = 0;
+        for row in -2..=2_i32 {
+            for column in -2..=2_i32 {
+                let x: f64 = center.0 + column as f64 * module_x.0 + row as f64 * module_y.0;
+                let y: f64 = center.1 + column as f64 * module_x.1 + row as f64 * module_y.1;
+                // black center and outer ring, white ring in between
+                let is_black: bool = row.abs().max(column.abs()) != 1;
+                if x >= 0.0 && y >= 0.0 && x < width && y < height {
+                    matches += (bits[x as usize][y as usize] == is_black) as usize;
+                }
+            }
+        }
+        matches
+    };
+    // the 5x5 elements of the data can resemble the pattern as well, so a match needs
+    // the ratio through its center along both axes and the whole pattern
+    // with the element size measured there
+    let mut scores: Vec<(Point, usize)> = vec![];
+    for offset_y in (-radius..=radius).step_by(step) {
+        for offset_x in (-radius..=radius).step_by(step) {
+            let center: Point = (estimate.0 + offset_x as f64, estimate.1 + offset_y as f64);
+            let Some(local_x) = alignment_cross_check(bits, center, module_x) else {
+                continue;
+            };
+            let Some(local_y) = alignment_cross_check(bits, center, module_y) else {
+                continue;
+            };
+            let score: usize = score(center, local_x, local_y);
+            if score >= MINALIGNMENTSCORE {
+                scores.push((center, score));
+            }
+        }
+    }
+    // without a match the corner is estimated from the finder patterns alone
+    let best_score: usize = scores.iter().map(|(_, score)| *score).max()?;
+    // the pattern matches within half an element around its center,
+    // so the best matches nearest to the estimate are averaged
+    let (nearest, _) = scores
+        .iter()
+        .filter(|(_, score)| *score == best_score)
+        .min_by(|lhs, rhs| distance(lhs.0, estimate).total_cmp(&distance(rhs.0, estimate)))?;
+    let matches: Vec<Point> = scores
+        .iter()
+        .filter(|(center, score)| *score == best_score && distance(*center, *nearest) < module_size)
+        .map(|(center, _)| *center)
+        .collect();
+    let count: f64 = matches.len() as f64;
+    Some((
+        matches.iter().map(|center| center.0).sum::<f64>() / count,
+        matches.iter().map(|center| center.1).sum::<f64>() / count,
+    ))
+}
+
+/// reads the color of every element of a symbol of the given version
+fn sample_symbol(
+    bits: &[Vec<bool>],
+    finders: &[FinderCandidate; 3],
+    version: u8,
+) -> Result<Vec<Vec<SymbolStatus>>, QrError> {
+    let width: usize = 17 + 4 * version as usize;
+    let [top_left, top_right, bottom_left] = finders.map(|finder| finder.center);
+    // the centers of the finder patterns are 3.5 elements away from the edges
+    let finder_distance: f64 = width as f64 - 7.0;
+    let module_x: Point = (
+        (top_right.0 - top_left.0) / finder_distance,
+        (top_right.1 - top_left.1) / finder_distance,
+    );
+    let module_y: Point = (
+        (bottom_left.0 - top_left.0) / finder_distance,
+        (bottom_left.1 - top_left.1) / finder_distance,
+    );
+    // the fourth corner of the parallelogram through the finder patterns
+    let mut source_corner: Point = (width as f64 - 3.5, width as f64 - 3.5);
+    let mut image_corner: Point = (
+        top_right.0 + bottom_left.0 - top_left.0,
+        top_right.1 + bottom_left.1 - top_left.1,
+    );
+    // the bottom right alignment pattern shows how much the symbol is distorted
+    if let Some(position) = alignment_pattern_data(version).1.last() {
+        let center: f64 = *position as f64 + 0.5;
+        let estimate: Point = (
+            top_left.0 + (center - 3.5) * (module_x.0 + module_y.0),
+            top_left.1 + (center - 3.5) * (module_x.1 + module_y.1),
+        );
+        if let Some(found) = find_alignment_pattern(bits, estimate, module_x, module_y) {
+            source_corner = (center, center);
+            image_corner = found;
+        }
+    }
+    let transform: PerspectiveTransform = PerspectiveTransform::new(
+        [
+            (3.5, 3.5),
+            (width as f64 - 3.5, 3.5),
+            (3.5, width as f64 - 3.5),
+            source_corner,
+        ],
+        [top_left, top_right, bottom_left, image_corner],
+    )?;
+    let mut grid: Vec<Vec<SymbolStatus>> = vec![vec![SymbolStatus::LogicalFalse; width]; width];
+    for (x, column) in grid.iter_mut().enumerate() {
+        for (y, element) in column.iter_mut().enumerate() {
+            let (image_x, image_y) = transform.apply((x as f64 + 0.5, y as f64 + 0.5));
+            if image_x < 0.0
+                || image_y < 0.0
+                || image_x >= bits.len() as f64
+                || image_y >= bits[0].len() as f64
+            {
+                return Err(QrError::SymbolNotFound(String::from(
+                    "the qr code reaches beyond the image",
+                )));
+            }
+            if bits[image_x as usize][image_y as usize] {
+                *element = SymbolStatus::LogicalTrue;
+            }
+        }
+    }
+    Ok(grid)
+}
+
+/// locates the qr code in the image and decodes it
+pub fn read_image(image: &GrayImage) -> Result<DecodedSymbol, QrError> {
+    let bits: Vec<Vec<bool>> = binarise(image);
+    let finders: [FinderCandidate; 3] = select_finders(find_finder_candidates(&bits))?;
+    // estimate the version from the distance between the finder patterns
+    let module_size: f64 = finders.iter().map(|finder| finder.module_size).sum::<f64>() / 3.0;
+    let finder_distance: f64 = (distance(finders[0].center, finders[1].center)
+        + distance(finders[0].center, finders[2].center))
+        / 2.0;
+    let estimated_version: i32 = ((finder_distance / module_size - 10.0) / 4.0).round() as i32;
+    // the estimate can be off by a version, especially for distorted images
+    let mut last_error: QrError = QrError::SymbolNotFound(String::from(
+        "the size of the qr code couldn't be determined",
+    ));
+    for offset in [0, -1, 1, -2, 2] {
+        let version: i32 = estimated_version + offset;
+        if !(1..=40).contains(&version) {
+            continue;
+        }
+        match sample_symbol(&bits, &finders, version as u8).and_then(|grid| decode(&grid, 0)) {
+            Ok(decoded) => return Ok(decoded),
+            Err(error) => last_error = error,
+        }
+    }
+    Err(last_error)
+}
+
+mod tests {
+    /// draws a symbol (with quiet zone) into an image, so its corners land on the given points
+    /// (top left, top right, bottom left, bottom right); the brightness falls off to the right
+    #[cfg(test)]
+    fn render(
+        grid: &[Vec<crate::standard_qr_code::qr_struct::SymbolStatus>],
+        size: usize,
+        corners: [super::Point; 4],
+        is_shaded: bool,
+    ) -> crate::standard_qr_code::netpbm::GrayImage {
+        use super::PerspectiveTransform;
+        use crate::standard_qr_code::netpbm::GrayImage;
+        use crate::standard_qr_code::qr_struct::SymbolStatus;
+
+        let width: f64 = grid.len() as f64;
+        let transform = PerspectiveTransform::new(
+            corners,
+            [(0.0, 0.0), (width, 0.0), (0.0, width), (width, width)],
+        )
+        .unwrap();
+        let mut pixels: Vec<u8> = vec![];
+        for y in 0..size {
+            for x in 0..size {
+                let (module_x, module_y) = transform.apply((x as f64 + 0.5, y as f64 + 0.5));
+                let is_black: bool = module_x >= 0.0
+                    && module_y >= 0.0
+                    && module_x < width
+                    && module_y < width
+                    && grid[module_x as usize][module_y as usize] == SymbolStatus::LogicalTrue;
+                let brightness: f64 = if is_black { 40.0 } else { 230.0 };
+                let light: f64 = if is_shaded {
+                    1.0 - 0.7 * x as f64 / size as f64
+                } else {
+                    1.0
+                };
+                pixels.push((brightness * light) as u8);
+            }
+        }
+        GrayImage {
+            width: size,
+            height: size,
+            pixels,
+        }
+    }
+
+    #[test]
+    fn read_scaled_image() {
+        use super::read_image;
+        use crate::input::{test_settings, ErrorLevel};
+        use crate::standard_qr_code::qr_code;
+
+        let settings = test_settings("Hello, world! 123", ErrorLevel::M);
+        let grid = qr_code(settings).unwrap()[0].get_data();
+        let image = render(
+            &grid,
+            200,
+            [(10.0, 10.0), (175.0, 10.0), (10.0, 175.0), (175.0, 175.0)],
+            false,
+        );
+        let decoded = read_image(&image).unwrap();
+        assert_eq!(decoded.version, 2);
+        assert_eq!(decoded.text(), Ok(String::from("Hello, world! 123")));
+    }
+
+    #[test]
+    fn read_rotated_shaded_image() {
+        use super::read_image;
+        use crate::input::{test_settings, ErrorLevel};
+        use crate::standard_qr_code::qr_code;
+
+        let text: &str = "This example is testing the version information, which appears in versions 7 or higher.";
+        let settings = test_settings(text, ErrorLevel::Q);
+        let grid = qr_code(settings).unwrap()[0].get_data();
+        // turned by about 100 degrees and lit from the left
+        let image = render(
+            &grid,
+            400,
+            [(360.0, 60.0), (330.0, 370.0), (50.0, 30.0), (20.0, 340.0)],
+            true,
+        );
+        let decoded = read_image(&image).unwrap();
+        assert_eq!(decoded.version, 8);
+        assert_eq!(decoded.text(), Ok(String::from(text)));
+    }
+
+    #[test]
+    fn read_distorted_image() {
+        use super::read_image;
+        use crate::input::{test_settings, ErrorLevel};
+        use crate::standard_qr_code::netpbm::parse_netpbm;
+        use crate::standard_qr_code::qr_code;
+
+        let text: &str = "https://EXAMPLE.COM/ORDER/000123456789";
+        // the alignment pattern has to be found with every mask around it
+        for mask in 0..8 {
+            let mut settings = test_settings(text, ErrorLevel::H);
+            settings.symbol.mask = Some(mask);
+            let grid = qr_code(settings).unwrap()[0].get_data();
+            // photographed at an angle, so the bottom is smaller than the top
+            let image = render(
+                &grid,
+                300,
+                [(20.0, 20.0), (280.0, 30.0), (60.0, 270.0), (240.0, 260.0)],
+                false,
+            );
+            // write and read a pgm file
+            let mut file: Vec<u8> =
+                format!("P5\n{} {}\n255\n", image.width, image.height).into_bytes();
+            file.extend(image.pixels.iter());
+            let image = parse_netpbm(&file).unwrap();
+            let decoded = read_image(&image);
+            assert_eq!(
+                decoded.and_then(|decoded| decoded.text()),
+                Ok(String::from(text)),
+                "mask {mask}"
+            );
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use crate::error::QrError;
+use crate::input::EciCharset;
+use crate::standard_qr_code::encoding_modes::EncodingMode;
+use crate::standard_qr_code::segmentation::Segment;
+
+/// largest ECI assignment number that can be written
+pub const MAXECIASSIGNMENT: u32 = 999999;
+
+/// the character sets that can be selected
+const ECICHARSETS: [EciCharset; 9] = [
+    EciCharset::Iso8859_1,
+    EciCharset::Iso8859_2,
+    EciCharset::Iso8859_5,
+    EciCharset::Iso8859_7,
+    EciCharset::Iso8859_15,
+    EciCharset::Windows1250,
+    EciCharset::Windows1251,
+    EciCharset::Windows1252,
+    EciCharset::Utf8,
+];
+
+// the upper halves of the single byte character sets; the lower half is always ascii
+// a value of 0 marks a byte that isn't assigned to a character
+/// unicode code points of the bytes 0x80 to 0xFF in ISO-8859-2 (Latin-2, central european)
+const ISO88592: [u16; 128] = [
+    0x0080, 0x0081, 0x0082, 0x0083, 0x0084, 0x0085, 0x0086, 0x0087, 0x0088, 0x0089, 0x008A, 0x008B,
+    0x008C, 0x008D, 0x008E, 0x008F, 0x0090, 0x0091, 0x0092, 0x0093, 0x0094, 0x0095, 0x0096, 0x0097,
+    0x0098, 0x0099, 0x009A, 0x009B, 0x009C, 0x009D, 0x009E, 0x009F, 0x00A0, 0x0104, 0x02D8, 0x0141,
+    0x00A4, 0x013D, 0x015A, 0x00A7, 0x00A8, 0x0160, 0x015E, 0x0164, 0x0179, 0x00AD, 0x017D, 0x017B,
+    0x00B0, 0x0105, 0x02DB, 0x0142, 0x00B4, 0x013E, 0x015B, 0x02C7, 0x00B8, 0x0161, 0x015F, 0x0165,
+    0x017A, 0x02DD, 0x017E, 0x017C, 0x0154, 0x00C1, 0x00C2, 0x0102, 0x00C4, 0x0139, 0x0106, 0x00C7,
+    0x010C, 0x00C9, 0x0118, 0x00CB, 0x011A, 0x00CD, 0x00CE, 0x010E, 0x0110, 0x0143, 0x0147, 0x00D3,
+    0x00D4, 0x0150, 0x00D6, 0x00D7, 0x0158, 0x016E, 0x00DA, 0x0170, 0x00DC, 0x00DD, 0x0162, 0x00DF,
+    0x0155, 0x00E1, 0x00E2, 0x0103, 0x00E4, 0x013A, 0x0107, 0x00E7, 0x010D, 0x00E9, 0x0119, 0x00EB,
+    0x011B, 0x00ED, 0x00EE, 0x010F, 0x0111, 0x0144, 0x0148, 0x00F3, 0x00F4, 0x0151, 0x00F6, 0x00F7,
+    0x0159, 0x016F, 0x00FA, 0x0171, 0x00FC, 0x00FD, 0x0163, 0x02D9,
+];
+
+/// unicode code points of the bytes 0x80 to 0xFF in ISO-8859-5 (cyrillic)
+const ISO88595: [u16; 128] = [
+    0x0080, 0x0081, 0x0082, 0x0083, 0x0084, 0x0085, 0x0086, 0x0087, 0x0088, 0x0089, 0x008A, 0x008B,
+    0x008C, 0x008D, 0x008E, 0x008F, 0x0090, 0x0091, 0x0092, 0x0093, 0x0094, 0x0095, 0x0096, 0x0097,
+    0x0098, 0x0099, 0x009A, 0x009B, 0x009C, 0x009D, 0x009E, 0x009F, 0x00A0, 0x0401, 0x0402, 0x0403,
+    0x0404, 0x0405, 0x0406, 0x0407, 0x0408, 0x0409, 0x040A, 0x040B, 0x040C, 0x00AD, 0x040E, 0x040F,
+    0x0410, 0x0411, 0x0412, 0x0413, 0x0414, 0x0415, 0x0416, 0x0417, 0x0418, 0x0419, 0x041A, 0x041B,
+    0x041C, 0x041D, 0x041E, 0x041F, 0x0420, 0x0421, 0x0422, 0x0423, 0x0424, 0x0425, 0x0426, 0x0427,
+    0x0428, 0x0429, 0x042A, 0x042B, 0x042C, 0x042D, 0x042E, 0x042F, 0x0430, 0x0431, 0x0432, 0x0433,
+    0x0434, 0x0435, 0x0436, 0x0437, 0x0438, 0x0439, 0x043A, 0x043B, 0x043C, 0x043D, 0x043E, 0x043F,
+    0x0440, 0x0441, 0x0442, 0x0443, 0x0444, 0x0445, 0x0446, 0x0447, 0x0448, 0x0449, 0x044A, 0x044B,
+    0x044C, 0x044D, 0x044E, 0x044F, 0x2116, 0x0451, 0x0452, 0x0453, 0x0454, 0x0455, 0x0456, 0x0457,
+    0x0458, 0x0459, 0x045A, 0x045B, 0x045C, 0x00A7, 0x045E, 0x045F,
+];
+
+/// unicode code points of the bytes 0x80 to 0xFF in ISO-8859-7 (greek)
+const ISO88597: [u16; 128] = [
+    0x0080, 0x0081, 0x0082, 0x0083, 0x0084, 0x0085, 0x0086, 0x0087, 0x0088, 0x0089, 0x008A, 0x008B,
+    0x008C, 0x008D, 0x008E, 0x008F, 0x0090, 0x0091, 0x0092, 0x0093, 0x0094, 0x0095, 0x0096, 0x0097,
+    0x0098, 0x0099, 0x009A, 0x009B, 0x009C, 0x009D, 0x009E, 0x009F, 0x00A0, 0x2018, 0x2019, 0x00A3,
+    0x20AC, 0x20AF, 0x00A6, 0x00A7, 0x00A8, 0x00A9, 0x037A, 0x00AB, 0x00AC, 0x00AD, 0x0000, 0x2015,
+    0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x0384, 0x0385, 0x0386, 0x00B7, 0x0388, 0x0389, 0x038A, 0x00BB,
+    0x038C, 0x00BD, 0x038E, 0x038F, 0x0390, 0x0391, 0x0392, 0x0393, 0x0394, 0x0395, 0x0396, 0x0397,
+    0x0398, 0x0399, 0x039A, 0x039B, 0x039C, 0x039D, 0x039E, 0x039F, 0x03A0, 0x03A1, 0x0000, 0x03A3,
+    0x03A4, 0x03A5, 0x03A6, 0x03A7, 0x03A8, 0x03A9, 0x03AA, 0x03AB, 0x03AC, 0x03AD, 0x03AE, 0x03AF,
+    0x03B0, 0x03B1, 0x03B2, 0x03B3, 0x03B4, 0x03B5, 0x03B6, 0x03B7, 0x03B8, 0x03B9, 0x03BA, 0x03BB,
+    0x03BC, 0x03BD, 0x03BE, 0x03BF, 0x03C0, 0x03C1, 0x03C2, 0x03C3, 0x03C4, 0x03C5, 0x03C6, 0x03C7,
+    0x03C8, 0x03C9, 0x03CA, 0x03CB, 0x03CC, 0x03CD, 0x03CE, 0x0000,
+];
+
+/// unicode code points of the bytes 0x80 to 0xFF in ISO-8859-15 (Latin-9, western european with euro sign)
+const ISO885915: [u16; 128] = [
+    0x0080, 0x0081, 0x0082, 0x0083, 0x0084, 0x0085, 0x0086, 0x0087, 0x0088, 0x0089, 0x008A, 0x008B,
+    0x008C, 0x008D, 0x008E, 0x008F, 0x0090, 0x0091, 0x0092, 0x0093, 0x0094, 0x0095, 0x0096, 0x0097,
+    0x0098, 0x0099, 0x009A, 0x009B, 0x009C, 0x009D, 0x009E, 0x009F, 0x00A0, 0x00A1, 0x00A2, 0x00A3,
+    0x20AC, 0x00A5, 0x0160, 0x00A7, 0x0161, 0x00A9, 0x00AA, 0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x00AF,
+    0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x017D, 0x00B5, 0x00B6, 0x00B7, 0x017E, 0x00B9, 0x00BA, 0x00BB,
+    0x0152, 0x0153, 0x0178, 0x00BF, 0x00C0, 0x00C1, 0x00C2, 0x00C3, 0x00C4, 0x00C5, 0x00C6, 0x00C7,
+    0x00C8, 0x00C9, 0x00CA, 0x00CB, 0x00CC, 0x00CD, 0x00CE, 0x00CF, 0x00D0, 0x00D1, 0x00D2, 0x00D3,
+    0x00D4, 0x00D5, 0x00D6, 0x00D7, 0x00D8, 0x00D9, 0x00DA, 0x00DB, 0x00DC, 0x00DD, 0x00DE, 0x00DF,
+    0x00E0, 0x00E1, 0x00E2, 0x00E3, 0x00E4, 0x00E5, 0x00E6, 0x00E7, 0x00E8, 0x00E9, 0x00EA, 0x00EB,
+    0x00EC, 0x00ED, 0x00EE, 0x00EF, 0x00F0, 0x00F1, 0x00F2, 0x00F3, 0x00F4, 0x00F5, 0x00F6, 0x00F7,
+    0x00F8, 0x00F9, 0x00FA, 0x00FB, 0x00FC, 0x00FD, 0x00FE, 0x00FF,
+];
+
+/// unicode code points of the bytes 0x80 to 0xFF in Windows-1250 (central european)
+const WINDOWS1250: [u16; 128] = [
+    0x20AC, 0x0000, 0x201A, 0x0000, 0x201E, 0x2026, 0x2020, 0x2021, 0x0000, 0x2030, 0x0160, 0x2039,
+    0x015A, 0x0164, 0x017D, 0x0179, 0x0000, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+    0x0000, 0x2122, 0x0161, 0x203A, 0x015B, 0x0165, 0x017E, 0x017A, 0x00A0, 0x02C7, 0x02D8, 0x0141,
+    0x00A4, 0x0104, 0x00A6, 0x00A7, 0x00A8, 0x00A9, 0x015E, 0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x017B,
+    0x00B0, 0x00B1, 0x02DB, 0x0142, 0x00B4, 0x00B5, 0x00B6, 0x00B7, 0x00B8, 0x0105, 0x015F, 0x00BB,
+    0x013D, 0x02DD, 0x013E, 0x017C, 0x0154, 0x00C1, 0x00C2, 0x0102, 0x00C4, 0x0139, 0x0106, 0x00C7,
+    0x010C, 0x00C9, 0x0118, 0x00CB, 0x011A, 0x00CD, 0x00CE, 0x010E, 0x0110, 0x0143, 0x0147, 0x00D3,
+    0x00D4, 0x0150, 0x00D6, 0x00D7, 0x0158, 0x016E, 0x00DA, 0x0170, 0x00DC, 0x00DD, 0x0162, 0x00DF,
+    0x0155, 0x00E1, 0x00E2, 0x0103, 0x00E4, 0x013A, 0x0107, 0x00E7, 0x010D, 0x00E9, 0x0119, 0x00EB,
+    0x011B, 0x00ED, 0x00EE, 0x010F, 0x0111, 0x0144, 0x0148, 0x00F3, 0x00F4, 0x0151, 0x00F6, 0x00F7,
+    0x0159, 0x016F, 0x00FA, 0x0171, 0x00FC, 0x00FD, 0x0163, 0x02D9,
+];
+
+/// unicode code points of the bytes 0x80 to 0xFF in Windows-1251 (cyrillic)
+const WINDOWS1251: [u16; 128] = [
+    0x0402, 0x0403, 0x201A, 0x0453, 0x201E, 0x2026, 0x2020, 0x2021, 0x20AC, 0x2030, 0x0409, 0x2039,
+    0x040A, 0x040C, 0x040B, 0x040F, 0x0452, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+    0x0000, 0x2122, 0x0459, 0x203A, 0x045A, 0x045C, 0x045B, 0x045F, 0x00A0, 0x040E, 0x045E, 0x0408,
+    0x00A4, 0x0490, 0x00A6, 0x00A7, 0x0401, 0x00A9, 0x0404, 0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x0407,
+    0x00B0, 0x00B1, 0x0406, 0x0456, 0x0491, 0x00B5, 0x00B6, 0x00B7, 0x0451, 0x2116, 0x0454, 0x00BB,
+    0x0458, 0x0405, 0x0455, 0x0457, 0x0410, 0x0411, 0x0412, 0x0413, 0x0414, 0x0415, 0x0416, 0x0417,
+    0x0418, 0x0419, 0x041A, 0x041B, 0x041C, 0x041D, 0x041E, 0x041F, 0x0420, 0x0421, 0x0422, 0x0423,
+    0x0424, 0x0425, 0x0426, 0x0427, 0x0428, 0x0429, 0x042A, 0x042B, 0x042C, 0x042D, 0x042E, 0x042F,
+    0x0430, 0x0431, 0x0432, 0x0433, 0x0434, 0x0435, 0x0436, 0x0437, 0x0438, 0x0439, 0x043A, 0x043B,
+    0x043C, 0x043D, 0x043E, 0x043F, 0x0440, 0x0441, 0x0442, 0x0443, 0x0444, 0x0445, 0x0446, 0x0447,
+    0x0448, 0x0449, 0x044A, 0x044B, 0x044C, 0x044D, 0x044E, 0x044F,
+];
+
+/// unicode code points of the bytes 0x80 to 0xFF in Windows-1252 (western european)
+const WINDOWS1252: [u16; 128] = [
+    0x20AC, 0x0000, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021, 0x02C6, 0x2030, 0x0160, 0x2039,
+    0x0152, 0x0000, 0x017D, 0x0000, 0x0000, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+    0x02DC, 0x2122, 0x0161, 0x203A, 0x0153, 0x0000, 0x017E, 0x0178, 0x00A0, 0x00A1, 0x00A2, 0x00A3,
+    0x00A4, 0x00A5, 0x00A6, 0x00A7, 0x00A8, 0x00A9, 0x00AA, 0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x00AF,
+    0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x00B4, 0x00B5, 0x00B6, 0x00B7, 0x00B8, 0x00B9, 0x00BA, 0x00BB,
+    0x00BC, 0x00BD, 0x00BE, 0x00BF, 0x00C0, 0x00C1, 0x00C2, 0x00C3, 0x00C4, 0x00C5, 0x00C6, 0x00C7,
+    0x00C8, 0x00C9, 0x00CA, 0x00CB, 0x00CC, 0x00CD, 0x00CE, 0x00CF, 0x00D0, 0x00D1, 0x00D2, 0x00D3,
+    0x00D4, 0x00D5, 0x00D6, 0x00D7, 0x00D8, 0x00D9, 0x00DA, 0x00DB, 0x00DC, 0x00DD, 0x00DE, 0x00DF,
+    0x00E0, 0x00E1, 0x00E2, 0x00E3, 0x00E4, 0x00E5, 0x00E6, 0x00E7, 0x00E8, 0x00E9, 0x00EA, 0x00EB,
+    0x00EC, 0x00ED, 0x00EE, 0x00EF, 0x00F0, 0x00F1, 0x00F2, 0x00F3, 0x00F4, 0x00F5, 0x00F6, 0x00F7,
+    0x00F8, 0x00F9, 0x00FA, 0x00FB, 0x00FC, 0x00FD, 0x00FE, 0x00FF,
+];
+
+impl EciCharset {
+    /// returns the ECI assignment number of the character set
+    pub fn assignment_number(&self) -> u32 {
+        match self {
+            EciCharset::Iso8859_1 => 3,
+            EciCharset::Iso8859_2 => 4,
+            EciCharset::Iso8859_5 => 7,
+            EciCharset::Iso8859_7 => 9,
+            EciCharset::Iso8859_15 => 17,
+            EciCharset::Windows1250 => 21,
+            EciCharset::Windows1251 => 22,
+            EciCharset::Windows1252 => 23,
+            EciCharset::Utf8 => 26,
+        }
+    }
+
+    /// returns the character set with the given ECI assignment number,
+    /// or None if it isn't supported
+    pub fn from_assignment_number(assignment_number: u32) -> Option<EciCharset> {
+        ECICHARSETS
+            .into_iter()
+            .find(|charset| charset.assignment_number() == assignment_number)
+    }
+
+    /// returns the upper half of a single byte character set,
+    /// or None for character sets with multiple bytes per character
+    fn upper_half(&self) -> Option<&'static [u16; 128]> {
+        match self {
+            EciCharset::Iso8859_1 | EciCharset::Utf8 => None,
+            EciCharset::Iso8859_2 => Some(&ISO88592),
+            EciCharset::Iso8859_5 => Some(&ISO88595),
+            EciCharset::Iso8859_7 => Some(&ISO88597),
+            EciCharset::Iso8859_15 => Some(&ISO885915),
+            EciCharset::Windows1250 => Some(&WINDOWS1250),
+            EciCharset::Windows1251 => Some(&WINDOWS1251),
+            EciCharset::Windows1252 => Some(&WINDOWS1252),
+        }
+    }
+
+    /// returns the bytes of a character in this character set,
+    /// or None if the character set doesn't contain the character
+    pub fn encode_char(&self, character: char) -> Option<Vec<u8>> {
+        let code_point: u32 = character as u32;
+        match self {
+            EciCharset::Utf8 => Some(character.to_string().into_bytes()),
+            // ISO-8859-1 is identical to the first 256 unicode code points
+            EciCharset::Iso8859_1 => (code_point < 0x100).then(|| vec![code_point as u8]),
+            _ => {
+                if code_point < 0x80 {
+                    return Some(vec![code_point as u8]);
+                }
+                self.upper_half()?
+                    .iter()
+                    .position(|table_point| *table_point != 0 && *table_point as u32 == code_point)
+                    .map(|position| vec![0x80 + position as u8])
+            }
+        }
+    }
+
+    /// converts bytes of this character set back into text
+    pub fn decode_bytes(&self, bytes: &[u8]) -> Result<String, QrError> {
+        match self {
+            EciCharset::Utf8 => String::from_utf8(bytes.to_vec()).map_err(|_| {
+                QrError::InvalidSymbol(String::from("byte mode data isn't valid UTF-8"))
+            }),
+            EciCharset::Iso8859_1 => Ok(bytes.iter().map(|byte| *byte as char).collect()),
+            _ => {
+                let upper_half = self.upper_half().expect("single byte character set");
+                let mut text: String = String::new();
+                for byte in bytes.iter() {
+                    if *byte < 0x80 {
+                        text.push(*byte as char);
+                        continue;
+                    }
+                    match char::from_u32(upper_half[(*byte - 0x80) as usize] as u32) {
+                        Some(character) if character != '\0' => text.push(character),
+                        _ => {
+                            return Err(QrError::InvalidSymbol(format!(
+                                "byte {byte:#04x} isn't part of {self}"
+                            )))
+                        }
+                    }
+                }
+                Ok(text)
+            }
+        }
+    }
+}
+
+impl Segment {
+    /// creates a segment that selects the character set with the given ECI assignment number
+    ///
+    /// the assignment number is written with one byte up to 127,
+    /// two bytes up to 16383 and three bytes up to 999999
+    pub fn eci(assignment_number: u32) -> Segment {
+        let designator: Vec<u8> = match assignment_number {
+            0..=127 => vec![assignment_number as u8],
+            128..=16383 => vec![
+                0b1000_0000 | (assignment_number >> 8) as u8,
+                (assignment_number & 0b1111_1111) as u8,
+            ],
+            16384..=MAXECIASSIGNMENT => vec![
+                0b1100_0000 | (assignment_number >> 16) as u8,
+                ((assignment_number >> 8) & 0b1111_1111) as u8,
+                (assignment_number & 0b1111_1111) as u8,
+            ],
+            _ => panic!(
+                "ECI assignment number {assignment_number} is larger than {MAXECIASSIGNMENT}"
+            ),
+        };
+        Segment::new(EncodingMode::Eci, designator)
+    }
+}
+
+mod tests {
+    #[test]
+    fn eci_designators() {
+        use super::Segment;
+
+        assert_eq!(Segment::eci(26).data, vec![26]);
+        assert_eq!(Segment::eci(127).data, vec![0x7F]);
+        assert_eq!(Segment::eci(128).data, vec![0x80, 0x80]);
+        assert_eq!(Segment::eci(16383).data, vec![0xBF, 0xFF]);
+        assert_eq!(Segment::eci(16384).data, vec![0xC0, 0x40, 0x00]);
+        assert_eq!(Segment::eci(999999).data, vec![0xCF, 0x42, 0x3F]);
+    }
+
+    #[test]
+    fn charset_transcoding() {
+        use crate::input::EciCharset;
+
+        let encode = |charset: EciCharset, text: &str| -> Option<Vec<u8>> {
+            let mut bytes: Vec<u8> = vec![];
+            for character in text.chars() {
+                bytes.extend(charset.encode_char(character)?);
+            }
+            Some(bytes)
+        };
+        assert_eq!(
+            encode(EciCharset::Iso8859_5, "Привет"),
+            Some(vec![0xBF, 0xE0, 0xD8, 0xD2, 0xD5, 0xE2])
+        );
+        assert_eq!(
+            encode(EciCharset::Windows1252, "€ café"),
+            Some(vec![0x80, 0x20, 0x63, 0x61, 0x66, 0xE9])
+        );
+        assert_eq!(encode(EciCharset::Iso8859_1, "é"), Some(vec![0xE9]));
+        assert_eq!(encode(EciCharset::Iso8859_15, "€"), Some(vec![0xA4]));
+        assert_eq!(encode(EciCharset::Utf8, "€"), Some(vec![0xE2, 0x82, 0xAC]));
+        assert_eq!(encode(EciCharset::Iso8859_1, "€"), None);
+        assert_eq!(encode(EciCharset::Windows1252, "Привет"), None);
+        // decoding returns the original text
+        assert_eq!(
+            EciCharset::Iso8859_5.decode_bytes(&[0xBF, 0xE0, 0xD8, 0xD2, 0xD5, 0xE2]),
+            Ok(String::from("Привет"))
+        );
+        assert_eq!(
+            EciCharset::Windows1252.decode_bytes(&[0x80, 0x20, 0x63, 0x61, 0x66, 0xE9]),
+            Ok(String::from("€ café"))
+        );
+        assert_eq!(
+            EciCharset::Iso8859_1.decode_bytes(&[0xE9]),
+            Ok(String::from("é"))
+        );
+        // 0x81 isn't assigned in windows-1252
+        assert!(EciCharset::Windows1252.decode_bytes(&[0x81]).is_err());
+        assert_eq!(
+            EciCharset::from_assignment_number(26),
+            Some(EciCharset::Utf8)
+        );
+        assert_eq!(EciCharset::from_assignment_number(2), None);
+    }
+}
@@ -0,0 +1,241 @@
+use crate::standard_qr_code::shift_jis_table::UNICODE_TO_SHIFT_JIS;
+
+/// constant for numeric mode indicator
+pub const NUMERICMODEINDICATOR: u8 = 0b0001;
+/// constant for alphanumeric mode indicator
+pub const ALPHANUMERICMODEINDICATOR: u8 = 0b0010;
+/// constant for byte mode indicator
+pub const BYTEMODEINDICATOR: u8 = 0b0100;
+/// constant for extended channel interpretation (ECI) mode indicator
+pub const ECIMODEINDICATOR: u8 = 0b0111;
+/// constant for structured append mode indicator
+pub const STRUCTUREDAPPENDMODEINDICATOR: u8 = 0b0011;
+/// constant for FNC1 in first position mode indicator
+pub const FNC1FIRSTMODEINDICATOR: u8 = 0b0101;
+/// constant for FNC1 in second position mode indicator
+pub const FNC1SECONDMODEINDICATOR: u8 = 0b1001;
+/// constant for kanji mode indicator
+pub const KANJIMODEINDICATOR: u8 = 0b1000;
+/// bits a single character takes up in byte mode
+pub const CHARACTERBITS: u8 = 8;
+/// bits a single character takes up in kanji mode
+pub const KANJIBITS: u8 = 13;
+/// all characters of the alphanumeric mode; the index is the value of the character
+pub const ALPHANUMERICTABLE: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+/// the modes the data can be encoded in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EncodingMode {
+    /// digits 0-9; three digits are packed into 10 bits
+    Numeric,
+    /// digits, uppercase letters and " $%*+-./:"; two characters are packed into 11 bits
+    Alphanumeric,
+    /// every byte takes up 8 bits
+    Byte,
+    /// double byte shift jis characters of JIS X 0208; every character takes up 13 bits
+    Kanji,
+    /// extended channel interpretation; selects the character set of the following
+    /// byte mode data, has no character count indicator and is followed by the assignment number
+    Eci,
+    /// marks the symbol as part of a sequence of up to 16 symbols; has no character count
+    /// indicator and is followed by the symbol index, the number of symbols and a parity byte
+    StructuredAppend,
+    /// marks the data as GS1 element strings; has no character count indicator and no data
+    Fnc1First,
+    /// marks the data as formatted according to an industry application; has no character
+    /// count indicator and is followed by the application indicator
+    Fnc1Second,
+}
+
+impl EncodingMode {
+    /// returns the 4 bit mode indicator that precedes the data
+    pub fn indicator(&self) -> u8 {
+        match self {
+            EncodingMode::Numeric => NUMERICMODEINDICATOR,
+            EncodingMode::Alphanumeric => ALPHANUMERICMODEINDICATOR,
+            EncodingMode::Byte => BYTEMODEINDICATOR,
+            EncodingMode::Kanji => KANJIMODEINDICATOR,
+            EncodingMode::Eci => ECIMODEINDICATOR,
+            EncodingMode::StructuredAppend => STRUCTUREDAPPENDMODEINDICATOR,
+            EncodingMode::Fnc1First => FNC1FIRSTMODEINDICATOR,
+            EncodingMode::Fnc1Second => FNC1SECONDMODEINDICATOR,
+        }
+    }
+
+    /// returns the length of the character count indicator in bits,
+    /// which grows at version 10 and 27
+    pub fn character_count_bits(&self, version: u8) -> u8 {
+        match (self, version) {
+            (EncodingMode::Numeric, 1..=9) => 10,
+            (EncodingMode::Numeric, 10..=26) => 12,
+            (EncodingMode::Numeric, _) => 14,
+            (EncodingMode::Alphanumeric, 1..=9) => 9,
+            (EncodingMode::Alphanumeric, 10..=26) => 11,
+            (EncodingMode::Alphanumeric, _) => 13,
+            (EncodingMode::Byte, 1..=9) => 8,
+            (EncodingMode::Byte, _) => 16,
+            (EncodingMode::Kanji, 1..=9) => 8,
+            (EncodingMode::Kanji, 10..=26) => 10,
+            (EncodingMode::Kanji, _) => 12,
+            (
+                EncodingMode::Eci
+                | EncodingMode::StructuredAppend
+                | EncodingMode::Fnc1First
+                | EncodingMode::Fnc1Second,
+                _,
+            ) => 0,
+        }
+    }
+
+    /// returns the amount of bits the characters take up
+    /// (without mode indicator and character count indicator)
+    pub fn data_bits(&self, num_characters: usize) -> usize {
+        match self {
+            // groups of three digits take up 10 bits, a remainder
+            // of two digits 7 bits and a single digit 4 bits
+            EncodingMode::Numeric => {
+                (num_characters / 3) * 10
+                    + match num_characters % 3 {
+                        2 => 7,
+                        1 => 4,
+                        _ => 0,
+                    }
+            }
+            // pairs of characters take up 11 bits, a single remaining character 6 bits
+            EncodingMode::Alphanumeric => (num_characters / 2) * 11 + (num_characters % 2) * 6,
+            EncodingMode::Byte
+            | EncodingMode::Eci
+            | EncodingMode::StructuredAppend
+            | EncodingMode::Fnc1First
+            | EncodingMode::Fnc1Second => num_characters * CHARACTERBITS as usize,
+            EncodingMode::Kanji => num_characters * KANJIBITS as usize,
+        }
+    }
+
+    /// returns whether the mode holds the text itself (and not information about it)
+    pub fn is_data(&self) -> bool {
+        matches!(
+            self,
+            EncodingMode::Numeric
+                | EncodingMode::Alphanumeric
+                | EncodingMode::Byte
+                | EncodingMode::Kanji
+        )
+    }
+
+    /// returns the number of characters the encoded bytes of a segment hold in this mode,
+    /// which is the value written into the character count indicator
+    pub fn character_count(&self, num_bytes: usize) -> usize {
+        match self {
+            // every kanji character is stored as two shift jis bytes
+            EncodingMode::Kanji => num_bytes / 2,
+            _ => num_bytes,
+        }
+    }
+
+    /// returns the total amount of bits the text takes up in the given version
+    /// (mode indicator, character count indicator and the data itself)
+    pub fn total_bits(&self, num_characters: usize, version: u8) -> usize {
+        4 + self.character_count_bits(version) as usize + self.data_bits(num_characters)
+    }
+}
+
+/// returns the value of a character in the alphanumeric mode,
+/// or None if the character isn't part of the alphanumeric mode
+pub fn alphanumeric_value(character: char) -> Option<u8> {
+    ALPHANUMERICTABLE
+        .chars()
+        .position(|table_character| table_character == character)
+        .map(|position| position as u8)
+}
+
+/// returns the shift jis value of a character, or None if the
+/// character can't be written in kanji mode
+pub fn shift_jis_value(character: char) -> Option<u16> {
+    let code_point: u32 = character as u32;
+    if code_point > u16::MAX as u32 {
+        return None;
+    }
+    UNICODE_TO_SHIFT_JIS
+        .binary_search_by_key(&(code_point as u16), |(unicode, _)| *unicode)
+        .ok()
+        .map(|index| UNICODE_TO_SHIFT_JIS[index].1)
+}
+
+/// converts a shift jis value into the 13 bit value written in kanji mode
+pub fn kanji_value(shift_jis: u16) -> u16 {
+    // subtract the offset of the range the value is in
+    let reduced: u16 = if shift_jis <= 0x9FFC {
+        shift_jis - 0x8140
+    } else {
+        shift_jis - 0xC140
+    };
+    // most significant byte times 0xC0 plus the least significant byte
+    (reduced >> 8) * 0xC0 + (reduced & 0b1111_1111)
+}
+
+/// returns the character of a shift jis value, or None if the value isn't a character
+/// of the kanji mode
+pub fn shift_jis_character(shift_jis: u16) -> Option<char> {
+    UNICODE_TO_SHIFT_JIS
+        .iter()
+        .find(|(_, table_shift_jis)| *table_shift_jis == shift_jis)
+        .and_then(|(unicode, _)| char::from_u32(*unicode as u32))
+}
+
+/// converts the 13 bit value written in kanji mode back into a shift jis value
+pub fn shift_jis_from_kanji(kanji: u16) -> u16 {
+    let reduced: u16 = ((kanji / 0xC0) << 8) | (kanji % 0xC0);
+    // add the offset of the range the value is in
+    if reduced + 0x8140 <= 0x9FFC {
+        reduced + 0x8140
+    } else {
+        reduced + 0xC140
+    }
+}
+
+mod tests {
+    #[test]
+    fn numeric_bit_lengths() {
+        use super::EncodingMode;
+
+        // "01234567" in version 1: 4 + 10 + 10 + 10 + 7
+        assert_eq!(EncodingMode::Numeric.total_bits(8, 1), 41);
+        assert_eq!(EncodingMode::Numeric.character_count_bits(10), 12);
+        assert_eq!(EncodingMode::Numeric.character_count_bits(27), 14);
+    }
+
+    #[test]
+    fn alphanumeric_bit_lengths() {
+        use super::{alphanumeric_value, EncodingMode};
+
+        assert_eq!(alphanumeric_value('0'), Some(0));
+        assert_eq!(alphanumeric_value('A'), Some(10));
+        assert_eq!(alphanumeric_value(':'), Some(44));
+        assert_eq!(alphanumeric_value('a'), None);
+        // "AC-42" in version 1: 4 + 9 + 11 + 11 + 6
+        assert_eq!(EncodingMode::Alphanumeric.total_bits(5, 1), 41);
+        assert_eq!(EncodingMode::Alphanumeric.character_count_bits(10), 11);
+        assert_eq!(EncodingMode::Alphanumeric.character_count_bits(40), 13);
+    }
+
+    #[test]
+    fn kanji_values() {
+        use super::{
+            kanji_value, shift_jis_character, shift_jis_from_kanji, shift_jis_value, EncodingMode,
+        };
+
+        // examples from ISO/IEC 18004
+        assert_eq!(shift_jis_value('点'), Some(0x935F));
+        assert_eq!(shift_jis_value('茗'), Some(0xE4AA));
+        assert_eq!(shift_jis_value('a'), None);
+        assert_eq!(kanji_value(0x935F), 0x0D9F);
+        assert_eq!(kanji_value(0xE4AA), 0x1AAA);
+        assert_eq!(shift_jis_from_kanji(0x0D9F), 0x935F);
+        assert_eq!(shift_jis_from_kanji(0x1AAA), 0xE4AA);
+        assert_eq!(shift_jis_character(0x935F), Some('点'));
+        assert_eq!(shift_jis_character(0x0041), None);
+        assert_eq!(EncodingMode::Kanji.total_bits(2, 1), 4 + 8 + 26);
+        assert_eq!(EncodingMode::Kanji.character_count(4), 2);
+    }
+}
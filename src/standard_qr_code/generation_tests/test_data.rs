@@ -1364,25 +1364,25 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
+            LogicalFalse,
+            LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
@@ -1411,27 +1411,27 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
@@ -1457,28 +1457,28 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
@@ -1504,27 +1504,27 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
@@ -1553,24 +1553,24 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
@@ -1598,31 +1598,31 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
+            LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -1692,27 +1692,27 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
-            LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
+            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -1731,41 +1731,41 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
-            LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
+            LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
@@ -1778,43 +1778,43 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -1827,40 +1827,40 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
+            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
-            LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
@@ -1874,13 +1874,15 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
+            LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
@@ -1889,26 +1891,24 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -1921,30 +1921,33 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
+            LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
@@ -1955,9 +1958,6 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalFalse,
-            LogicalFalse,
             LogicalFalse,
             LogicalFalse,
         ],
@@ -1966,39 +1966,40 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalFalse,
+            LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
@@ -2006,29 +2007,30 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalFalse,
         ],
         [
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalFalse,
+            LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
@@ -2036,16 +2038,13 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
@@ -2054,19 +2053,24 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
+            LogicalFalse,
         ],
         [
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalFalse,
+            LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
@@ -2074,29 +2078,25 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -2109,28 +2109,23 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalFalse,
+            LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
@@ -2138,13 +2133,18 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
+            LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -2156,41 +2156,41 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
-            LogicalTrue,
             LogicalTrue,
-            LogicalTrue,
-            LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
+            LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalTrue,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalTrue,
+            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -2203,13 +2203,16 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
@@ -2225,14 +2228,9 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
@@ -2242,45 +2240,48 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
         ],
         [
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
@@ -2288,24 +2289,17 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalFalse,
         ],
         [
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
@@ -2315,23 +2309,29 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
+            LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -2342,6 +2342,8 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
+            LogicalFalse,
+            LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
@@ -2350,11 +2352,15 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
@@ -2367,20 +2373,14 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalFalse,
-            LogicalFalse,
             LogicalFalse,
             LogicalFalse,
         ],
@@ -2391,27 +2391,23 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalFalse,
+            LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
@@ -2419,79 +2415,71 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
-        ],
-        [
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
+        ],
+        [
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
-            LogicalTrue,
             LogicalTrue,
             LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-        ],
-        [
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
+        ],
+        [
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -2499,26 +2487,38 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
+            LogicalTrue,
+            LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalFalse,
+            LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
@@ -2530,34 +2530,33 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalFalse,
-            LogicalTrue,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
@@ -2567,6 +2566,7 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -2577,29 +2577,26 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
@@ -2607,13 +2604,16 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -2624,35 +2624,34 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
-            LogicalTrue,
             LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
-            LogicalTrue,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
@@ -2660,7 +2659,8 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -2673,32 +2673,31 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalFalse,
+            LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
-            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
@@ -2707,6 +2706,7 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -2726,35 +2726,35 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -2775,33 +2775,33 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -2821,23 +2821,23 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
@@ -2845,14 +2845,14 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
         ],
         [
             LogicalFalse,
@@ -2868,13 +2868,12 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
@@ -2887,6 +2886,8 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
+            LogicalFalse,
+            LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
@@ -2895,7 +2896,6 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -2915,38 +2915,38 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
+            LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
+            LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalFalse,
         ],
         [
             LogicalFalse,
@@ -2961,35 +2961,35 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -3010,32 +3010,32 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
+            LogicalTrue,
+            LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalTrue,
             LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
@@ -3055,35 +3055,35 @@ pub fn _test_data_byte_shuffling() -> [[SymbolStatus; 45]; 45] {
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
+            LogicalTrue,
             LogicalTrue,
             LogicalTrue,
             LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
-            LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
+            LogicalTrue,
+            LogicalTrue,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
@@ -3537,22 +3537,22 @@ pub fn _test_data_version_information() -> [[SymbolStatus; 57]; 57] {
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
             LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
+            LogicalTrue,
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
@@ -3598,19 +3598,19 @@ pub fn _test_data_version_information() -> [[SymbolStatus; 57]; 57] {
             LogicalFalse,
             LogicalTrue,
             LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalTrue,
+            LogicalTrue,
             LogicalTrue,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
             LogicalFalse,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
-            LogicalTrue,
+            LogicalFalse,
+            LogicalFalse,
+            LogicalFalse,
             LogicalFalse,
             LogicalFalse,
             LogicalTrue,
@@ -6647,3 +6647,30 @@ pub fn _test_data_version_information() -> [[SymbolStatus; 57]; 57] {
         ],
     ]
 }
+
+/// "HELLO WORLD" at level Q with mask 0, one string per row (# is black)
+pub fn _reference_hello_world() -> [&'static str; 21] {
+    [
+        "#######.##....#######",
+        "#.....#.#..#..#.....#",
+        "#.###.#.#..##.#.###.#",
+        "#.###.#.#.....#.###.#",
+        "#.###.#.#.#...#.###.#",
+        "#.....#...#...#.....#",
+        "#######.#.#.#.#######",
+        "........#............",
+        ".##.#.##....#.#.#####",
+        ".#......####....#...#",
+        "..##.###.##...#.##...",
+        ".##.##.#..##.#.#.###.",
+        "#...#.#.#.###.###.#.#",
+        "........##.#..#...#.#",
+        "#######.#.#....#.##..",
+        "#.....#..#.##.##.#...",
+        "#.###.#.#.#...#######",
+        "#.###.#..#.#.#.#...#.",
+        "#.###.#.#..#.###.#..#",
+        "#.....#.#.####...#.##",
+        "#######....#.###....#",
+    ]
+}
@@ -1,18 +1,16 @@
 #[test]
 fn generated_output_basics() {
-    use crate::input::{ErrorLevel, Settings};
-    use crate::qr_code;
+    use crate::input::{test_settings, ErrorLevel};
+    use crate::standard_qr_code::qr_code;
     use crate::standard_qr_code::{
         generation_tests::test_data,
         qr_struct::SymbolStatus::{self},
     };
 
-    let settings = Settings {
-        information: String::from("Hello, world! 123"),
-        error_level: ErrorLevel::M,
-        debugging: false,
-    };
-    let qrdata = qr_code(settings)._get_data();
+    let mut settings = test_settings("Hello, world! 123", ErrorLevel::M);
+    // the expected symbol was generated with mask 4
+    settings.symbol.mask = Some(4);
+    let qrdata = qr_code(settings).unwrap()[0].get_data();
     let test_data = test_data::_test_data_basics();
     let mut test_wrapper_vector: Vec<Vec<SymbolStatus>> = vec![];
     for line in test_data.iter() {
@@ -24,19 +22,19 @@ fn generated_output_basics() {
 
 #[test]
 fn generated_output_byte_shuffling() {
-    use crate::input::{ErrorLevel, Settings};
-    use crate::qr_code;
+    use crate::input::{test_settings, ErrorLevel};
+    use crate::standard_qr_code::qr_code;
     use crate::standard_qr_code::{
         generation_tests::test_data,
         qr_struct::SymbolStatus::{self},
     };
 
-    let settings = Settings {
-        information: String::from("Example string to test qr code program for byte shuffling"),
-        error_level: ErrorLevel::Q,
-        debugging: false,
-    };
-    let qrdata = qr_code(settings)._get_data();
+    // the expected symbol uses the mask 2 the reference encoders chose as well
+    let settings = test_settings(
+        "Example string to test qr code program for byte shuffling",
+        ErrorLevel::Q,
+    );
+    let qrdata = qr_code(settings).unwrap()[0].get_data();
     let test_data = test_data::_test_data_byte_shuffling();
     let mut test_wrapper_vector: Vec<Vec<SymbolStatus>> = vec![];
     for line in test_data.iter() {
@@ -46,21 +44,116 @@ fn generated_output_byte_shuffling() {
     assert_eq!(test_wrapper_vector, qrdata);
 }
 
+#[test]
+fn generated_output_mask_selection() {
+    use crate::input::{test_settings, ErrorLevel};
+    use crate::standard_qr_code::generation_tests::test_data;
+    use crate::standard_qr_code::qr_code;
+
+    // the masks and penalties (n1, n2, n3, n4) of the qrcode and qrcodegen crates
+    // for the same data codewords, counting each finder-like pattern once
+    let cases = [
+        (
+            "Hello, world! 123",
+            ErrorLevel::M,
+            2,
+            [
+                (232, 207, 800, 0),
+                (250, 180, 720, 0),
+                (245, 183, 720, 0),
+                (238, 216, 760, 0),
+                (235, 210, 800, 0),
+                (254, 249, 720, 0),
+                (254, 204, 760, 0),
+                (226, 216, 720, 0),
+            ],
+        ),
+        (
+            "Example string to test qr code program for byte shuffling",
+            ErrorLevel::Q,
+            2,
+            [
+                (450, 450, 960, 0),
+                (477, 483, 840, 0),
+                (415, 414, 760, 0),
+                (417, 417, 840, 0),
+                (411, 465, 800, 0),
+                (461, 417, 800, 0),
+                (427, 450, 920, 0),
+                (403, 363, 880, 0),
+            ],
+        ),
+        (
+            "HELLO WORLD",
+            ErrorLevel::Q,
+            0,
+            [
+                (177, 90, 760, 0),
+                (172, 138, 800, 0),
+                (205, 141, 800, 0),
+                (177, 144, 760, 0),
+                (195, 144, 800, 0),
+                (191, 165, 760, 0),
+                (172, 102, 800, 0),
+                (198, 120, 760, 0),
+            ],
+        ),
+        (
+            "00000000000000000000000000000000000000",
+            ErrorLevel::L,
+            0,
+            [
+                (155, 81, 720, 0),
+                (277, 66, 720, 0),
+                (276, 180, 760, 0),
+                (143, 81, 800, 0),
+                (148, 171, 720, 0),
+                (234, 123, 800, 0),
+                (216, 171, 760, 10),
+                (155, 96, 720, 0),
+            ],
+        ),
+    ];
+    for (text, error_level, mask, penalties) in cases {
+        let settings = test_settings(text, error_level);
+        let qrdata = &qr_code(settings).unwrap()[0];
+        assert_eq!(qrdata.get_mask(), mask, "{text}");
+        let calculated: Vec<(u32, u32, u32, u32)> = qrdata
+            .get_mask_penalties()
+            .iter()
+            .map(|penalty| (penalty.n1, penalty.n2, penalty.n3, penalty.n4))
+            .collect();
+        assert_eq!(calculated, penalties, "{text}");
+    }
+
+    // the whole symbol of the well known example, as the reference encoders drew it
+    let settings = test_settings("HELLO WORLD", ErrorLevel::Q);
+    let modules = qr_code(settings).unwrap()[0].get_modules();
+    let rows: Vec<String> = (0..modules.len())
+        .map(|y| {
+            modules
+                .iter()
+                .map(|column| if column[y] { '#' } else { '.' })
+                .collect()
+        })
+        .collect();
+    assert_eq!(rows, test_data::_reference_hello_world());
+}
+
 #[test]
 fn generated_output_version_information() {
-    use crate::input::{ErrorLevel, Settings};
-    use crate::qr_code;
+    use crate::input::{test_settings, ErrorLevel};
+    use crate::standard_qr_code::qr_code;
     use crate::standard_qr_code::{
         generation_tests::test_data,
         qr_struct::SymbolStatus::{self},
     };
 
-    let settings = Settings {
-        information: String::from("This example is testing the version information, which appears in versions 7 or higher."),
-        error_level: ErrorLevel::Q,
-        debugging: false,
-    };
-    let qrdata = qr_code(settings)._get_data();
+    let settings = test_settings(
+        "This example is testing the version information, which appears in versions 7 or higher.",
+        ErrorLevel::Q,
+    );
+    let qrdata = qr_code(settings).unwrap()[0].get_data();
     let test_data = test_data::_test_data_version_information();
     let mut test_wrapper_vector: Vec<Vec<SymbolStatus>> = vec![];
     for line in test_data.iter() {
@@ -69,3 +162,263 @@ fn generated_output_version_information() {
     // check if two outputs are the same
     assert_eq!(test_wrapper_vector, qrdata);
 }
+
+#[test]
+fn generated_output_utf8_bytes() {
+    use crate::input::{test_settings, ErrorLevel};
+    use crate::standard_qr_code::qr_code;
+
+    // characters outside of ascii must be written as their utf-8 bytes
+    // and the character count must be the number of bytes
+    // (none of them are part of JIS X 0208, which would be written in kanji mode)
+    for text in [
+        "café",
+        "Grüße aus Köln",
+        "€ 42,50",
+        "Ñandú 😀",
+        "Šťastný nový rok",
+    ] {
+        let settings = test_settings(text, ErrorLevel::M);
+        let codewords = qr_code(settings).unwrap()[0]._get_data_codewords();
+        let mut expected: Vec<u8> = vec![0x40 | (text.len() >> 4) as u8];
+        for (index, byte) in text.bytes().enumerate() {
+            let previous: u8 = if index == 0 {
+                text.len() as u8
+            } else {
+                text.as_bytes()[index - 1]
+            };
+            expected.push((previous << 4) | (byte >> 4));
+        }
+        assert_eq!(codewords[..expected.len()], expected, "text: {text}");
+    }
+}
+
+#[test]
+fn generated_output_utf8_eci() {
+    use crate::input::{test_settings, EciCharset, ErrorLevel};
+    use crate::standard_qr_code::qr_code;
+
+    let mut settings = test_settings("Grüße", ErrorLevel::M);
+    settings.eci = Some(EciCharset::Utf8);
+    let codewords = qr_code(settings).unwrap()[0]._get_data_codewords();
+    // 0111 00011010 (ECI 26) 0100 00000111 (7 bytes) 01000111 (G) ...
+    assert_eq!(codewords[..4], [0x71, 0xa4, 0x07, 0x47]);
+}
+
+#[test]
+fn generated_output_eci_charsets() {
+    use crate::input::{test_settings, EciCharset, ErrorLevel};
+    use crate::standard_qr_code::qr_code;
+
+    let mut settings = test_settings("Ёж", ErrorLevel::M);
+    settings.eci = Some(EciCharset::Iso8859_5);
+    let codewords = qr_code(settings).unwrap()[0]._get_data_codewords();
+    // 0111 00000111 (ECI 7) 0100 00000010 (2 bytes) 10100001 (Ё) 11010110 (ж) 0000
+    assert_eq!(codewords[..5], [0x70, 0x74, 0x02, 0xa1, 0xd6]);
+
+    let mut settings = test_settings("Prix: 5€", ErrorLevel::M);
+    settings.eci = Some(EciCharset::Windows1252);
+    let codewords = qr_code(settings).unwrap()[0]._get_data_codewords();
+    // 0111 00010111 (ECI 23) 0100 00001000 (8 bytes) "Prix: 5" 10000000 (€)
+    let mut expected: Vec<u8> = vec![0x71, 0x74, 0x08];
+    let bytes: [u8; 8] = [b'P', b'r', b'i', b'x', b':', b' ', b'5', 0x80];
+    for byte in bytes {
+        expected.push(byte);
+    }
+    assert_eq!(codewords[..11], expected);
+}
+
+#[test]
+fn generated_output_structured_append() {
+    use crate::input::{test_settings, ErrorLevel};
+    use crate::standard_qr_code::qr_code;
+
+    // too long for a single qr code of error level L (2953 bytes)
+    let text: String = "configuration blob; ".repeat(200);
+    let settings = test_settings(&text, ErrorLevel::L);
+    let symbols = qr_code(settings).unwrap();
+    assert_eq!(symbols.len(), 2);
+    let mut parity: u8 = 0;
+    for byte in text.bytes() {
+        parity ^= byte;
+    }
+    for (index, symbol) in symbols.iter().enumerate() {
+        let codewords = symbol._get_data_codewords();
+        // 0011 iiii tttt pppppppp
+        assert_eq!(codewords[0], 0x30 | index as u8);
+        assert_eq!(codewords[1], 0x10 | (parity >> 4));
+        assert_eq!(codewords[2] >> 4, parity & 0b1111);
+    }
+}
+
+#[test]
+fn generated_output_gs1() {
+    use crate::input::{test_settings, ErrorLevel};
+    use crate::standard_qr_code::qr_code;
+
+    let mut settings = test_settings("(01)09501101530003(17)250101(10)ABC", ErrorLevel::M);
+    settings.gs1 = true;
+    let codewords = qr_code(settings).unwrap()[0]._get_data_codewords();
+    // 0101 (FNC1 first) 0001 0000011010 (26 digits) 0000001010 (010) ...
+    assert_eq!(codewords[..3], [0x51, 0x06, 0x80]);
+
+    let mut settings = test_settings("AB-123", ErrorLevel::M);
+    settings.fnc1_second = Some(String::from("37"));
+    let codewords = qr_code(settings).unwrap()[0]._get_data_codewords();
+    // 1001 (FNC1 second) 00100101 (37) 0010 000000110 (6 characters) ...
+    assert_eq!(codewords[..3], [0x92, 0x52, 0x03]);
+}
+
+#[test]
+fn generated_output_all_error_lengths() {
+    use crate::input::{test_settings, ErrorLevel};
+    use crate::standard_qr_code::capacity::capacity;
+    use crate::standard_qr_code::decoder::decode;
+    use crate::standard_qr_code::encoding_modes::EncodingMode;
+    use crate::standard_qr_code::qr_code;
+
+    // every version and level has its own block structure, so each one is filled completely
+    // and read back
+    for version in 1..=40 {
+        for error_level in [ErrorLevel::L, ErrorLevel::M, ErrorLevel::Q, ErrorLevel::H] {
+            let length: usize = capacity(version, error_level, EncodingMode::Numeric).unwrap();
+            let text: String = (0..length)
+                .map(|index| char::from(b'0' + (index % 10) as u8))
+                .collect();
+            let mut settings = test_settings(&text, error_level);
+            settings.symbol.min_version = version;
+            settings.symbol.max_version = version;
+            let symbols = qr_code(settings).unwrap();
+            assert_eq!(symbols.len(), 1);
+            assert_eq!(symbols[0].get_version(), version);
+            let decoded = decode(&symbols[0].get_data(), 4).unwrap();
+            assert_eq!(
+                (decoded.version, decoded.error_level),
+                (version, error_level)
+            );
+            assert_eq!(decoded.text(), Ok(text), "{version}-{error_level:?}");
+        }
+    }
+}
+
+#[test]
+fn generated_output_round_trip() {
+    use crate::input::{test_settings, EciCharset, ErrorLevel};
+    use crate::standard_qr_code::decoder::decode;
+    use crate::standard_qr_code::qr_code;
+
+    let texts: [(&str, Option<EciCharset>); 8] = [
+        ("Hello, world! 123", None),
+        ("01234567", None),
+        ("HELLO WORLD", None),
+        ("点茗", None),
+        ("https://EXAMPLE.COM/ORDER/000123456789", None),
+        ("Grüße aus Köln", Some(EciCharset::Iso8859_1)),
+        (
+            "This example is testing the version information, which appears in versions 7 or higher.",
+            None,
+        ),
+        (
+            "A longer text, which needs a bigger version with several error correction blocks \
+             of two different lengths: 0123456789 ABCDEFGHIJKLMNOPQRSTUVWXYZ abcdefghijklmnopqrstuvwxyz",
+            Some(EciCharset::Utf8),
+        ),
+    ];
+    for (text, eci) in texts {
+        for error_level in [ErrorLevel::L, ErrorLevel::M, ErrorLevel::Q, ErrorLevel::H] {
+            let mut settings = test_settings(text, error_level);
+            settings.eci = eci;
+            let symbol = &qr_code(settings).unwrap()[0];
+            let decoded = decode(&symbol.get_data(), 4).unwrap();
+            assert_eq!(decoded.version, symbol.get_version());
+            assert_eq!(decoded.error_level, error_level);
+            // a freshly generated code must not contain any errors
+            assert_eq!(decoded.corrected_errors, 0, "text: {text} ({error_level})");
+            assert_eq!(decoded.text(), Ok(String::from(text)));
+        }
+    }
+}
+
+#[test]
+fn generated_output_round_trip_gs1() {
+    use crate::input::{test_settings, ErrorLevel};
+    use crate::standard_qr_code::decoder::decode;
+    use crate::standard_qr_code::qr_code;
+
+    let mut settings = test_settings("(01)09501101530003(10)AB%1(17)250101", ErrorLevel::M);
+    settings.gs1 = true;
+    let symbol = &qr_code(settings).unwrap()[0];
+    let decoded = decode(&symbol.get_data(), 4).unwrap();
+    assert_eq!(
+        decoded.text(),
+        Ok(String::from("010950110153000310AB%1\u{1D}17250101"))
+    );
+}
+
+#[test]
+fn generated_output_round_trip_structured_append() {
+    use crate::input::{test_settings, ErrorLevel};
+    use crate::standard_qr_code::decoder::decode;
+    use crate::standard_qr_code::qr_code;
+
+    let text: String = "configuration blob; ".repeat(200);
+    let settings = test_settings(&text, ErrorLevel::L);
+    let mut decoded_text: String = String::new();
+    for symbol in qr_code(settings).unwrap() {
+        let decoded = decode(&symbol.get_data(), 4).unwrap();
+        assert_eq!(decoded.corrected_errors, 0);
+        decoded_text.push_str(&decoded.text().unwrap());
+    }
+    assert_eq!(decoded_text, text);
+}
+
+#[test]
+fn decode_damaged_code() {
+    use crate::input::{test_settings, ErrorLevel};
+    use crate::standard_qr_code::decoder::decode;
+    use crate::standard_qr_code::qr_code;
+    use crate::standard_qr_code::qr_struct::SymbolStatus;
+
+    let settings = test_settings("Hello, world! 123", ErrorLevel::H);
+    let mut data = qr_code(settings).unwrap()[0].get_data();
+    // paint a block of 4x4 elements in the data region black
+    for column in data.iter_mut().skip(14).take(4) {
+        for element in column.iter_mut().skip(14).take(4) {
+            *element = SymbolStatus::LogicalTrue;
+        }
+    }
+    let decoded = decode(&data, 4).unwrap();
+    assert!(decoded.corrected_errors > 0);
+    assert_eq!(decoded.text(), Ok(String::from("Hello, world! 123")));
+}
+
+#[test]
+fn generation_errors() {
+    use crate::error::QrError;
+    use crate::input::{test_settings, EciCharset, ErrorLevel};
+    use crate::standard_qr_code::qr_code;
+    use crate::standard_qr_code::qr_struct::QRData;
+
+    let mut settings = test_settings("5 €", ErrorLevel::M);
+    settings.eci = Some(EciCharset::Iso8859_1);
+    assert_eq!(
+        qr_code(settings.clone()).err(),
+        Some(QrError::InvalidCharacter {
+            character: '€',
+            charset: EciCharset::Iso8859_1
+        })
+    );
+    let mut settings = test_settings("(01)123", ErrorLevel::M);
+    settings.gs1 = true;
+    assert!(matches!(
+        qr_code(settings).err(),
+        Some(QrError::InvalidGs1(_))
+    ));
+    // 16 symbols of version 40-H hold 16 * 1273 bytes
+    let settings = test_settings(&"a".repeat(16 * 1273 + 1), ErrorLevel::H);
+    assert_eq!(qr_code(settings).err(), Some(QrError::TooManySymbols(16)));
+    assert_eq!(
+        QRData::empty(41, ErrorLevel::L).err(),
+        Some(QrError::InvalidVersion(41))
+    );
+}
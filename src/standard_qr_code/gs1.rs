@@ -0,0 +1,336 @@
+// based on the "GS1 General Specifications" (Release 24.0, January 2024), section 3 and 7.8
+// https://www.gs1.org/standards/barcodes-epcrfid-id-keys/gs1-general-specifications
+
+use crate::error::QrError;
+use crate::standard_qr_code::encoding_modes::EncodingMode;
+use crate::standard_qr_code::segmentation::Segment;
+
+/// group separator, which ends variable length element strings
+pub const GROUPSEPARATOR: char = '\u{1D}';
+
+/// describes the data field of an application identifier
+struct AiFormat {
+    /// the application identifier itself (or the first digits shared by a group of them)
+    prefix: &'static str,
+    /// number of digits of the application identifier
+    ai_length: usize,
+    /// the data is exactly max_length characters long instead of up to max_length characters
+    is_fixed: bool,
+    /// maximum length of the data field
+    max_length: usize,
+    /// the data field may only contain digits
+    is_numeric: bool,
+    /// the last digit of the data field is a GS1 check digit
+    has_check_digit: bool,
+}
+
+/// macro for the entries of the application identifier table
+macro_rules! ai {
+    ($prefix:expr, $ai_length:expr, fixed n $length:expr, check) => {
+        AiFormat {
+            prefix: $prefix,
+            ai_length: $ai_length,
+            is_fixed: true,
+            max_length: $length,
+            is_numeric: true,
+            has_check_digit: true,
+        }
+    };
+    ($prefix:expr, $ai_length:expr, fixed n $length:expr) => {
+        AiFormat {
+            prefix: $prefix,
+            ai_length: $ai_length,
+            is_fixed: true,
+            max_length: $length,
+            is_numeric: true,
+            has_check_digit: false,
+        }
+    };
+    ($prefix:expr, $ai_length:expr, n $length:expr) => {
+        AiFormat {
+            prefix: $prefix,
+            ai_length: $ai_length,
+            is_fixed: false,
+            max_length: $length,
+            is_numeric: true,
+            has_check_digit: false,
+        }
+    };
+    ($prefix:expr, $ai_length:expr, an $length:expr) => {
+        AiFormat {
+            prefix: $prefix,
+            ai_length: $ai_length,
+            is_fixed: false,
+            max_length: $length,
+            is_numeric: false,
+            has_check_digit: false,
+        }
+    };
+}
+
+/// the commonly used application identifiers; longer prefixes have to come first
+const AITABLE: [AiFormat; 55] = [
+    ai!("00", 2, fixed n 18, check),
+    ai!("01", 2, fixed n 14, check),
+    ai!("02", 2, fixed n 14, check),
+    ai!("10", 2, an 20),
+    ai!("11", 2, fixed n 6),
+    ai!("12", 2, fixed n 6),
+    ai!("13", 2, fixed n 6),
+    ai!("15", 2, fixed n 6),
+    ai!("16", 2, fixed n 6),
+    ai!("17", 2, fixed n 6),
+    ai!("20", 2, fixed n 2),
+    ai!("21", 2, an 20),
+    ai!("22", 2, an 20),
+    ai!("235", 3, an 28),
+    ai!("240", 3, an 30),
+    ai!("241", 3, an 30),
+    ai!("242", 3, n 6),
+    ai!("243", 3, an 20),
+    ai!("250", 3, an 30),
+    ai!("251", 3, an 30),
+    ai!("253", 3, an 30),
+    ai!("254", 3, an 20),
+    ai!("255", 3, n 25),
+    ai!("30", 2, n 8),
+    // trade measures with the position of the decimal point as last digit
+    ai!("31", 4, fixed n 6),
+    ai!("32", 4, fixed n 6),
+    ai!("33", 4, fixed n 6),
+    ai!("34", 4, fixed n 6),
+    ai!("35", 4, fixed n 6),
+    ai!("36", 4, fixed n 6),
+    ai!("37", 2, n 8),
+    ai!("390", 4, n 15),
+    ai!("392", 4, n 15),
+    ai!("400", 3, an 30),
+    ai!("401", 3, an 30),
+    ai!("402", 3, fixed n 17, check),
+    ai!("403", 3, an 30),
+    // global location numbers of the parties and places (418 and 419 aren't assigned)
+    ai!("410", 3, fixed n 13, check),
+    ai!("411", 3, fixed n 13, check),
+    ai!("412", 3, fixed n 13, check),
+    ai!("413", 3, fixed n 13, check),
+    ai!("414", 3, fixed n 13, check),
+    ai!("415", 3, fixed n 13, check),
+    ai!("416", 3, fixed n 13, check),
+    ai!("417", 3, fixed n 13, check),
+    ai!("420", 3, an 20),
+    ai!("421", 3, an 12),
+    ai!("422", 3, fixed n 3),
+    ai!("7003", 4, fixed n 10),
+    ai!("8003", 4, an 30),
+    ai!("8004", 4, an 30),
+    ai!("8005", 4, fixed n 6),
+    ai!("8020", 4, an 25),
+    ai!("90", 2, an 30),
+    ai!("9", 2, an 90),
+];
+
+/// application identifiers starting with these two digits have a predefined length
+/// and don't have to be followed by a group separator (GS1 General Specifications, figure 7.8.5-1)
+const PREDEFINEDLENGTHS: [&str; 22] = [
+    "00", "01", "02", "03", "04", "11", "12", "13", "14", "15", "16", "17", "18", "19", "20", "31",
+    "32", "33", "34", "35", "36", "41",
+];
+
+/// checks whether the character belongs to GS1 AI encodable character set 82
+/// (GS1 General Specifications, figure 7.11-1); the brackets of the element string
+/// can't be used in the data
+fn is_cset82(character: char) -> bool {
+    character.is_ascii_alphanumeric() || "!\"%&'*+,-./:;<=>?_".contains(character)
+}
+
+/// looks up the format of an application identifier
+fn ai_format(ai: &str) -> Option<&'static AiFormat> {
+    AITABLE
+        .iter()
+        .find(|format| ai.len() == format.ai_length && ai.starts_with(format.prefix))
+}
+
+/// checks the GS1 check digit (last digit) of a string of digits
+fn is_check_digit_valid(digits: &str) -> bool {
+    let values: Vec<u32> = digits
+        .chars()
+        .filter_map(|digit| digit.to_digit(10))
+        .collect();
+    let Some((check_digit, body)) = values.split_last() else {
+        return false;
+    };
+    // from the right the digits are weighted alternating with 3 and 1
+    let mut sum: u32 = 0;
+    for (index, value) in body.iter().rev().enumerate() {
+        sum += if index % 2 == 0 { value * 3 } else { *value };
+    }
+    (10 - sum % 10) % 10 == *check_digit
+}
+
+/// converts a human readable GS1 element string like "(01)09501101530003(17)250101(10)ABC"
+/// into the data of a GS1 qr code, where every element string with a variable length
+/// that isn't the last one is terminated with a group separator
+pub fn parse_element_string(text: &str) -> Result<String, QrError> {
+    let mut elements: Vec<(String, String)> = vec![];
+    let mut remaining: &str = text;
+    while !remaining.is_empty() {
+        // read the application identifier in brackets
+        let Some(after_bracket) = remaining.strip_prefix('(') else {
+            return Err(QrError::InvalidGs1(format!(
+                "expected '(' in front of an application identifier, found \"{remaining}\""
+            )));
+        };
+        let Some(ai_end) = after_bracket.find(')') else {
+            return Err(QrError::InvalidGs1(String::from(
+                "application identifier isn't closed with ')'",
+            )));
+        };
+        let ai: &str = &after_bracket[..ai_end];
+        // the data reaches up to the next application identifier
+        let after_ai: &str = &after_bracket[ai_end + 1..];
+        let data_end: usize = after_ai.find('(').unwrap_or(after_ai.len());
+        elements.push((String::from(ai), String::from(&after_ai[..data_end])));
+        remaining = &after_ai[data_end..];
+    }
+    if elements.is_empty() {
+        return Err(QrError::InvalidGs1(String::from(
+            "GS1 element string contains no application identifier",
+        )));
+    }
+    let mut result: String = String::new();
+    for (index, (ai, data)) in elements.iter().enumerate() {
+        let Some(format) = ai_format(ai) else {
+            return Err(QrError::InvalidGs1(format!(
+                "unknown application identifier ({ai})"
+            )));
+        };
+        // check the data field
+        if data.is_empty()
+            || data.len() > format.max_length
+            || (format.is_fixed && data.len() != format.max_length)
+        {
+            return Err(QrError::InvalidGs1(format!(
+                "data \"{data}\" of ({ai}) must be {}{} characters long",
+                if format.is_fixed { "" } else { "up to " },
+                format.max_length
+            )));
+        }
+        if format.is_numeric && !data.chars().all(|character| character.is_ascii_digit()) {
+            return Err(QrError::InvalidGs1(format!(
+                "data \"{data}\" of ({ai}) may only contain digits"
+            )));
+        }
+        if !data.chars().all(is_cset82) {
+            return Err(QrError::InvalidGs1(format!(
+                "data \"{data}\" of ({ai}) contains characters not allowed in GS1 element strings"
+            )));
+        }
+        if format.has_check_digit && !is_check_digit_valid(data) {
+            return Err(QrError::InvalidGs1(format!(
+                "check digit of ({ai}) {data} is invalid"
+            )));
+        }
+        result.push_str(ai);
+        result.push_str(data);
+        // element strings without a predefined length must be terminated,
+        // unless they are the last one
+        if index != elements.len() - 1 && !PREDEFINEDLENGTHS.contains(&&ai[..2]) {
+            result.push(GROUPSEPARATOR);
+        }
+    }
+    Ok(result)
+}
+
+/// converts the application indicator of FNC1 in second position into its value;
+/// two digits are written as their number, a single letter as its ascii value + 100
+pub fn application_indicator(text: &str) -> Result<u8, QrError> {
+    let characters: Vec<char> = text.chars().collect();
+    match characters[..] {
+        [first, second] if first.is_ascii_digit() && second.is_ascii_digit() => Ok(text
+            .parse::<u8>()
+            .expect("two digits are always a valid number")),
+        [letter] if letter.is_ascii_alphabetic() => Ok(letter as u8 + 100),
+        _ => Err(QrError::InvalidGs1(format!(
+            "application indicator \"{text}\" must be two digits or a single letter"
+        ))),
+    }
+}
+
+impl Segment {
+    /// creates the header of a symbol whose data consists of GS1 element strings
+    pub fn fnc1_first() -> Segment {
+        Segment::new(EncodingMode::Fnc1First, vec![])
+    }
+
+    /// creates the header of a symbol formatted according to the industry application
+    /// with the given application indicator
+    pub fn fnc1_second(application_indicator: u8) -> Segment {
+        Segment::new(EncodingMode::Fnc1Second, vec![application_indicator])
+    }
+}
+
+mod tests {
+    #[test]
+    fn element_strings() {
+        use super::parse_element_string;
+
+        assert_eq!(
+            parse_element_string("(01)09501101530003(17)250101(10)ABC"),
+            Ok(String::from("01095011015300031725010110ABC"))
+        );
+        // variable length element strings are terminated with a group separator
+        assert_eq!(
+            parse_element_string("(10)ABC123(17)250101(21)XYZ"),
+            Ok(String::from("10ABC123\u{1D}1725010121XYZ"))
+        );
+        assert_eq!(
+            parse_element_string("(3103)000189(00)095011010000000018"),
+            Ok(String::from("310300018900095011010000000018"))
+        );
+        // (410) has a predefined length, (235) doesn't
+        assert_eq!(
+            parse_element_string("(410)9501101530003(10)A-1/2"),
+            Ok(String::from("410950110153000310A-1/2"))
+        );
+        assert_eq!(
+            parse_element_string("(235)AB12(10)C"),
+            Ok(String::from("235AB12\u{1D}10C"))
+        );
+    }
+
+    #[test]
+    fn invalid_element_strings() {
+        use super::parse_element_string;
+
+        // wrong check digit
+        assert!(parse_element_string("(01)09501101530004").is_err());
+        // wrong length
+        assert!(parse_element_string("(17)2501").is_err());
+        assert!(parse_element_string("(10)123456789012345678901").is_err());
+        // letters in a numeric field
+        assert!(parse_element_string("(01)0950110153000A").is_err());
+        // characters outside of the 82 character set
+        assert!(parse_element_string("(10)AB#12").is_err());
+        assert!(parse_element_string("(21)A{B}").is_err());
+        assert!(parse_element_string("(10)AB@").is_err());
+        // unknown application identifier and missing brackets
+        assert!(parse_element_string("(05)123").is_err());
+        assert!(parse_element_string("(418)9501101530003").is_err());
+        assert!(parse_element_string("(419)9501101530003").is_err());
+        assert!(parse_element_string("0109501101530003").is_err());
+        assert!(parse_element_string("").is_err());
+    }
+
+    #[test]
+    fn application_indicators() {
+        use super::application_indicator;
+
+        assert_eq!(application_indicator("37"), Ok(37));
+        assert_eq!(application_indicator("00"), Ok(0));
+        assert_eq!(application_indicator("a"), Ok(197));
+        assert_eq!(application_indicator("Z"), Ok(190));
+        assert!(application_indicator("123").is_err());
+        assert!(application_indicator("%").is_err());
+        assert!(application_indicator("").is_err());
+    }
+}
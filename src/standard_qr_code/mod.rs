@@ -1,23 +1,78 @@
+use crate::error::QrError;
 use crate::input::Settings;
+pub mod bch;
+pub mod capacity;
+pub mod decoder;
+mod detector;
+mod eci;
+pub mod encoding_modes;
 mod generation_tests;
-mod qr_struct;
+mod gs1;
+mod module_matrix;
+mod netpbm;
+pub mod qr_struct;
+pub mod segmentation;
+mod shift_jis_table;
 mod utils;
 mod version_constants;
+pub use crate::standard_qr_code::decoder::DecodedSymbol;
+use crate::standard_qr_code::netpbm::GrayImage;
 use crate::standard_qr_code::qr_struct::QRData;
 
-pub fn qr_code(input: Settings) -> QRData {
-    // all text is assumed to be encoded in byte mode
+/// generates the qr codes for the text of the settings; texts that are too long for a
+/// single qr code are split up into multiple ones
+pub(crate) fn qr_code(input: Settings) -> Result<Vec<QRData>, QrError> {
     if input.debugging {
         println!("{input:?}");
     }
     // struct for all data
-    let mut qrdata: QRData = QRData::new(input);
-    // fill in everything required
+    let mut symbols: Vec<QRData> = QRData::new_sequence(input)?;
+    for qrdata in symbols.iter_mut() {
+        // fill in everything required
+        generate_symbol(qrdata)?;
+        if qrdata.get_settings().debugging {
+            println!("printing the qrcodes raw data:");
+            qrdata.print_data();
+            // read the finished qr code back to verify it
+            match decoder::decode(&qrdata.get_data(), 4) {
+                Ok(decoded) => println!(
+                    "decoded qr code: {decoded:?}\ndecoded text: {:?}",
+                    decoded.text()
+                ),
+                Err(error) => println!("decoding the qr code failed: {error}"),
+            }
+        }
+    }
+    Ok(symbols)
+}
+
+/// reads the qr code in the PBM or PGM image at the given path
+pub fn read_image_file(path: &str) -> Result<DecodedSymbol, QrError> {
+    let image: GrayImage = netpbm::read_netpbm(path)?;
+    detector::read_image(&image)
+}
+
+/// draws all patterns and the data into the qr code
+fn generate_symbol(qrdata: &mut QRData) -> Result<(), QrError> {
+    // draw everything that isn't data
+    draw_function_patterns(qrdata);
+
+    // after all preparations are done process and write the data
+    qrdata.read_and_write()?;
 
-    // draw white elements around the qrcode to
-    // separate it visualy from the background
-    qrdata.quiet_zone();
+    // write the version information
+
+    qrdata.version_information();
+
+    // apply a mask onto the code, so that the code has
+    // no major white and/or black spots
+    qrdata.masking_format_information();
+    Ok(())
+}
 
+/// draws the patterns and reserves the elements for the format and version information,
+/// everything left uninitialised afterwards is filled with data
+fn draw_function_patterns(qrdata: &mut QRData) {
     // draw the finding patterns to determine rotation of the qrcode
     qrdata.finders();
 
@@ -40,23 +95,4 @@ pub fn qr_code(input: Settings) -> QRData {
     // mark elements for version information,
     // so they won't get overwritten
     qrdata.reserve_version_information();
-
-    // after all preparations are done process and write the data
-    qrdata.read_and_write();
-
-    // write the version information
-
-    qrdata.version_information();
-
-    // apply a mask onto the code, so that the code has
-    // no major white and/or black spots
-    qrdata.masking_format_information();
-    // actually display the qrcode, if not in debugging mode
-    if !qrdata.get_settings().debugging {
-        println!("{qrdata}");
-    } else {
-        println!("printing the qrcodes raw data:");
-        qrdata.print_data();
-    }
-    qrdata
 }
@@ -0,0 +1,165 @@
+use crate::standard_qr_code::qr_struct::mask_condition;
+
+/// number of elements stored in a word of a bit row
+const WORDBITS: usize = 64;
+
+/// the elements of a symbol (without quiet zone) stored as rows of bits: one bitplane holds
+/// the colors (1 is black), another one which elements belong to function patterns
+/// (everything that isn't data, including the format and version information)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModuleMatrix {
+    width: usize,
+    height: usize,
+    /// number of words every row takes up
+    words_per_row: usize,
+    dark: Vec<u64>,
+    function: Vec<u64>,
+}
+
+impl ModuleMatrix {
+    /// creates a matrix of white data elements
+    pub fn new(width: usize, height: usize) -> ModuleMatrix {
+        let words_per_row: usize = width.div_ceil(WORDBITS);
+        ModuleMatrix {
+            width,
+            height,
+            words_per_row,
+            dark: vec![0; words_per_row * height],
+            function: vec![0; words_per_row * height],
+        }
+    }
+
+    /// returns the number of elements per row
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// returns the index of the word and the bit of the element
+    fn position(&self, x: usize, y: usize) -> (usize, u64) {
+        assert!(
+            x < self.width && y < self.height,
+            "element x: {x}, y: {y} is outside of the matrix"
+        );
+        (y * self.words_per_row + x / WORDBITS, 1 << (x % WORDBITS))
+    }
+
+    /// returns whether the element in column x and row y is black
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        let (word, bit) = self.position(x, y);
+        self.dark[word] & bit != 0
+    }
+
+    /// returns whether the element belongs to a function pattern
+    pub fn is_function(&self, x: usize, y: usize) -> bool {
+        let (word, bit) = self.position(x, y);
+        self.function[word] & bit != 0
+    }
+
+    /// sets the color of a data element
+    pub fn set_dark(&mut self, x: usize, y: usize, is_dark: bool) {
+        let (word, bit) = self.position(x, y);
+        if is_dark {
+            self.dark[word] |= bit;
+        } else {
+            self.dark[word] &= !bit;
+        }
+    }
+
+    /// marks the element as part of a function pattern with the given color
+    pub fn set_function(&mut self, x: usize, y: usize, is_dark: bool) {
+        let (word, bit) = self.position(x, y);
+        self.function[word] |= bit;
+        self.set_dark(x, y, is_dark);
+    }
+
+    /// returns the colors of the row from left to right
+    pub fn row(&self, y: usize) -> impl Iterator<Item = bool> + '_ {
+        (0..self.width).map(move |x| self.is_dark(x, y))
+    }
+
+    /// returns the colors of the column from top to bottom
+    pub fn column(&self, x: usize) -> impl Iterator<Item = bool> + '_ {
+        (0..self.height).map(move |y| self.is_dark(x, y))
+    }
+
+    /// returns the number of black elements
+    pub fn count_dark(&self) -> usize {
+        self.dark
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// returns the bitplane of the data elements the standard qr code mask inverts
+    pub fn mask_plane(&self, mask_number: u8) -> Vec<u64> {
+        let mut plane: Vec<u64> = vec![0; self.dark.len()];
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if mask_condition(mask_number, y, x) {
+                    let (word, bit) = self.position(x, y);
+                    plane[word] |= bit;
+                }
+            }
+        }
+        // function patterns are never masked
+        for (word, function) in plane.iter_mut().zip(self.function.iter()) {
+            *word &= !function;
+        }
+        plane
+    }
+
+    /// inverts the elements set in the bitplane; applying it twice restores the colors
+    pub fn xor(&mut self, plane: &[u64]) {
+        assert_eq!(plane.len(), self.dark.len());
+        for (word, mask) in self.dark.iter_mut().zip(plane.iter()) {
+            *word ^= mask;
+        }
+    }
+}
+
+mod tests {
+    #[test]
+    fn matrix_accessors() {
+        use super::ModuleMatrix;
+
+        // rows that need more than one word
+        let mut matrix: ModuleMatrix = ModuleMatrix::new(70, 3);
+        matrix.set_dark(0, 0, true);
+        matrix.set_dark(64, 1, true);
+        matrix.set_function(69, 2, true);
+        matrix.set_function(68, 2, false);
+        assert!(matrix.is_dark(64, 1) && !matrix.is_dark(63, 1) && !matrix.is_dark(64, 0));
+        assert!(matrix.is_function(69, 2) && matrix.is_function(68, 2));
+        assert!(!matrix.is_function(64, 1));
+        assert_eq!(matrix.count_dark(), 3);
+        assert_eq!(matrix.row(1).filter(|is_dark| *is_dark).count(), 1);
+        assert_eq!(
+            matrix.column(69).collect::<Vec<bool>>(),
+            vec![false, false, true]
+        );
+        matrix.set_dark(0, 0, false);
+        assert_eq!(matrix.count_dark(), 2);
+    }
+
+    #[test]
+    fn matrix_mask_plane() {
+        use super::ModuleMatrix;
+        use crate::standard_qr_code::qr_struct::mask_condition;
+
+        let mut matrix: ModuleMatrix = ModuleMatrix::new(21, 21);
+        matrix.set_function(0, 0, true);
+        for mask_number in 0..8 {
+            let plane: Vec<u64> = matrix.mask_plane(mask_number);
+            let original: ModuleMatrix = matrix.clone();
+            matrix.xor(&plane);
+            for x in 0..21 {
+                for y in 0..21 {
+                    let is_masked: bool = (x, y) != (0, 0) && mask_condition(mask_number, y, x);
+                    assert_eq!(matrix.is_dark(x, y) != original.is_dark(x, y), is_masked);
+                }
+            }
+            matrix.xor(&plane);
+            assert_eq!(matrix, original);
+        }
+    }
+}
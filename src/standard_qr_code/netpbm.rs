@@ -0,0 +1,267 @@
+// based on the netpbm format descriptions
+// https://netpbm.sourceforge.net/doc/pbm.html and https://netpbm.sourceforge.net/doc/pgm.html
+
+use crate::error::QrError;
+
+/// grayscale image, where 0 is black and 255 is white
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrayImage {
+    pub width: usize,
+    pub height: usize,
+    /// the pixels row by row
+    pub pixels: Vec<u8>,
+}
+
+impl GrayImage {
+    /// returns the brightness of the pixel in column x and row y
+    pub fn get(&self, x: usize, y: usize) -> u8 {
+        self.pixels[y * self.width + x]
+    }
+}
+
+/// reads the header and the ascii raster of a netpbm file
+struct NetpbmReader<'a> {
+    bytes: &'a [u8],
+    /// index of the next byte
+    position: usize,
+}
+
+impl NetpbmReader<'_> {
+    /// skips whitespace and comments, which reach from '#' to the end of the line
+    fn skip_whitespace(&mut self) {
+        while let Some(byte) = self.bytes.get(self.position) {
+            if *byte == b'#' {
+                while self
+                    .bytes
+                    .get(self.position)
+                    .is_some_and(|byte| *byte != b'\n')
+                {
+                    self.position += 1;
+                }
+            } else if byte.is_ascii_whitespace() {
+                self.position += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// reads the next decimal number
+    fn read_number(&mut self) -> Result<usize, QrError> {
+        self.skip_whitespace();
+        let start: usize = self.position;
+        while self
+            .bytes
+            .get(self.position)
+            .is_some_and(u8::is_ascii_digit)
+        {
+            self.position += 1;
+        }
+        if start == self.position {
+            return Err(QrError::InvalidImage(String::from(
+                "expected a number in the netpbm file",
+            )));
+        }
+        // only digits were read, so the text is valid
+        std::str::from_utf8(&self.bytes[start..self.position])
+            .expect("digits are valid utf-8")
+            .parse::<usize>()
+            .map_err(|_| {
+                QrError::InvalidImage(String::from("number in the netpbm file is too big"))
+            })
+    }
+
+    /// reads a single digit of a plain pbm file (there doesn't have to be whitespace between them)
+    fn read_bit(&mut self) -> Result<bool, QrError> {
+        self.skip_whitespace();
+        match self.bytes.get(self.position) {
+            Some(b'0') => {
+                self.position += 1;
+                Ok(false)
+            }
+            Some(b'1') => {
+                self.position += 1;
+                Ok(true)
+            }
+            _ => Err(QrError::InvalidImage(String::from(
+                "expected 0 or 1 in the pbm file",
+            ))),
+        }
+    }
+
+    /// returns the raster of a binary file, which follows a single whitespace after the header
+    fn raster(&self, length: usize) -> Result<&[u8], QrError> {
+        self.bytes
+            .get(self.position + 1..self.position + 1 + length)
+            .ok_or(QrError::InvalidImage(String::from(
+                "netpbm file ends in the middle of the image",
+            )))
+    }
+}
+
+/// converts the contents of a PBM (P1, P4) or PGM (P2, P5) file into a grayscale image
+pub fn parse_netpbm(bytes: &[u8]) -> Result<GrayImage, QrError> {
+    let mut reader: NetpbmReader = NetpbmReader { bytes, position: 2 };
+    let magic_number: &[u8] = bytes.get(..2).unwrap_or_default();
+    if !matches!(magic_number, b"P1" | b"P2" | b"P4" | b"P5") {
+        return Err(QrError::InvalidImage(String::from(
+            "file isn't a PBM or PGM image",
+        )));
+    }
+    let width: usize = reader.read_number()?;
+    let height: usize = reader.read_number()?;
+    if width == 0 || height == 0 {
+        return Err(QrError::InvalidImage(String::from("image is empty")));
+    }
+    let num_pixels: usize = width
+        .checked_mul(height)
+        .ok_or(QrError::InvalidImage(String::from("image is too big")))?;
+    let mut pixels: Vec<u8> = Vec::with_capacity(num_pixels);
+    match magic_number {
+        // plain pbm, 1 is black
+        b"P1" => {
+            for _ in 0..num_pixels {
+                pixels.push(if reader.read_bit()? { 0 } else { 255 });
+            }
+        }
+        // raw pbm, every row starts with a new byte
+        b"P4" => {
+            let row_bytes: usize = width.div_ceil(8);
+            let raster: &[u8] = reader.raster(row_bytes * height)?;
+            for row in raster.chunks(row_bytes) {
+                for x in 0..width {
+                    let is_black: bool = (row[x / 8] << (x % 8)) & 0b1000_0000 != 0;
+                    pixels.push(if is_black { 0 } else { 255 });
+                }
+            }
+        }
+        b"P2" | b"P5" => {
+            let max_value: usize = reader.read_number()?;
+            if max_value == 0 || max_value > u16::MAX as usize {
+                return Err(QrError::InvalidImage(format!(
+                    "maximum gray value {max_value} isn't supported"
+                )));
+            }
+            let values: Vec<usize> = if magic_number == b"P2" {
+                let mut values: Vec<usize> = Vec::with_capacity(num_pixels);
+                for _ in 0..num_pixels {
+                    values.push(reader.read_number()?);
+                }
+                values
+            } else if max_value < 256 {
+                let raster: &[u8] = reader.raster(num_pixels)?;
+                raster.iter().map(|value| *value as usize).collect()
+            } else {
+                // two bytes per pixel, most significant byte first
+                let raster: &[u8] = reader.raster(2 * num_pixels)?;
+                raster
+                    .chunks(2)
+                    .map(|value| u16::from_be_bytes([value[0], value[1]]) as usize)
+                    .collect()
+            };
+            for value in values {
+                if value > max_value {
+                    return Err(QrError::InvalidImage(format!(
+                        "gray value {value} is above the maximum value {max_value}"
+                    )));
+                }
+                pixels.push((value * 255 / max_value) as u8);
+            }
+        }
+        _ => unreachable!("the magic number was checked above"),
+    }
+    Ok(GrayImage {
+        width,
+        height,
+        pixels,
+    })
+}
+
+/// loads a PBM or PGM file
+pub fn read_netpbm(path: &str) -> Result<GrayImage, QrError> {
+    match std::fs::read(path) {
+        Ok(bytes) => parse_netpbm(&bytes),
+        Err(error) => Err(QrError::Io(format!("couldn't read \"{path}\": {error}"))),
+    }
+}
+
+mod tests {
+    #[test]
+    fn plain_formats() {
+        use super::{parse_netpbm, GrayImage};
+
+        let expected = GrayImage {
+            width: 3,
+            height: 2,
+            pixels: vec![0, 255, 0, 255, 255, 0],
+        };
+        assert_eq!(
+            parse_netpbm(b"P1\n# comment\n3 2\n1 0 1\n0 0 1\n"),
+            Ok(expected.clone())
+        );
+        // the digits of a plain pbm don't have to be separated
+        assert_eq!(parse_netpbm(b"P1 3 2 101001"), Ok(expected));
+        assert_eq!(
+            parse_netpbm(b"P2\n2 2\n# maximum value\n15\n0 5\n10 15\n"),
+            Ok(GrayImage {
+                width: 2,
+                height: 2,
+                pixels: vec![0, 85, 170, 255],
+            })
+        );
+    }
+
+    #[test]
+    fn raw_formats() {
+        use super::{parse_netpbm, GrayImage};
+
+        // every row of 10 pixels takes two bytes
+        let mut file: Vec<u8> = b"P4\n10 2\n".to_vec();
+        file.extend([0b1100_0000, 0b0100_0000, 0b0000_0000, 0b1000_0000]);
+        let mut pixels: Vec<u8> = vec![255; 20];
+        for index in [0, 1, 9, 18] {
+            pixels[index] = 0;
+        }
+        assert_eq!(
+            parse_netpbm(&file),
+            Ok(GrayImage {
+                width: 10,
+                height: 2,
+                pixels,
+            })
+        );
+        let mut file: Vec<u8> = b"P5 2 1 255\n".to_vec();
+        file.extend([0x20, 0xff]);
+        assert_eq!(
+            parse_netpbm(&file),
+            Ok(GrayImage {
+                width: 2,
+                height: 1,
+                pixels: vec![0x20, 0xff],
+            })
+        );
+        // 16 bit gray values
+        let mut file: Vec<u8> = b"P5 2 1 65535\n".to_vec();
+        file.extend([0x00, 0x00, 0x80, 0x00]);
+        assert_eq!(
+            parse_netpbm(&file),
+            Ok(GrayImage {
+                width: 2,
+                height: 1,
+                pixels: vec![0, 127],
+            })
+        );
+    }
+
+    #[test]
+    fn invalid_files() {
+        use super::parse_netpbm;
+
+        assert!(parse_netpbm(b"P3 1 1 255 0 0 0").is_err());
+        assert!(parse_netpbm(b"P1 2 2 1 0 1").is_err());
+        assert!(parse_netpbm(b"P2 1 1 10 11").is_err());
+        assert!(parse_netpbm(b"P5 2 2 255\n\x00\x00").is_err());
+        assert!(parse_netpbm(b"P1 0 5").is_err());
+        assert!(parse_netpbm(b"").is_err());
+    }
+}
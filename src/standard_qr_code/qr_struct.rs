@@ -1,17 +1,21 @@
+use crate::error::QrError;
 use crate::galois_field::GaloisFields;
-use crate::input::ErrorLevel;
+use crate::input::{EciCharset, ErrorLevel, Settings};
 use crate::polynomials::{Indeterminate, Polynomial};
-use crate::standard_qr_code::version_constants::{alignment_pattern_data, version_info};
-use crate::{standard_qr_code::utils::get_verison_info, Settings};
+use crate::standard_qr_code::utils::{get_verison_info, split_text};
+use crate::standard_qr_code::version_constants::{alignment_pattern_data, get_error_block_info};
 use std::vec;
 use std::{
     cmp::Ordering,
     fmt::{Display, Formatter, Result},
-    ops::BitXor,
+    ops::{BitXor, RangeInclusive},
     vec::Vec,
 };
 
-use super::version_constants::information_sequences;
+use super::bch::{encode_format_information, encode_version_information};
+use super::gs1::{application_indicator, parse_element_string};
+use super::module_matrix::ModuleMatrix;
+use super::segmentation::{Segment, TextEncoding, MAXSYMBOLS};
 
 // constants for ANSI colors
 // https://en.wikipedia.org/wiki/ANSI_escape_code
@@ -19,23 +23,10 @@ use super::version_constants::information_sequences;
 // 30  40 black
 // 37  47 white
 // 97 107 bright white
-const COLORSTOP: &str = "\x1b[m";
-const BRIGHTMAGENTA: &str = "\x1b[30;105m";
-const BRIGHTWHITE: &str = "\x1b[30;107m";
-const WHITE: &str = "\x1b[30;47m";
-const BRIGHTBLACK: &str = "\x1b[30;100m";
-const BLACK: &str = "\x1b[37;40m";
-const RED: &str = "\x1b[37;41m";
-const MAGENTA: &str = "\x1b[30;45m";
-const BRIGHTRED: &str = "\x1b[37;101m";
-const BRIGHTYELLOW: &str = "\x1b[30;103m";
+pub const COLORSTOP: &str = "\x1b[m";
+pub const BRIGHTWHITE: &str = "\x1b[30;107m";
+pub const BLACK: &str = "\x1b[37;40m";
 const BRIGHTBLUE: &str = "\x1b[30;104m";
-const BLUE: &str = "\x1b[37;44m";
-const BRIGHTCYAN: &str = "\x1b[30;106m";
-
-/// constant for byte mode indicator
-const BYTEMODEINDICATOR: u8 = 0b0100;
-const CHARACTERBITS: u8 = 8;
 
 /// supports writing u8 values bitwise in a vector
 #[derive(Clone, Debug)]
@@ -57,7 +48,9 @@ impl MyBitVector {
     }
 
     pub fn pad_empty_rest(&mut self) {
-        let _begin_pad_index = (self.curr_position as f32 / 8.0).ceil() as usize;
+        // leave room for the 4 bit terminator (or what is left of the capacity)
+        let terminator_end: u32 = (self.curr_position + 4).min(self.capacity as u32 * 8);
+        let _begin_pad_index = (terminator_end as f32 / 8.0).ceil() as usize;
         let padding_value_1 = 0xec;
         let padding_value_2 = 0x11;
         let mut is_value_1 = true;
@@ -116,18 +109,14 @@ impl MyBitVector {
         }
     }
 
-    /// writes size bits of value into MyBitVector
-    fn push(&mut self, value: u8, size: u8) {
+    /// writes size bits of value into MyBitVector; fails if the bits don't fit
+    fn push(&mut self, value: u8, size: u8) -> std::result::Result<(), QrError> {
         // check whether the data fits into the vector
         if self.capacity as u32 * 8 < (self.curr_position + size as u32) {
-            eprintln!(
-                "MyBitVector has capacity of {} bits is at index {} and as a \
-                result {} additional bits can't be written",
-                self.capacity * 8,
-                self.curr_position,
-                size
-            );
-            panic!();
+            return Err(QrError::DataTooLong {
+                needed_bits: (self.curr_position + size as u32) as usize,
+                available_bits: self.capacity as usize * 8,
+            });
         }
         // which bit should be read from
         let mut current_bit_read: u8 = size - 1;
@@ -171,6 +160,21 @@ impl MyBitVector {
             // update the byte index
             self.curr_position += 1;
         }
+        Ok(())
+    }
+
+    /// writes size bits of value into MyBitVector, where size may be up to 16 bits
+    fn push_bits(&mut self, value: u16, size: u8) -> std::result::Result<(), QrError> {
+        assert!(size <= 16);
+        if size == 0 {
+            return Ok(());
+        }
+        if size > 8 {
+            self.push((value >> 8) as u8, size - 8)?;
+            self.push((value & 0b1111_1111) as u8, 8)
+        } else {
+            self.push(value as u8, size)
+        }
     }
 
     fn get_data(&self) -> Vec<u8> {
@@ -209,35 +213,6 @@ impl BitXor for SymbolStatus {
     }
 }
 
-/// represents the role of symbol inside the qr code
-#[derive(Debug, PartialEq, Clone)]
-pub enum SymbolRole {
-    /// role not get determined
-    Uninitialised,
-    /// quiet zone around code
-    QuietZone,
-    /// Finder Pattern; the recognisable squares in the corners
-    FinderPattern,
-    /// separator of Finder Patterns and actual data
-    Separator,
-    /// Timing pattern; defines spacing of symbols
-    TimingPattern,
-    /// Alignment Pattern; 3x3 squares for qrcodes of version 2 or larger
-    AlignmentPattern,
-    /// Encoding region; acual data
-    EncodingRegion,
-    /// reserving Version Information for writing later
-    ReservedVersionInformation,
-    /// Version information; version 7 or larger
-    VersionInformation,
-    /// reserving Format information for writing later
-    ReservedFormatInformation,
-    /// Format Information; error correction level and mask pattern
-    FormatInformation,
-    /// Element that is always LogicalTrue/black
-    AlwaysTrue,
-}
-
 /// contains error correction block information
 #[derive(Clone, Copy, Debug)]
 pub struct ErrorBlockInfo {
@@ -262,209 +237,299 @@ impl ErrorBlockInfo {
 /// encomposes all data required to generate a qr code
 #[derive(Debug, Clone)]
 pub struct QRData {
-    output_data: Vec<Vec<SymbolStatus>>,
-    role_data: Vec<Vec<SymbolRole>>,
+    /// the elements without quiet zone
+    matrix: ModuleMatrix,
     version: u8,
     error_blocks: Vec<ErrorBlockInfo>,
+    segments: Vec<Segment>,
     settings: Settings,
+    /// the mask that was applied (0 until the mask is chosen)
+    mask: u8,
+    /// the penalties of all masks the mask was chosen by
+    mask_penalties: Vec<MaskPenalty>,
+}
+
+/// the penalty points of a masked symbol for the four rules of ISO/IEC 18004;
+/// the mask with the lowest total is applied
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaskPenalty {
+    /// the mask that was rated
+    pub mask: u8,
+    /// rows and columns of 5 or more elements of the same color
+    pub n1: u32,
+    /// 2x2 blocks of the same color
+    pub n2: u32,
+    /// 1:1:3:1:1 patterns with 4 white elements in front of or behind them
+    pub n3: u32,
+    /// deviation of the share of black elements from 50 %
+    pub n4: u32,
+}
+
+impl MaskPenalty {
+    /// returns the sum of all four penalties
+    pub fn total(&self) -> u32 {
+        self.n1 + self.n2 + self.n3 + self.n4
+    }
 }
 
-macro_rules! bit_to_qrcode {
-    ($self: expr, $x_1: expr, $y_1: expr, $x_2: expr, $y_2: expr, $bit: expr) => {
-        if $bit {
-            $self.output_data[$x_1][$y_1] = SymbolStatus::LogicalTrue;
+/// returns the penalty for the runs of elements of the same color in a row or column:
+/// 3 points for 5 elements and 1 point for every further element
+fn penalty_runs(line: &[bool]) -> u32 {
+    let mut penalty: u32 = 0;
+    let mut former_status: Option<bool> = None;
+    let mut consecutive_counter = 0;
+    for is_dark in line.iter().copied() {
+        if Some(is_dark) == former_status {
+            consecutive_counter += 1;
         } else {
-            $self.output_data[$x_1][$y_1] = SymbolStatus::LogicalFalse;
+            consecutive_counter = 1;
+            former_status = Some(is_dark);
         }
-        $self.role_data[$x_1][$y_1] = SymbolRole::FormatInformation;
-        if $bit {
-            $self.output_data[$x_2][$y_2] = SymbolStatus::LogicalTrue;
-        } else {
-            $self.output_data[$x_2][$y_2] = SymbolStatus::LogicalFalse;
+        match consecutive_counter.cmp(&5) {
+            Ordering::Equal => penalty += 3,
+            Ordering::Greater => penalty += 1,
+            Ordering::Less => (),
         }
-        $self.role_data[$x_2][$y_2] = SymbolRole::FormatInformation;
-    };
+    }
+    penalty
 }
 
-macro_rules! write_format_info {
-    ($self:expr, $smallest_index: expr, $biggest_index: expr, $final_data_bits: expr) => {
-        // 14
-        bit_to_qrcode!(
-            $self,
-            $smallest_index,
-            $smallest_index + 8,
-            $smallest_index + 8,
-            $biggest_index,
-            ($final_data_bits & 0b0100_0000_0000_0000) > 0
-        );
-        // 13
-        bit_to_qrcode!(
-            $self,
-            $smallest_index + 1,
-            $smallest_index + 8,
-            $smallest_index + 8,
-            $biggest_index - 1,
-            ($final_data_bits & 0b0010_0000_0000_0000) > 0
-        );
-        // 12
-        bit_to_qrcode!(
-            $self,
-            $smallest_index + 2,
-            $smallest_index + 8,
-            $smallest_index + 8,
-            $biggest_index - 2,
-            ($final_data_bits & 0b0001_0000_0000_0000) > 0
-        );
-        // 11
-        bit_to_qrcode!(
-            $self,
-            $smallest_index + 3,
-            $smallest_index + 8,
-            $smallest_index + 8,
-            $biggest_index - 3,
-            ($final_data_bits & 0b0000_1000_0000_0000) > 0
-        );
-        // 10
-        bit_to_qrcode!(
-            $self,
-            $smallest_index + 4,
-            $smallest_index + 8,
-            $smallest_index + 8,
-            $biggest_index - 4,
-            ($final_data_bits & 0b0000_0100_0000_0000) > 0
-        );
-        // 9
-        bit_to_qrcode!(
-            $self,
-            $smallest_index + 5,
-            $smallest_index + 8,
-            $smallest_index + 8,
-            $biggest_index - 5,
-            ($final_data_bits & 0b0000_0010_0000_0000) > 0
-        );
-        // 8
-        bit_to_qrcode!(
-            $self,
-            $smallest_index + 7,
-            $smallest_index + 8,
-            $smallest_index + 8,
-            $biggest_index - 6,
-            ($final_data_bits & 0b0000_0001_0000_0000) > 0
-        );
-        // since the other fields are changed from ReservedFormatInformation to
-        // FormatInformation the element that is always black, should be adjusted aswell
-        $self.role_data[$smallest_index + 8][$biggest_index - 7] = SymbolRole::AlwaysTrue;
-        // 7
-        bit_to_qrcode!(
-            $self,
-            $smallest_index + 8,
-            $smallest_index + 8,
-            $biggest_index - 7,
-            $smallest_index + 8,
-            ($final_data_bits & 0b0000_0000_1000_0000) > 0
-        );
-        // 6
-        bit_to_qrcode!(
-            $self,
-            $smallest_index + 8,
-            $smallest_index + 7,
-            $biggest_index - 6,
-            $smallest_index + 8,
-            ($final_data_bits & 0b0000_0000_0100_0000) > 0
-        );
-        // 5
-        bit_to_qrcode!(
-            $self,
-            $smallest_index + 8,
-            $smallest_index + 5,
-            $biggest_index - 5,
-            $smallest_index + 8,
-            ($final_data_bits & 0b0000_0000_0010_0000) > 0
-        );
-        // 4
-        bit_to_qrcode!(
-            $self,
-            $smallest_index + 8,
-            $smallest_index + 4,
-            $biggest_index - 4,
-            $smallest_index + 8,
-            ($final_data_bits & 0b0000_0000_0001_0000) > 0
-        );
-        // 3
-        bit_to_qrcode!(
-            $self,
-            $smallest_index + 8,
-            $smallest_index + 3,
-            $biggest_index - 3,
-            $smallest_index + 8,
-            ($final_data_bits & 0b0000_0000_0000_1000) > 0
-        );
-        // 2
-        bit_to_qrcode!(
-            $self,
-            $smallest_index + 8,
-            $smallest_index + 2,
-            $biggest_index - 2,
-            $smallest_index + 8,
-            ($final_data_bits & 0b0000_0000_0000_0100) > 0
-        );
-        // 1
-        bit_to_qrcode!(
-            $self,
-            $smallest_index + 8,
-            $smallest_index + 1,
-            $biggest_index - 1,
-            $smallest_index + 8,
-            ($final_data_bits & 0b0000_0000_0000_0010) > 0
-        );
-        // 0
-        bit_to_qrcode!(
-            $self,
-            $smallest_index + 8,
-            $smallest_index,
-            $biggest_index,
-            $smallest_index + 8,
-            ($final_data_bits & 0b0000_0000_0000_0001) > 0
-        );
+/// returns the penalty for the patterns in a row or column that look like the centre
+/// of a finder pattern (1:1:3:1:1) with 4 white elements in front of or behind them;
+/// the quiet zone counts as white
+fn penalty_finder_like(line: &[bool]) -> u32 {
+    let core: [bool; 7] = [true, false, true, true, true, false, true];
+    let quiet_zone: [bool; 4] = [false; 4];
+    let padded: Vec<bool> = [&quiet_zone, line, &quiet_zone].concat();
+    let mut penalty: u32 = 0;
+    for window in padded.windows(15) {
+        if window[4..11] == core && (window[..4] == quiet_zone || window[11..] == quiet_zone) {
+            penalty += 40;
+        }
+    }
+    penalty
+}
+
+/// returns the ECI and FNC1 segments selected in the settings and the encoding the text
+/// is written in; without an ECI the text is written as UTF-8 and it is up to the scanner
+/// to detect it
+pub fn header_segments(
+    input: &Settings,
+) -> std::result::Result<(Vec<Segment>, TextEncoding), QrError> {
+    let mut segments: Vec<Segment> = vec![];
+    let charset: EciCharset = match input.eci {
+        Some(eci_charset) => {
+            segments.push(Segment::eci(eci_charset.assignment_number()));
+            eci_charset
+        }
+        None => EciCharset::Utf8,
     };
+    // FNC1 follows any ECI or structured append header
+    if input.gs1 {
+        segments.push(Segment::fnc1_first());
+    } else if let Some(indicator) = &input.fnc1_second {
+        segments.push(Segment::fnc1_second(application_indicator(indicator)?));
+    }
+    let fnc1: bool = input.gs1 || input.fnc1_second.is_some();
+    Ok((segments, TextEncoding::new(charset, fnc1)))
+}
+
+/// returns whether the element in the given row and column (without quiet zone)
+/// is inverted by the mask
+pub fn mask_condition(mask_number: u8, row: usize, column: usize) -> bool {
+    match mask_number {
+        0 => (row + column).is_multiple_of(2),
+        1 => row.is_multiple_of(2),
+        2 => column.is_multiple_of(3),
+        3 => (row + column).is_multiple_of(3),
+        4 => ((row as f32 / 2.0) as usize + (column as f32 / 3.0) as usize).is_multiple_of(2),
+        5 => (row * column) % 2 + (row * column) % 3 == 0,
+        6 => ((row * column) % 2 + (row * column) % 3).is_multiple_of(2),
+        7 => ((row + column) % 2 + (row * column) % 3).is_multiple_of(2),
+        _ => panic!("mask {mask_number} doesn't exist"),
+    }
+}
+
+/// writes the mode indicator, the character count indicator
+/// and the data of a segment into the bit stream
+fn write_segment(
+    bit_stream: &mut MyBitVector,
+    segment: &Segment,
+    version: u8,
+) -> std::result::Result<(), QrError> {
+    // add mode indicator
+    bit_stream.push(segment.mode.indicator(), 4)?;
+    // add character count indicator
+    bit_stream.push_bits(
+        segment.character_count() as u16,
+        segment.mode.character_count_bits(version),
+    )?;
+    for (value, size) in segment.value_groups() {
+        bit_stream.push_bits(value, size)?;
+    }
+    Ok(())
 }
 
 impl QRData {
-    /// generate the data
-    pub fn new(input: Settings) -> QRData {
-        let (version, error_blocks) =
-            match get_verison_info(input.information.len(), input.error_level) {
-                Ok(result) => result,
-                Err(msg) => {
-                    eprintln!("{msg}");
-                    panic!()
+    /// generates the data for as many symbols as are required to hold the text (up to the
+    /// maximum number of symbols in the settings); if the text doesn't fit into a single symbol
+    /// it is split up and every symbol starts with a structured append header
+    pub(crate) fn new_sequence(mut input: Settings) -> std::result::Result<Vec<QRData>, QrError> {
+        let versions: RangeInclusive<u8> = input.symbol.versions();
+        for version in [versions.start(), versions.end()] {
+            if !(1..=40).contains(version) {
+                return Err(QrError::InvalidVersion(*version));
+            }
+        }
+        if versions.is_empty() {
+            return Err(QrError::InvalidVersionRange {
+                min_version: *versions.start(),
+                max_version: *versions.end(),
+            });
+        }
+        if let Some(mask) = input.symbol.mask.filter(|mask| *mask > 7) {
+            return Err(QrError::InvalidMask(mask));
+        }
+        let max_symbols: u8 = input.symbol.max_symbols.clamp(1, MAXSYMBOLS);
+        // GS1 element strings are converted once for all symbols
+        if input.gs1 {
+            input.information = parse_element_string(&input.information)?;
+        }
+        // only a text that is too long is split up
+        match QRData::new(input.clone(), vec![]) {
+            Ok(qrdata) => return Ok(vec![qrdata]),
+            Err(QrError::DataTooLong { .. }) if max_symbols > 1 => (),
+            Err(error) => return Err(error),
+        }
+        // the header of the structured append is the same length for every symbol,
+        // so a placeholder can be used to find out how the text must be split
+        let (mut split_prefix, encoding) = header_segments(&input)?;
+        split_prefix.insert(0, Segment::structured_append(0, MAXSYMBOLS, 0));
+        let parts: Vec<String> = split_text(
+            &split_prefix,
+            &input.information,
+            encoding,
+            input.error_level,
+            versions,
+            max_symbols,
+        )?;
+        let total_symbols: u8 = parts.len() as u8;
+        let mut symbols: Vec<QRData> = vec![];
+        for (index, part) in parts.into_iter().enumerate() {
+            let mut part_settings: Settings = input.clone();
+            part_settings.information = part;
+            let header: Segment = Segment::structured_append(index as u8, total_symbols, 0);
+            symbols.push(QRData::new(part_settings, vec![header])?);
+        }
+        // the parity is calculated over the data of all symbols,
+        // so it can only be written once all symbols are split up
+        let mut parity: u8 = 0;
+        for symbol in symbols.iter() {
+            for segment in symbol.segments.iter() {
+                if segment.mode.is_data() {
+                    for byte in segment.data.iter() {
+                        parity ^= byte;
+                    }
                 }
+            }
+        }
+        for symbol in symbols.iter_mut() {
+            symbol.segments[0] =
+                Segment::structured_append(symbol.segments[0].data[0] >> 4, total_symbols, parity);
+        }
+        Ok(symbols)
+    }
+
+    /// generate the data, where the prefix segments are written in front of the text
+    /// (GS1 element strings must already be converted with parse_element_string)
+    pub(crate) fn new(
+        mut input: Settings,
+        prefix_segments: Vec<Segment>,
+    ) -> std::result::Result<QRData, QrError> {
+        // segments that are written in front of the text
+        let (header, encoding) = header_segments(&input)?;
+        let mut all_prefix_segments: Vec<Segment> = prefix_segments;
+        all_prefix_segments.extend(header);
+        let (version, mut error_blocks, segments) = get_verison_info(
+            &all_prefix_segments,
+            &input.information,
+            encoding,
+            input.error_level,
+            input.symbol.versions(),
+        )?;
+        if input.symbol.boost_ecl {
+            // the higher levels are tried in the version that was chosen for the requested level
+            let higher_levels: &[ErrorLevel] = match input.error_level {
+                ErrorLevel::L => &[ErrorLevel::M, ErrorLevel::Q, ErrorLevel::H],
+                ErrorLevel::M => &[ErrorLevel::Q, ErrorLevel::H],
+                ErrorLevel::Q => &[ErrorLevel::H],
+                ErrorLevel::H => &[],
             };
+            for error_level in higher_levels.iter() {
+                match get_verison_info(
+                    &all_prefix_segments,
+                    &input.information,
+                    encoding,
+                    *error_level,
+                    version..=version,
+                ) {
+                    Ok((_, boosted_blocks, _)) => {
+                        error_blocks = boosted_blocks;
+                        input.error_level = *error_level;
+                    }
+                    Err(_) => break,
+                }
+            }
+        }
+        Ok(QRData::with_version(version, error_blocks, segments, input))
+    }
+
+    /// generates an empty symbol of the given version and error correction level,
+    /// which only serves as a template for the layout of the symbol (e.g. for decoding)
+    pub fn empty(version: u8, error_level: ErrorLevel) -> std::result::Result<QRData, QrError> {
+        // every version has all four error correction levels
+        let Some((_, error_blocks)) = get_error_block_info()
+            .into_iter()
+            .find(|version_info| version_info.0 == version)
+            .and_then(|version_info| {
+                version_info
+                    .2
+                    .into_iter()
+                    .find(|(error_enum, _)| *error_enum == error_level)
+            })
+        else {
+            return Err(QrError::InvalidVersion(version));
+        };
+        let settings: Settings = Settings {
+            error_level,
+            ..Settings::default()
+        };
+        Ok(QRData::with_version(
+            version,
+            error_blocks,
+            vec![],
+            settings,
+        ))
+    }
+
+    /// creates the empty symbol of the given version
+    fn with_version(
+        version: u8,
+        error_blocks: Vec<ErrorBlockInfo>,
+        segments: Vec<Segment>,
+        settings: Settings,
+    ) -> QRData {
         // calculate width of the code
         // width = 17 + 4 * <version number>
         let width: usize = 17 + 4 * version as usize;
-        // size of the code with the quiet zone
-        // quiet zone: 4 square whide border around the qr code
-        let width_quite_zone: usize = width + (2 * 4);
-        // declare the vectors
-        let mut output_data: Vec<Vec<SymbolStatus>> = Vec::with_capacity(width_quite_zone);
-        let mut role_data: Vec<Vec<SymbolRole>> = Vec::with_capacity(width_quite_zone);
-        // push the defaults
-        for row in 0..width_quite_zone {
-            output_data.push(Vec::with_capacity(width_quite_zone));
-            role_data.push(Vec::with_capacity(width_quite_zone));
-            // initialise the vectors
-            for _ in 0..width_quite_zone {
-                output_data[row].push(SymbolStatus::Uninitialised);
-                role_data[row].push(SymbolRole::Uninitialised);
-            }
-        }
         QRData {
-            output_data,
-            role_data,
+            matrix: ModuleMatrix::new(width, width),
             version,
             error_blocks,
-            settings: input,
+            segments,
+            settings,
+            mask: 0,
+            mask_penalties: vec![],
         }
     }
 
@@ -473,13 +538,18 @@ impl QRData {
         self.version
     }
 
-    /// returns the witdh
+    /// returns the mask
+    pub fn get_mask(&self) -> u8 {
+        self.mask
+    }
+
+    /// returns the witdh (without quiet zone)
     pub fn get_width(&self) -> usize {
-        self.output_data[0].len()
+        self.matrix.width()
     }
 
     /// returns a reference to the settings
-    pub fn get_settings(&self) -> &Settings {
+    pub(crate) fn get_settings(&self) -> &Settings {
         &self.settings
     }
 
@@ -488,130 +558,16 @@ impl QRData {
         &self.error_blocks
     }
 
-    // draws the quiet zone around the code
-    pub fn quiet_zone(&mut self) {
-        if self.get_settings().debugging {
-            println!("default initialisation of the data");
-            print!("{self}");
-        }
-        let width: usize = self.output_data.len();
-        for x in 0..width {
-            for y in 0..width {
-                if x <= 3 || x >= width - 4 || y <= 3 || y >= width - 4 {
-                    self.output_data[x][y] = SymbolStatus::LogicalFalse;
-                    self.role_data[x][y] = SymbolRole::QuietZone;
-                }
-            }
-        }
-        if self.get_settings().debugging {
-            println!("drew quiet zone");
-            print!("{self}");
-        }
-    }
-
     /// adds the finder patterns for the code
     pub fn finders(&mut self) {
-        if self.settings.debugging {
-            println!(
-                "width: {} height: {}",
-                self.output_data.len(),
-                self.output_data[0].len()
-            );
-        }
-        // finding pattern
-        let logical_true: SymbolStatus = SymbolStatus::LogicalTrue;
-        let logical_false: SymbolStatus = SymbolStatus::LogicalFalse;
-        let pattern: Vec<Vec<SymbolStatus>> = vec![
-            vec![
-                logical_true,
-                logical_true,
-                logical_true,
-                logical_true,
-                logical_true,
-                logical_true,
-                logical_true,
-            ],
-            vec![
-                logical_true,
-                logical_false,
-                logical_false,
-                logical_false,
-                logical_false,
-                logical_false,
-                logical_true,
-            ],
-            vec![
-                logical_true,
-                logical_false,
-                logical_true,
-                logical_true,
-                logical_true,
-                logical_false,
-                logical_true,
-            ],
-            vec![
-                logical_true,
-                logical_false,
-                logical_true,
-                logical_true,
-                logical_true,
-                logical_false,
-                logical_true,
-            ],
-            vec![
-                logical_true,
-                logical_false,
-                logical_true,
-                logical_true,
-                logical_true,
-                logical_false,
-                logical_true,
-            ],
-            vec![
-                logical_true,
-                logical_false,
-                logical_false,
-                logical_false,
-                logical_false,
-                logical_false,
-                logical_true,
-            ],
-            vec![
-                logical_true,
-                logical_true,
-                logical_true,
-                logical_true,
-                logical_true,
-                logical_true,
-                logical_true,
-            ],
-        ];
-        // width with quiet zone
-        let width: usize = self.output_data[0].len();
-        // vector that contains the centre coordinate of the finder patterns
-        // centre qiet pattern = quiet zone + distance to centre - index starting at zero
-        // 7 = 4 + 4 - 1
-        let pattern_centers: Vec<(usize, usize)> = vec![(7, 7), (width - 8, 7), (7, width - 8)];
-        // go over the qrcode to fill in the patterns
-        // left to right
-        for x in 0..width {
-            // top to bottom
-            for y in 0..width {
-                // check manhattan distance from current coodinate to pattern centers
-                for pattern_centre in pattern_centers.iter() {
-                    let x_difference_neg: i16 = x as i16 - pattern_centre.0 as i16;
-                    let x_difference = x_difference_neg.abs();
-                    let y_difference_neg: i16 = y as i16 - pattern_centre.1 as i16;
-                    let y_difference = y_difference_neg.abs();
-                    // make shure that the current position is within the pattern
-                    if x_difference < 4
-                        && y_difference < 4
-                        && self.role_data[x][y] == SymbolRole::Uninitialised
-                    {
-                        self.output_data[x][y] = pattern[(x_difference_neg + 3) as usize]
-                            [(y_difference_neg + 3) as usize];
-                        self.role_data[x][y] = SymbolRole::FinderPattern;
-                    }
+        let width: usize = self.get_width();
+        // centres of the finder patterns in the top left, top right and bottom left corner
+        for (x_centre, y_centre) in [(3, 3), (width - 4, 3), (3, width - 4)] {
+            for x in x_centre - 3..=x_centre + 3 {
+                for y in y_centre - 3..=y_centre + 3 {
+                    // the ring at a distance of 2 elements from the centre is white
+                    let distance: usize = x.abs_diff(x_centre).max(y.abs_diff(y_centre));
+                    self.matrix.set_function(x, y, distance != 2);
                 }
             }
         }
@@ -624,26 +580,17 @@ impl QRData {
     /// add separators between finder patterns and data
     pub fn separators(&mut self) {
         let width: usize = self.get_width();
-        for x in 0..width {
-            for y in 0..width {
-                // make shure the current position is not at the edge
-                if (x > 0) && (x < width - 1) && (y > 0) && (y < width - 1) {
-                    // make shure an finder pattern element is in direct vicinity
-                    if (self.role_data[x + 1][y] == SymbolRole::FinderPattern
-                        || self.role_data[x][y + 1] == SymbolRole::FinderPattern
-                        || self.role_data[x - 1][y] == SymbolRole::FinderPattern
-                        || self.role_data[x][y - 1] == SymbolRole::FinderPattern
-                        || self.role_data[x - 1][y - 1] == SymbolRole::FinderPattern
-                        || self.role_data[x + 1][y - 1] == SymbolRole::FinderPattern
-                        || self.role_data[x - 1][y + 1] == SymbolRole::FinderPattern)
-                        && (self.role_data[x][y] == SymbolRole::Uninitialised
-                            || self.role_data[x][y] == SymbolRole::TimingPattern)
-                    {
-                        self.output_data[x][y] = SymbolStatus::LogicalFalse;
-                        self.role_data[x][y] = SymbolRole::Separator;
-                    }
-                }
-            }
+        // the white line along the inner edges of every finder pattern
+        for index in 0..8 {
+            // top left
+            self.matrix.set_function(7, index, false);
+            self.matrix.set_function(index, 7, false);
+            // top right
+            self.matrix.set_function(width - 8, index, false);
+            self.matrix.set_function(width - 1 - index, 7, false);
+            // bottom left
+            self.matrix.set_function(7, width - 1 - index, false);
+            self.matrix.set_function(index, width - 8, false);
         }
         if self.get_settings().debugging {
             println!("drew separators");
@@ -653,27 +600,11 @@ impl QRData {
 
     /// adding timing patterns to the code
     pub fn timing_pattern(&mut self) {
-        // width with quiet zone
-        let width: usize = self.output_data[0].len();
-        for x in 0..width {
-            for y in 0..width {
-                if (x == 10 || y == 10) && self.role_data[x][y] == SymbolRole::Uninitialised {
-                    if x == 10 {
-                        if (y + 1) % 2 == 1 {
-                            self.output_data[x][y] = SymbolStatus::LogicalTrue;
-                        } else {
-                            self.output_data[x][y] = SymbolStatus::LogicalFalse;
-                        }
-                    } else {
-                        self.output_data[x][y] = if (x + 1) % 2 == 1 {
-                            SymbolStatus::LogicalTrue
-                        } else {
-                            SymbolStatus::LogicalFalse
-                        }
-                    }
-                    self.role_data[x][y] = SymbolRole::TimingPattern;
-                }
-            }
+        let width: usize = self.get_width();
+        // row and column 6 between the separators, starting with a black element
+        for index in 8..width - 8 {
+            self.matrix.set_function(index, 6, index % 2 == 0);
+            self.matrix.set_function(6, index, index % 2 == 0);
         }
         if self.get_settings().debugging {
             println!("drew timing patterns");
@@ -681,40 +612,37 @@ impl QRData {
         }
     }
 
+    /// returns the positions of the format information bits 0 to 14, each next to the top
+    /// left finder pattern and split up next to the other two finder patterns
+    fn format_information_positions(&self) -> [((usize, usize), (usize, usize)); 15] {
+        let width: usize = self.get_width();
+        std::array::from_fn(|bit| {
+            let top_left: (usize, usize) = match bit {
+                0..=5 => (8, bit),
+                6 => (8, 7),
+                7 => (8, 8),
+                8 => (7, 8),
+                _ => (14 - bit, 8),
+            };
+            let split: (usize, usize) = match bit {
+                0..=7 => (width - 1 - bit, 8),
+                _ => (8, width - 15 + bit),
+            };
+            (top_left, split)
+        })
+    }
+
     /// reserve space for the format information so the data can be written without overwriting
     /// the format information data
     pub fn reserve_format_information(&mut self) {
         let width: usize = self.get_width();
-        let max_index_width: usize = width - 1;
-        for x in 0..width {
-            for y in 0..width {
-                // make shure the current position is not at the edge
-                if (x > 0) && (x < width - 1) && (y > 0) && (y < width - 1) {
-                    // make shure an finder pattern element is in direct vicinity
-                    if (self.role_data[x + 1][y] == SymbolRole::Separator
-                        || self.role_data[x][y + 1] == SymbolRole::Separator
-                        || self.role_data[x - 1][y] == SymbolRole::Separator
-                        || self.role_data[x][y - 1] == SymbolRole::Separator
-                        || self.role_data[x - 1][y - 1] == SymbolRole::Separator
-                        || self.role_data[x + 1][y - 1] == SymbolRole::Separator
-                        || self.role_data[x - 1][y + 1] == SymbolRole::Separator)
-                        && self.role_data[x][y] == SymbolRole::Uninitialised
-                    {
-                        // make shure nothing is added "on top" and "the left side" of Separators
-                        if y != (max_index_width - 4 - 7 - 1) && x != (max_index_width - 4 - 7 - 1)
-                        {
-                            self.role_data[x][y] = SymbolRole::ReservedFormatInformation;
-                            // turn the elements white for mask calculation later
-                            if x == 12 && y == (width - 1 - 4 - 7) {
-                                self.output_data[x][y] = SymbolStatus::LogicalTrue;
-                            } else {
-                                self.output_data[x][y] = SymbolStatus::LogicalFalse;
-                            }
-                        }
-                    }
-                }
-            }
+        // the elements stay white for the mask evaluation
+        for (top_left, split) in self.format_information_positions() {
+            self.matrix.set_function(top_left.0, top_left.1, false);
+            self.matrix.set_function(split.0, split.1, false);
         }
+        // element above the format information in the bottom left that is always black
+        self.matrix.set_function(8, width - 8, true);
         if self.get_settings().debugging {
             println!("after reserving place for format information");
             print!("{self}");
@@ -723,83 +651,32 @@ impl QRData {
 
     /// draw alignment pattern into qr code
     pub fn draw_alignment_pattern(&mut self) {
-        let width: usize = self.get_width();
-        let logical_true: SymbolStatus = SymbolStatus::LogicalTrue;
-        let logical_false: SymbolStatus = SymbolStatus::LogicalFalse;
-        let alignment_pattern: Vec<Vec<SymbolStatus>> = vec![
-            vec![
-                logical_true,
-                logical_true,
-                logical_true,
-                logical_true,
-                logical_true,
-            ],
-            vec![
-                logical_true,
-                logical_false,
-                logical_false,
-                logical_false,
-                logical_true,
-            ],
-            vec![
-                logical_true,
-                logical_false,
-                logical_true,
-                logical_false,
-                logical_true,
-            ],
-            vec![
-                logical_true,
-                logical_false,
-                logical_false,
-                logical_false,
-                logical_true,
-            ],
-            vec![
-                logical_true,
-                logical_true,
-                logical_true,
-                logical_true,
-                logical_true,
-            ],
-        ];
         if self.settings.debugging {
             println!("version: {}", self.version);
         }
-        // alignment_information.1 is empty for version 1, since version 1 doesn't have any alignment patterns
+        // version 1 doesn't have any alignment patterns
         if self.version > 1 {
-            let mut alignment_information: (u8, Vec<u8>) = alignment_pattern_data(self.version);
-            // increase the centres of the alignment patterns by four to compensate for the quiet zone
-            // in the indices
-            for alignment_centre in alignment_information.1.iter_mut() {
-                *alignment_centre += 4;
-            }
+            let (_, centres): (u8, Vec<u8>) = alignment_pattern_data(self.version);
+            let centres: Vec<usize> = centres.into_iter().map(|centre| centre as usize).collect();
             // first and last element of the alignment centres to avoid drawing into finder patterns
-            let lower_end: u8 = alignment_information.1[0];
-            let upper_end: u8 = alignment_information.1[alignment_information.1.len() - 1];
-            if self.settings.debugging {
-                println!("alignment lower: {lower_end} upper: {upper_end}");
-            }
-            // go over all qr code elements
-            for x in 0..width {
-                for y in 0..width {
-                    // go over all rq code elements
-                    for x_align in alignment_information.1.iter() {
-                        for y_align in alignment_information.1.iter() {
-                            // don't draw on finder patterns
-                            if !(((*x_align == upper_end || *x_align == lower_end)
-                                && *y_align == lower_end)
-                                || (*x_align == lower_end && *y_align == upper_end))
-                            {
-                                let x_diff: i32 = *x_align as i32 - x as i32;
-                                let y_diff: i32 = *y_align as i32 - y as i32;
-                                if (-2..=2).contains(&x_diff) && (-2..=2).contains(&y_diff) {
-                                    self.output_data[x][y] = alignment_pattern
-                                        [(x_diff + 2) as usize]
-                                        [(y_diff + 2) as usize];
-                                    self.role_data[x][y] = SymbolRole::AlignmentPattern;
-                                }
-                            }
+            let lower_end: usize = centres[0];
+            let upper_end: usize = centres[centres.len() - 1];
+            for x_centre in centres.iter() {
+                for y_centre in centres.iter() {
+                    if [
+                        (lower_end, lower_end),
+                        (upper_end, lower_end),
+                        (lower_end, upper_end),
+                    ]
+                    .contains(&(*x_centre, *y_centre))
+                    {
+                        continue;
+                    }
+                    for x in x_centre - 2..=x_centre + 2 {
+                        for y in y_centre - 2..=y_centre + 2 {
+                            // the ring at a distance of 1 element from the centre is white
+                            let distance: usize = x.abs_diff(*x_centre).max(y.abs_diff(*y_centre));
+                            self.matrix.set_function(x, y, distance != 1);
                         }
                     }
                 }
@@ -811,29 +688,20 @@ impl QRData {
         }
     }
 
+    /// returns the positions of the version information bits 0 to 17 in the bottom left
+    /// and in the top right (which is the same position transposed)
+    fn version_information_positions(&self) -> [(usize, usize); 18] {
+        let width: usize = self.get_width();
+        std::array::from_fn(|bit| (bit / 3, width - 11 + bit % 3))
+    }
+
     /// reserve version information
     pub fn reserve_version_information(&mut self) {
         // version information exists only in version 7 and up
         if self.version >= 7 {
-            let width: usize = self.get_width();
-            for x in 0..width {
-                for y in 0..width {
-                    // draw in bottom left
-                    if (4..=9).contains(&x)
-                        && (y <= (width - 4 - 7 - 1))
-                        && (y >= (width - 4 - 7 - 1 - 3))
-                        && self.role_data[x][y] != SymbolRole::Separator
-                    {
-                        self.role_data[x][y] = SymbolRole::ReservedVersionInformation;
-                    }
-                    if (x <= (width - 4 - 7 - 1))
-                        && (x >= (width - 4 - 7 - 1 - 3))
-                        && (4..=9).contains(&y)
-                        && self.role_data[x][y] != SymbolRole::Separator
-                    {
-                        self.role_data[x][y] = SymbolRole::ReservedVersionInformation;
-                    }
-                }
+            for (x, y) in self.version_information_positions() {
+                self.matrix.set_function(x, y, false);
+                self.matrix.set_function(y, x, false);
             }
         }
         if self.get_settings().debugging {
@@ -869,66 +737,51 @@ impl QRData {
 
     /// all the data that should be written into the qrcode is taken from the struct and inserted into
     /// a custom struct, that allows easier processing later
-    fn write_data_into_vectors(&self, error_blocks: &[ErrorBlockInfo]) -> Vec<MyBitVector> {
-        // get the data
-        let data: String = self.settings.information.clone();
-        // create MyBitVectors to write data into
+    fn write_data_into_vectors(
+        &self,
+        error_blocks: &[ErrorBlockInfo],
+    ) -> std::result::Result<Vec<MyBitVector>, QrError> {
+        if self.settings.debugging {
+            println!("segments: {:?}", self.segments);
+        }
+        // the entire data is written into one bit stream first and
+        // split up into the individual blocks afterwards
+        let mut total_data_bytes: u16 = 0;
+        for block in error_blocks.iter() {
+            total_data_bytes += block.num_block as u16 * block.num_data_bytes as u16;
+        }
+        let mut bit_stream: MyBitVector = MyBitVector::new_with_capacity(total_data_bytes);
+        for segment in self.segments.iter() {
+            write_segment(&mut bit_stream, segment, self.version)?;
+        }
+        // the terminator and the bits up to the next full byte are already zero,
+        // so only the unused bytes have to be padded
+        bit_stream.pad_empty_rest();
+        if self.settings.debugging {
+            println!("bit stream: {bit_stream}");
+            bit_stream.print_hex();
+        }
+        // split the bit stream up into MyBitVectors for every block
+        let stream_data: Vec<u8> = bit_stream.get_data();
+        let mut stream_index: usize = 0;
         let mut bit_vectors: Vec<MyBitVector> = vec![];
         for block in error_blocks.iter() {
             for _ in 0..block.num_block {
                 if self.settings.debugging {
                     println!("num data bytes: {}", block.num_data_bytes);
                 }
-                bit_vectors.push(MyBitVector::new_with_capacity(block.num_data_bytes.into()));
-            }
-        }
-        if self.settings.debugging {
-            println!(
-                "bit vectors: {:?} (length {})",
-                bit_vectors,
-                bit_vectors.len()
-            );
-            for vector in bit_vectors.iter() {
-                vector.print_hex();
-            }
-            for vector in bit_vectors.iter() {
-                println!("{vector}");
+                let mut block_vector: MyBitVector =
+                    MyBitVector::new_with_capacity(block.num_data_bytes.into());
+                for byte in
+                    stream_data[stream_index..stream_index + block.num_data_bytes as usize].iter()
+                {
+                    block_vector.push(*byte, 8)?;
+                }
+                stream_index += block.num_data_bytes as usize;
+                bit_vectors.push(block_vector);
             }
         }
-        // write mode bits into data
         assert!(!bit_vectors.is_empty());
-        let mut bit_vector_index: usize = 0;
-        // add byte mode indicator (for byte mode)
-        bit_vectors[bit_vector_index].push(BYTEMODEINDICATOR, 4);
-        // add character count indicator
-        let len_text: usize = data.len();
-        if self.version >= 10 {
-            bit_vectors[bit_vector_index].push(((len_text & 0b1111_1111_0000_0000) >> 8) as u8, 8);
-            bit_vectors[bit_vector_index].push((len_text & 0b0000_0000_1111_1111) as u8, 8);
-        } else {
-            bit_vectors[bit_vector_index].push((len_text & 0b1111_1111) as u8, 8);
-        }
-        for char in data.chars() {
-            // get index and size of MyBitVector
-            let vector_index: u32 = bit_vectors[bit_vector_index].curr_position;
-            let vector_capacity: u16 = bit_vectors[bit_vector_index].capacity * 8;
-            // check if entire char can be written into MyBitVector
-            let remaining_capacity: u32 = vector_capacity as u32 - vector_index;
-            if remaining_capacity >= CHARACTERBITS as u32 {
-                bit_vectors[bit_vector_index].push(char as u8, CHARACTERBITS);
-            } else if remaining_capacity == 4 {
-                // write what fits into vector
-                bit_vectors[bit_vector_index]
-                    .push((char as u8 & 0b1111_0000) >> 4, remaining_capacity as u8);
-                // increase index
-                bit_vector_index += 1;
-                assert!(bit_vector_index < bit_vectors.len());
-                bit_vectors[bit_vector_index]
-                    .push(char as u8 & 0b0000_1111, remaining_capacity as u8);
-            } else {
-                panic!("remaining capacity wasn't 4, but {remaining_capacity}");
-            }
-        }
         if self.settings.debugging {
             println!(
                 "bit vectors: {:?} (length {})",
@@ -938,21 +791,8 @@ impl QRData {
             for vector in bit_vectors.iter() {
                 vector.print_hex();
             }
-            for vector in bit_vectors.iter() {
-                println!("{vector}");
-            }
-        }
-        // pad a bit vector if it has bytes that are unused
-        for bit_vector in bit_vectors.iter_mut() {
-            bit_vector.pad_empty_rest();
         }
-        if self.settings.debugging {
-            println!("padded vectors");
-            for vector in bit_vectors.iter() {
-                vector.print_hex();
-            }
-        }
-        bit_vectors
+        Ok(bit_vectors)
     }
 
     /// calculates the erroro correction term that must be attached to the data bytes
@@ -962,7 +802,7 @@ impl QRData {
         error_blocks: &[ErrorBlockInfo],
         all_blocks: &mut [Vec<u8>],
         bit_vectors: &[MyBitVector],
-    ) {
+    ) -> std::result::Result<(), QrError> {
         let mut vector_index: u8 = 0;
         if self.settings.debugging {
             println!("individual error blocks:");
@@ -978,51 +818,25 @@ impl QRData {
         );
         // go through all blocks
         for block in error_blocks.iter() {
-            let Some(generator_polynomial) = galois_field.correction_polynomial(
-                (block.num_data_bytes + block.num_error_bytes) - block.num_data_bytes,
-            ) else {
-                panic!("correction polynomial wasn't found")
-            };
             if self.settings.debugging {
-                println!("error correction polynomial: {generator_polynomial}");
+                if let Some(generator_polynomial) =
+                    galois_field.correction_polynomial(block.num_error_bytes)
+                {
+                    println!("error correction polynomial: {generator_polynomial}");
+                }
             }
             // process individual blocks
             for _ in 0..block.num_block {
-                let mut raw_polynomial: Polynomial =
-                    Polynomial::from(bit_vectors[vector_index as usize].get_data())
-                        * Polynomial::new(vec![Indeterminate::new(
-                            1,
-                            block.num_error_bytes as i16,
-                        )]);
+                let data: Vec<u8> = bit_vectors[vector_index as usize].get_data();
+                // generate error bytes
+                let error_bytes: Vec<u8> =
+                    galois_field.error_correction_bytes(&data, block.num_error_bytes)?;
                 if self.settings.debugging {
-                    println!(
-                        "pre expansion polynomial (block {}): {}",
-                        vector_index,
-                        Polynomial::from(bit_vectors[vector_index as usize].get_data())
-                    );
-                    println!("raw polynomial (block {vector_index}): {raw_polynomial}");
-                }
-                // attach missing 0x^n
-                let high_degree = raw_polynomial.get_function()[0].get_degree();
-                for degree in (0..=high_degree).rev() {
-                    if !raw_polynomial.is_degree_in_field(degree) {
-                        raw_polynomial
-                            .get_function_mut()
-                            .push(Indeterminate::new(0, degree));
-                    }
+                    println!("data bytes (block {vector_index}): {data:?}");
                 }
-                // generate error bytes
-                let error_polynomial = GaloisFields::calculate_error_correction(
-                    &raw_polynomial,
-                    generator_polynomial.clone(),
-                    galois_field.clone(),
-                );
                 // put data and error correction together
-                let final_polynomial = raw_polynomial + error_polynomial;
-                // write results into final vector
-                for values in final_polynomial.into_iter() {
-                    all_blocks[vector_index as usize].push(values.get_coefficient());
-                }
+                all_blocks[vector_index as usize].extend(data);
+                all_blocks[vector_index as usize].extend(error_bytes);
                 vector_index += 1;
             }
         }
@@ -1035,6 +849,7 @@ impl QRData {
                 println!("{data_printout:?}");
             }
         }
+        Ok(())
     }
 
     /// the to be written data is distributed across the qrcode to raise
@@ -1113,19 +928,9 @@ impl QRData {
                         match vector_env.cmp(&left_out_index) {
                             // if index above the index to be left out a value wasn't written and
                             // the index must be artificialy lowered to account for the öeft out value
+                            // (up to and including the last error correction byte)
                             Ordering::Greater => {
-                                if vector_env == 0 {
-                                    eprintln!("came in greater part with vector_env=0")
-                                } else if vector_env
-                                    < (error_blocks[0].num_data_bytes
-                                        + error_blocks[0].num_error_bytes)
-                                        as usize
-                                {
-                                    final_data_vect.push(all_blocks[block_env.0][vector_env - 1])
-                                // this triggers in the last index
-                                } else {
-                                    final_data_vect.push(all_blocks[block_env.0][vector_env - 2])
-                                }
+                                final_data_vect.push(all_blocks[block_env.0][vector_env - 1])
                             }
                             // if at the index to be left out, do nothing
                             Ordering::Equal => (),
@@ -1170,101 +975,55 @@ impl QRData {
         final_data_vect
     }
 
-    /// write all data into the QR code struct
-    fn write_into_self(&mut self, final_data_vect: &[u8]) {
-        // write all data into the actual QR code
-        let mut vector_bit_index: usize = 0;
-        let mut x_index: usize = self.output_data.len() - 1 - 4;
-        let mut y_index: usize = self.output_data.len() - 1 - 4;
-        if self.settings.debugging {
-            println!("starting indices for writing\nx: {x_index}\ny: {y_index}");
-        }
-        let mut is_y_shrinking: bool = true;
-        let mut is_right: bool = true;
-        // go througth all elements of the qr code and write data into
-        // everything uninitialised yet
+    /// returns the coordinates of all elements that don't belong to a function pattern
+    /// in the order the data is written into them
+    pub fn data_module_positions(&self) -> Vec<(usize, usize)> {
+        let width: usize = self.get_width();
+        let mut positions: Vec<(usize, usize)> = vec![];
+        // elements get written in a right-left-right-left manner in columns that are two
+        // elements wide, starting bottom up in the lower right corner of the code;
+        // the direction changes at the top and the bottom
+        let mut right_column: usize = width - 1;
+        let mut is_upwards: bool = true;
         loop {
-            // if nothing is inside the element yet
-            if self.role_data[x_index][y_index] == SymbolRole::Uninitialised {
-                // writing data
-                if (vector_bit_index / 8) < final_data_vect.len() {
-                    // figure out if the current bit is true or false
-                    let element_value: bool = match vector_bit_index % 8 {
-                        0 => (final_data_vect[vector_bit_index / 8] & 0b1000_0000) > 0,
-                        1 => (final_data_vect[vector_bit_index / 8] & 0b0100_0000) > 0,
-                        2 => (final_data_vect[vector_bit_index / 8] & 0b0010_0000) > 0,
-                        3 => (final_data_vect[vector_bit_index / 8] & 0b0001_0000) > 0,
-                        4 => (final_data_vect[vector_bit_index / 8] & 0b0000_1000) > 0,
-                        5 => (final_data_vect[vector_bit_index / 8] & 0b0000_0100) > 0,
-                        6 => (final_data_vect[vector_bit_index / 8] & 0b0000_0010) > 0,
-                        7 => (final_data_vect[vector_bit_index / 8] & 0b0000_0001) > 0,
-                        _ => panic!(
-                            "wrong bit index in qr code writing {}",
-                            vector_bit_index % 8
-                        ),
-                    };
-                    if element_value {
-                        self.output_data[x_index][y_index] = SymbolStatus::LogicalTrue;
-                    } else {
-                        self.output_data[x_index][y_index] = SymbolStatus::LogicalFalse;
-                    }
-                } else {
-                    // if all data is written fill the remaining data in the code with logical false
-                    self.output_data[x_index][y_index] = SymbolStatus::LogicalFalse;
-                    if self.settings.debugging {
-                        println!("wrote filler false");
+            for step in 0..width {
+                let y: usize = if is_upwards { width - 1 - step } else { step };
+                for x in [right_column, right_column - 1] {
+                    if !self.matrix.is_function(x, y) {
+                        positions.push((x, y));
                     }
                 }
-                self.role_data[x_index][y_index] = SymbolRole::EncodingRegion;
-
-                // increase bit index
-                vector_bit_index += 1;
-                // println!("{}", self);
             }
-
-            // update the indices
-            // elements get written in a right-left-right-left manner bottom up in
-            // the lower right corner of the code
-            // once the top is reached the index shifts 2 elements to the left and goes down again
-            // until it reaches the bottom shifts 2 elements to the left again
-            // repeat until yout hit
-            if is_right {
-                x_index -= 1;
-            } else {
-                x_index += 1;
-                if is_y_shrinking {
-                    y_index -= 1;
-                } else {
-                    y_index += 1;
-                }
-            }
-            // always shift between left and right
-            is_right = !is_right;
-            // direction changes (down/up)
-            // if y index hits the quiet zone move x to the left and reverse course
-            if (y_index < 4 || y_index > self.output_data.len() - 1 - 4) && is_right {
-                x_index -= 2;
-                // change direction
-                is_y_shrinking = !is_y_shrinking;
+            // the left edge of the code is reached
+            if right_column == 1 {
+                break;
             }
+            right_column -= 2;
             // avoid the timing pattern
-            if x_index == 10 {
-                x_index -= 1;
-                if self.settings.debugging {
-                    println!("avoided the timing pattern");
-                }
+            if right_column == 6 {
+                right_column -= 1;
             }
+            is_upwards = !is_upwards;
+        }
+        positions
+    }
 
-            // end loop if everything is done/left quiet zone is hit
-            if x_index < 4 {
-                break;
-            }
-            // println!("x: {}; y: {}", x_index, y_index);
+    /// write all data into the QR code struct
+    fn write_into_self(&mut self, final_data_vect: &[u8]) {
+        // write all data into the actual QR code
+        for (vector_bit_index, (x_index, y_index)) in
+            self.data_module_positions().into_iter().enumerate()
+        {
+            // if all data is written fill the remaining data in the code with logical false
+            let element_value: bool = (vector_bit_index / 8) < final_data_vect.len()
+                && (final_data_vect[vector_bit_index / 8] << (vector_bit_index % 8)) & 0b1000_0000
+                    > 0;
+            self.matrix.set_dark(x_index, y_index, element_value);
         }
     }
 
     /// reads the text from self.settings and write it into the qr code
-    pub fn read_and_write(&mut self) {
+    pub fn read_and_write(&mut self) -> std::result::Result<(), QrError> {
         // let width: usize = self.get_width();
         // let max_index: usize = width - 1;
         // get the data
@@ -1278,12 +1037,12 @@ impl QRData {
             self.generate_error_blocks();
 
         // write all data into the error block vector
-        let bit_vectors: Vec<MyBitVector> = self.write_data_into_vectors(&error_blocks);
+        let bit_vectors: Vec<MyBitVector> = self.write_data_into_vectors(&error_blocks)?;
         assert!(all_blocks.len() == bit_vectors.len());
 
         // convert the datavectors, so that they
         // also contain the error correction numbers
-        self.generate_error_corrction(&error_blocks, &mut all_blocks, &bit_vectors);
+        self.generate_error_corrction(&error_blocks, &mut all_blocks, &bit_vectors)?;
 
         // all vectors get shuffeled around to spread all information across the qrcode
         let final_data_vect = self.shuffle_bit_vectors(
@@ -1299,85 +1058,19 @@ impl QRData {
             println!("after writing the actual data");
             print!("{self}");
         }
+        Ok(())
     }
 
+    /// writes the version information into the reserved elements
     pub fn version_information(&mut self) {
-        let version: u8 = self.version;
         // only version 7 or larger
-        if (7..=40).contains(&version) {
-            let bit_stream: u32 = version_info(version);
-            // write into lower left
-            let mut mask_left: u32 = 2_u32.pow(17);
-            let y_left_start: usize = self.output_data.len() - 4 - 9;
-            let mut x_index_left: usize = 3 + 6;
-            let mut y_index_left: usize = y_left_start;
-            // write data into field in the bottom left
-            loop {
-                // only write into elements reserved
-                if self.role_data[x_index_left][y_index_left]
-                    == SymbolRole::ReservedVersionInformation
-                {
-                    // write
-                    if (bit_stream & mask_left) > 0 {
-                        self.output_data[x_index_left][y_index_left] = SymbolStatus::LogicalTrue;
-                    } else {
-                        self.output_data[x_index_left][y_index_left] = SymbolStatus::LogicalFalse;
-                    }
-                } else {
-                    println!("{self}");
-                    panic!("tried to write into field that isn't reserved for version information (x: {x_index_left}, y: {y_index_left})" );
-                }
-                self.role_data[x_index_left][y_index_left] = SymbolRole::VersionInformation;
-
-                // if mask is one the entire information was written
-                if mask_left == 1 {
-                    break;
-                }
-                // update mask
-                mask_left /= 2;
-
-                // update the indeces
-                y_index_left -= 1;
-                if y_index_left <= y_left_start - 3 {
-                    x_index_left -= 1;
-                    y_index_left = y_left_start;
-                }
-            }
-            // write data into field in the top right
-            let mut mask_right: u32 = 2_u32.pow(17);
-            let x_left_start: usize = self.output_data.len() - 4 - 9;
-            let mut x_index_right: usize = x_left_start;
-            let mut y_index_right: usize = 3 + 6;
-            loop {
-                // only write into elements reserved
-                if self.role_data[x_index_right][y_index_right]
-                    == SymbolRole::ReservedVersionInformation
-                {
-                    // write
-                    if (bit_stream & mask_right) > 0 {
-                        self.output_data[x_index_right][y_index_right] = SymbolStatus::LogicalTrue;
-                    } else {
-                        self.output_data[x_index_right][y_index_right] = SymbolStatus::LogicalFalse;
-                    }
-                } else {
-                    println!("{self}");
-                    panic!("tried to write into field that isn't reserved for version information (x: {x_index_right}, y: {y_index_right})" );
-                }
-                self.role_data[x_index_right][y_index_right] = SymbolRole::VersionInformation;
-
-                // if mask is one the entire information was written
-                if mask_right == 1 {
-                    break;
-                }
-                // update mask
-                mask_right /= 2;
-
-                // update the indeces
-                x_index_right -= 1;
-                if x_index_right <= x_left_start - 3 {
-                    y_index_right -= 1;
-                    x_index_right = x_left_start;
-                }
+        if (7..=40).contains(&self.version) {
+            let bit_stream: u32 = encode_version_information(self.version);
+            for (bit, (x, y)) in self.version_information_positions().into_iter().enumerate() {
+                let is_dark: bool = (bit_stream >> bit) & 1 == 1;
+                // bottom left and top right
+                self.matrix.set_function(x, y, is_dark);
+                self.matrix.set_function(y, x, is_dark);
             }
         }
         if self.get_settings().debugging {
@@ -1386,106 +1079,54 @@ impl QRData {
         }
     }
 
-    fn apply_mask(&self, mask_number: u8) -> Self {
-        assert!((0..=7).contains(&mask_number));
-        let mut working_copy = self.clone();
-        // iterate over qr code and apply the masking
-        let smallest_index = 4;
-        let biggest_index = self.output_data.len() - 4;
-        // left to right
-        for i in smallest_index..biggest_index {
-            // top to bottom
-            for j in smallest_index..biggest_index {
-                // only mask data
-                if self.role_data[i][j] == SymbolRole::EncodingRegion {
-                    // remove qiet zone around qr code for masking purposes
-                    // TODO figure out why i and j need to be flipped here in order for
-                    // the masks to work properly (for i_mod and j_mod)
-                    let i_mod = j - 4;
-                    let j_mod = i - 4;
-                    // apply mask according to mask_number
-                    let match_bool = match mask_number {
-                        0 => (i_mod + j_mod) % 2 == 0,
-                        1 => i_mod % 2 == 0,
-                        2 => j_mod % 3 == 0,
-                        3 => (i_mod + j_mod) % 3 == 0,
-                        4 => {
-                            ((i_mod as f32 / 2.0) as usize + (j_mod as f32 / 3.0) as usize) % 2 == 0
-                        }
-                        5 => (i_mod * j_mod) % 2 + (i_mod * j_mod) % 3 == 0,
-                        6 => ((i_mod * j_mod) % 2 + (i_mod * j_mod) % 3) % 2 == 0,
-                        7 => ((i_mod + j_mod) % 2 + (i_mod * j_mod) % 3) % 2 == 0,
-                        // cannot be reached due to assert earlier
-                        _ => false,
-                    };
-                    // invert value if necessary
-                    if match_bool {
-                        working_copy.output_data[i][j] =
-                            working_copy.output_data[i][j] ^ SymbolStatus::LogicalTrue;
-                    }
-                }
-            }
+    /// writes the format information of the error correction level and the mask
+    /// into the reserved elements
+    fn write_format_information(&mut self, mask_number: u8) {
+        // get the databits
+        let mut data_bits: u8 = 0;
+        data_bits |= match self.settings.error_level {
+            ErrorLevel::L => 0b01 << 3,
+            // value is 0, so do nothing
+            ErrorLevel::M => 0b00 << 3,
+            ErrorLevel::Q => 0b11 << 3,
+            ErrorLevel::H => 0b10 << 3,
+        };
+        data_bits |= mask_number;
+        let format_bits: u16 = encode_format_information(data_bits);
+        for (bit, (top_left, split)) in self.format_information_positions().into_iter().enumerate()
+        {
+            let is_dark: bool = (format_bits >> bit) & 1 == 1;
+            self.matrix.set_function(top_left.0, top_left.1, is_dark);
+            self.matrix.set_function(split.0, split.1, is_dark);
         }
-        working_copy
+    }
+
+    /// returns every row and every column of the symbol
+    fn lines(&self) -> Vec<Vec<bool>> {
+        let width: usize = self.get_width();
+        (0..width)
+            .map(|x| self.matrix.column(x).collect())
+            .chain((0..width).map(|y| self.matrix.row(y).collect()))
+            .collect()
     }
 
     // calculates the penalty score for consecutive elements of the same color
     fn penalty_consecutive_same_color(&self) -> u32 {
-        let mut penalty_consecuetive_same_color = 0;
-        // multiple consecutive elements of the same color
-        let low_index = 4;
-        let high_index = self.output_data.len() - 4;
-        // move top to bottom, left to right
-        for x in low_index..high_index {
-            let mut former_status = SymbolStatus::LogicalFalse;
-            let mut consecutive_counter = 0;
-            for y in low_index..high_index {
-                if self.output_data[x][y] == former_status {
-                    consecutive_counter += 1;
-                    match consecutive_counter.cmp(&5) {
-                        Ordering::Equal => penalty_consecuetive_same_color += 3,
-                        Ordering::Greater => penalty_consecuetive_same_color += 1,
-                        Ordering::Less => (),
-                    }
-                } else {
-                    consecutive_counter = 0;
-                    former_status = self.output_data[x][y];
-                }
-            }
-        }
-        // move left to right, top to bottom
-        for y in low_index..high_index {
-            let mut former_status = SymbolStatus::LogicalFalse;
-            let mut consecutive_counter = 0;
-            for x in low_index..high_index {
-                if self.output_data[x][y] == former_status {
-                    consecutive_counter += 1;
-                    match consecutive_counter.cmp(&5) {
-                        Ordering::Equal => penalty_consecuetive_same_color += 3,
-                        Ordering::Greater => penalty_consecuetive_same_color += 1,
-                        Ordering::Less => (),
-                    }
-                } else {
-                    consecutive_counter = 0;
-                    former_status = self.output_data[x][y];
-                }
-            }
-        }
-        penalty_consecuetive_same_color
+        self.lines().iter().map(|line| penalty_runs(line)).sum()
     }
 
     // calculates the penalty score for 2x2 blocks of the same color
     fn penalty_color_blocks(&self) -> u32 {
         let mut penalty_color_blocks = 0;
-        let low_index = 4;
-        let high_index = self.output_data.len() - 4;
+        let width: usize = self.get_width();
         // blocks of the same color
-        for x in low_index..high_index - 1 {
-            for y in low_index..high_index - 1 {
+        for x in 0..width - 1 {
+            for y in 0..width - 1 {
                 // are all elements in a 2x2 box the same?
-                if self.output_data[x][y] == self.output_data[x + 1][y]
-                    && self.output_data[x][y] == self.output_data[x][y + 1]
-                    && self.output_data[x][y] == self.output_data[x + 1][y + 1]
+                let is_dark: bool = self.matrix.is_dark(x, y);
+                if is_dark == self.matrix.is_dark(x + 1, y)
+                    && is_dark == self.matrix.is_dark(x, y + 1)
+                    && is_dark == self.matrix.is_dark(x + 1, y + 1)
                 {
                     penalty_color_blocks += 3;
                 }
@@ -1496,168 +1137,76 @@ impl QRData {
 
     // calculates the penalty score for possible finder patterns in the generated code
     fn penalty_finder_patterns(&self) -> u32 {
-        let mut penalty_finder_patterns = 0;
-        let low_index = 4;
-        let high_index = self.output_data.len() - 4;
-        // sequences that are similar to the finder pattern
-        let pattern1 = [
-            SymbolStatus::LogicalTrue,
-            SymbolStatus::LogicalFalse,
-            SymbolStatus::LogicalTrue,
-            SymbolStatus::LogicalTrue,
-            SymbolStatus::LogicalTrue,
-            SymbolStatus::LogicalFalse,
-            SymbolStatus::LogicalTrue,
-            SymbolStatus::LogicalFalse,
-            SymbolStatus::LogicalFalse,
-            SymbolStatus::LogicalFalse,
-            SymbolStatus::LogicalFalse,
-        ];
-        let pattern2 = [
-            SymbolStatus::LogicalFalse,
-            SymbolStatus::LogicalFalse,
-            SymbolStatus::LogicalFalse,
-            SymbolStatus::LogicalFalse,
-            SymbolStatus::LogicalTrue,
-            SymbolStatus::LogicalFalse,
-            SymbolStatus::LogicalTrue,
-            SymbolStatus::LogicalTrue,
-            SymbolStatus::LogicalTrue,
-            SymbolStatus::LogicalFalse,
-            SymbolStatus::LogicalTrue,
-        ];
-        // move top to bottom, left to right
-        for x in low_index..high_index {
-            let mut element_vector = vec![];
-            for y in low_index..high_index {
-                element_vector.push(self.output_data[x][y]);
-                // if longer than the pattern shorten the vector to the length of the pattern
-                if element_vector.len() > 11 {
-                    _ = element_vector.remove(0);
-                }
-                if element_vector.len() == 11
-                    && (element_vector == pattern1 || element_vector == pattern2)
-                {
-                    penalty_finder_patterns += 40;
-                }
-            }
-        }
-        // move left to right, top to bottom
-        for y in low_index..high_index {
-            let mut element_vector = vec![];
-            for x in low_index..high_index {
-                element_vector.push(self.output_data[x][y]);
-                // if longer than the pattern shorten the vector to the length of the pattern
-                if element_vector.len() > 11 {
-                    _ = element_vector.remove(0);
-                }
-                if element_vector.len() == 11
-                    && (element_vector == pattern1 || element_vector == pattern2)
-                {
-                    penalty_finder_patterns += 40;
-                }
-            }
-        }
-        penalty_finder_patterns
+        self.lines()
+            .iter()
+            .map(|line| penalty_finder_like(line))
+            .sum()
     }
 
     // calculates the penalty score for the ratios of black and/or white elements
     fn penalty_color_ratios(&self) -> u32 {
-        let mut penalty_color_ratios = 0;
-        let low_index = 4;
-        let high_index = self.output_data.len() - 4;
-        // ratio of black to white elements
-        let total_elements = (high_index - low_index) * (high_index - low_index);
-        let mut total_black = 0;
-        for x in low_index..high_index {
-            for y in low_index..high_index {
-                if self.output_data[x][y] == SymbolStatus::LogicalTrue {
-                    total_black += 1;
-                }
-            }
-        }
-        let precentage_dark = (total_black as f32 / total_elements as f32) * 100.0;
-        let dark_floor = (precentage_dark / 5.0).floor() * 5.0;
-        let dark_result1 = (dark_floor - 50.0).abs() / 5.0;
-        let dark_result2 = ((dark_floor + 5.0) - 50.0).abs() / 5.0;
-        if dark_result1 >= dark_result2 {
-            penalty_color_ratios += dark_result2 as u32 * 10;
-        } else {
-            penalty_color_ratios *= dark_result1 as u32 * 10;
-        }
-        penalty_color_ratios
+        let total_elements: usize = self.get_width() * self.get_width();
+        let total_black: usize = self.matrix.count_dark();
+        // 10 points for every full 5 % the share of black elements deviates from 50 %
+        let deviation_steps: usize =
+            (20 * total_black).abs_diff(10 * total_elements) / total_elements;
+        deviation_steps as u32 * 10
     }
 
-    /// calulates the penalty score of a qr code based on:
+    /// calulates the penalty score of the masked qr code based on:
     /// consecutive elements of the same color,
     /// 2x2 blocks of the same color,
     /// possible finder patterns in the generated code
     /// and the ratios of black and/or white elements
-    fn calculate_penalty(&self) -> u32 {
-        // based on https://www.thonky.com/qr-code-tutorial/data-masking (14.03.2025)
-        let mut total_score = 0;
-        // condition #1
-        total_score += self.penalty_consecutive_same_color();
-        // condition #2
-        total_score += self.penalty_color_blocks();
-        // condition #3
-        total_score += self.penalty_finder_patterns();
-        // condition #4
-        total_score += self.penalty_color_ratios();
-        // summ of all penalties
-        total_score
+    fn calculate_penalty(&self, mask_number: u8) -> MaskPenalty {
+        // based on ISO/IEC 18004:2015 section 7.8.3.1
+        MaskPenalty {
+            mask: mask_number,
+            // condition #1
+            n1: self.penalty_consecutive_same_color(),
+            // condition #2
+            n2: self.penalty_color_blocks(),
+            // condition #3
+            n3: self.penalty_finder_patterns(),
+            // condition #4
+            n4: self.penalty_color_ratios(),
+        }
     }
 
     /// this function applies the mask with the lowest penalty
     /// to the qr code and applies/writes the format informaiton
     pub fn masking_format_information(&mut self) {
-        let smallest_index = 4;
-        let biggest_index = self.output_data.len() - 4 - 1;
-        // create white qr code for debugging the masks
-        let mut self_blank = self.clone();
-        for x in 0..self.output_data.len() {
-            for y in 0..self.output_data.len() {
-                // 'paint' everything white
-                self_blank.output_data[x][y] = SymbolStatus::LogicalFalse;
-            }
-        }
-        // apply every mask to the qr code and select the mask with the lowest penalty
-        let mut lowest_penalty_code = self.clone();
-        let mut lowest_penalty_so_far = u32::MAX;
-        let mut lowest_panalty_mask_number = 0;
-        for mask_number in 0..8 {
-            let current_masked = self.apply_mask(mask_number);
-            let current_loss = current_masked.calculate_penalty();
-            if current_loss < lowest_penalty_so_far {
-                lowest_penalty_code = current_masked;
-                lowest_penalty_so_far = current_loss;
-                lowest_panalty_mask_number = mask_number;
-            }
+        // the bitplanes invert the data elements, so every mask can be tried
+        // and removed again in place
+        let mask_planes: Vec<Vec<u64>> = (0..8)
+            .map(|mask_number| self.matrix.mask_plane(mask_number))
+            .collect();
+        // every mask is rated together with its format information
+        self.mask_penalties = vec![];
+        for (mask_number, mask_plane) in (0..8).zip(mask_planes.iter()) {
+            self.matrix.xor(mask_plane);
+            self.write_format_information(mask_number);
+            let penalty: MaskPenalty = self.calculate_penalty(mask_number);
             if self.settings.debugging {
-                print!(
-                    "mask {mask_number} (penalty: {current_loss}):\n{}",
-                    self_blank.apply_mask(mask_number)
-                );
+                print!("{penalty:?} (total: {}):\n{self}", penalty.total());
             }
-        }
-
-        // overwrite own data with the masked data
-        self.output_data = lowest_penalty_code.output_data;
-
-        // write the mask information into the qrcode
-        // get the databits
-        let mut data_bits: u8 = 0;
-        data_bits |= match self.settings.error_level {
-            ErrorLevel::L => 0b01 << 3,
-            // value is 0, so do nothing
-            ErrorLevel::M => 0b00 << 3,
-            ErrorLevel::Q => 0b11 << 3,
-            ErrorLevel::H => 0b10 << 3,
+            self.matrix.xor(mask_plane);
+            self.mask_penalties.push(penalty);
+        }
+        // a mask given in the settings is applied without comparing it to the others,
+        // otherwise the first mask with the lowest penalty is selected
+        self.mask = match self.settings.symbol.mask {
+            Some(mask_number) => mask_number,
+            None => self
+                .mask_penalties
+                .iter()
+                .min_by_key(|penalty| penalty.total())
+                .map_or(0, |penalty| penalty.mask),
         };
-        data_bits |= lowest_panalty_mask_number;
-        let final_data_bits = information_sequences(data_bits);
-        // write info into qrcode
-        write_format_info!(self, smallest_index, biggest_index, final_data_bits);
+
+        // apply the mask to the own data and write the mask information into the qrcode
+        self.matrix.xor(&mask_planes[self.mask as usize]);
+        self.write_format_information(self.mask);
         if self.get_settings().debugging {
             println!("after applying the mask and format information");
             print!("{self}");
@@ -1674,83 +1223,92 @@ impl QRData {
         }
     }
 
+    /// returns the penalties of all eight masks (empty until the mask is chosen)
+    pub fn get_mask_penalties(&self) -> &[MaskPenalty] {
+        &self.mask_penalties
+    }
+
     /// prints debugging output of the output data
     pub fn print_data(&self) {
-        println!("{:?}", self.output_data);
+        println!("{:?}", self.get_data());
+    }
+
+    /// returns the elements of the qr code (with quiet zone) indexed with [x][y]
+    pub fn get_data(&self) -> Vec<Vec<SymbolStatus>> {
+        let width: usize = self.get_width() + 2 * 4;
+        (0..width)
+            .map(|x| {
+                (0..width)
+                    .map(|y| {
+                        let is_dark: bool = (4..width - 4).contains(&x)
+                            && (4..width - 4).contains(&y)
+                            && self.matrix.is_dark(x - 4, y - 4);
+                        if is_dark {
+                            SymbolStatus::LogicalTrue
+                        } else {
+                            SymbolStatus::LogicalFalse
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// returns whether the elements of the qr code without the quiet zone (4 elements wide)
+    /// are black, indexed with [x][y]
+    pub fn get_modules(&self) -> Vec<Vec<bool>> {
+        let width: usize = self.get_width();
+        (0..width)
+            .map(|x| self.matrix.column(x).collect())
+            .collect()
     }
 
     // only used in tests
-    pub fn _get_data(&self) -> Vec<Vec<SymbolStatus>> {
-        self.output_data.clone()
+    /// returns the data codewords of all blocks before error correction and interleaving
+    pub fn _get_data_codewords(&self) -> Vec<u8> {
+        let mut data_codewords: Vec<u8> = vec![];
+        for bit_vector in self
+            .write_data_into_vectors(&self.error_blocks)
+            .expect("the version was chosen to fit the data")
+        {
+            data_codewords.extend(bit_vector.get_data());
+        }
+        data_codewords
     }
 }
 
 impl Display for QRData {
     fn fmt(&self, f: &mut Formatter) -> Result {
-        if self.settings.debugging {
-            writeln!(f, "{BRIGHTMAGENTA}Uninitialised{COLORSTOP}")?;
-            writeln!(f, "{BLACK}LogicalTrue{COLORSTOP}")?;
-            writeln!(f, "{BRIGHTWHITE}LogicalFalse{COLORSTOP}")?;
-        }
-        // put every data element in formatter
-        for row in 0..self.output_data.len() {
-            for column in 0..self.output_data[row].len() {
-                match self.output_data[column][row] {
-                    // color output utilising with ANSI
-                    // 105 => bright magenta
-                    SymbolStatus::Uninitialised => write!(f, "{BRIGHTMAGENTA}   {COLORSTOP}")?,
-
-                    // 40 => Black
-                    SymbolStatus::LogicalTrue => write!(f, "{BLACK}   {COLORSTOP}")?,
-                    // 107 => Bright White
-                    SymbolStatus::LogicalFalse => write!(f, "{BRIGHTWHITE}   {COLORSTOP}")?,
-                }
+        let width: usize = self.get_width() + 2 * 4;
+        // the colors of the elements surrounded by the quiet zone (4 elements wide)
+        for y in 0..width {
+            for x in 0..width {
+                let is_dark: bool = (4..width - 4).contains(&x)
+                    && (4..width - 4).contains(&y)
+                    && self.matrix.is_dark(x - 4, y - 4);
+                let color: &str = if is_dark { BLACK } else { BRIGHTWHITE };
+                write!(f, "{color}   {COLORSTOP}")?;
             }
-            // don't forget the newlines
             writeln!(f)?;
         }
-        // if debugging print the role data as well
+        // if debugging print which elements hold data as well
         if self.settings.debugging {
-            writeln!(f, "{BRIGHTMAGENTA}Uninitialised{COLORSTOP}")?;
-            writeln!(f, "{BRIGHTWHITE}QuietZone{COLORSTOP}")?;
-            writeln!(f, "{WHITE}FinderPattern{COLORSTOP}")?;
-            writeln!(f, "{BRIGHTBLACK}AlignmentPattern{COLORSTOP}")?;
-            writeln!(f, "{BRIGHTRED}TimingPattern{COLORSTOP}")?;
-            writeln!(f, "{RED}Separator{COLORSTOP}")?;
-            writeln!(f, "{MAGENTA}ReservedFormatInformation{COLORSTOP}")?;
-            writeln!(f, "{BRIGHTYELLOW}FormatInformation{COLORSTOP}")?;
-            writeln!(f, "{BLUE}ReservedVersionInformation{COLORSTOP}")?;
-            writeln!(f, "{BRIGHTCYAN}VersionInformation{COLORSTOP}")?;
             writeln!(f, "{BRIGHTBLUE}EncodingRegion{COLORSTOP}")?;
-            writeln!(f, "{BLACK}AlwaysTrue{COLORSTOP}")?;
-            for row in 0..self.role_data.len() {
-                for column in 0..self.role_data[row].len() {
-                    match self.role_data[column][row] {
-                        // for now all magenta; rest to be implemented
-                        SymbolRole::Uninitialised => write!(f, "{BRIGHTMAGENTA}   {COLORSTOP}")?,
-                        SymbolRole::QuietZone => write!(f, "{BRIGHTWHITE}   {COLORSTOP}")?,
-                        SymbolRole::FinderPattern => write!(f, "{WHITE}   {COLORSTOP}")?,
-                        SymbolRole::AlignmentPattern => write!(f, "{BRIGHTBLACK}   {COLORSTOP}")?,
-                        SymbolRole::TimingPattern => write!(f, "{BRIGHTRED}   {COLORSTOP}")?,
-                        SymbolRole::Separator => write!(f, "{RED}   {COLORSTOP}")?,
-                        SymbolRole::ReservedFormatInformation => {
-                            write!(f, "{MAGENTA}   {COLORSTOP}")?
-                        }
-                        SymbolRole::FormatInformation => write!(f, "{BRIGHTYELLOW}   {COLORSTOP}")?,
-                        SymbolRole::ReservedVersionInformation => {
-                            write!(f, "{BLUE}   {COLORSTOP}")?
-                        }
-                        SymbolRole::VersionInformation => write!(f, "{BRIGHTCYAN}   {COLORSTOP}")?,
-                        SymbolRole::EncodingRegion => write!(f, "{BRIGHTBLUE}   {COLORSTOP}")?,
-                        SymbolRole::AlwaysTrue => write!(f, "{BLACK}   {COLORSTOP}")?,
-                    }
+            for y in 0..self.get_width() {
+                for x in 0..self.get_width() {
+                    let color: &str = if !self.matrix.is_function(x, y) {
+                        BRIGHTBLUE
+                    } else if self.matrix.is_dark(x, y) {
+                        BLACK
+                    } else {
+                        BRIGHTWHITE
+                    };
+                    write!(f, "{color}   {COLORSTOP}")?;
                 }
-                // don't forget the newlines
-                writeln!(f,)?;
+                writeln!(f)?;
             }
         }
-        // end
-        write!(f, "")
+        Ok(())
     }
 }
 
@@ -1759,8 +1317,8 @@ mod tests {
     fn test_my_vect() {
         use super::MyBitVector;
         let mut test_vec: MyBitVector = MyBitVector::new_with_capacity(2);
-        test_vec.push(0b0000_0100, 4);
-        test_vec.push(0b0101_0101, 8);
+        test_vec.push(0b0000_0100, 4).unwrap();
+        test_vec.push(0b0101_0101, 8).unwrap();
         println!(
             "created: {:?}: {:?}",
             test_vec.data,
@@ -1769,12 +1327,28 @@ mod tests {
         assert_eq!(test_vec.data, vec![0b0100_0101, 0b0101_0000]);
     }
 
+    #[test]
+    fn test_my_vect_overflow() {
+        use super::MyBitVector;
+        use crate::error::QrError;
+        let mut test_vector: MyBitVector = MyBitVector::new_with_capacity(1);
+        test_vector.push(0b0000_0101, 6).unwrap();
+        assert_eq!(
+            test_vector.push(0b0000_0111, 3),
+            Err(QrError::DataTooLong {
+                needed_bits: 9,
+                available_bits: 8
+            })
+        );
+        assert_eq!(test_vector.data, vec![0b0001_0100]);
+    }
+
     #[test]
     fn test_my_vect_padding() {
         use super::MyBitVector;
         let mut test_vector = MyBitVector::new_with_capacity(10);
-        test_vector.push(0b1010, 4);
-        test_vector.push(0b01010101, 8);
+        test_vector.push(0b1010, 4).unwrap();
+        test_vector.push(0b01010101, 8).unwrap();
         test_vector.pad_empty_rest();
         for byte in test_vector.data.clone() {
             print!("{byte:#x} ");
@@ -1785,6 +1359,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn numeric_mode_data_codewords() {
+        use super::QRData;
+        use crate::input::{test_settings, ErrorLevel};
+
+        // example from ISO/IEC 18004 annex I: "01234567" as 1-M
+        let qrdata = QRData::new(test_settings("01234567", ErrorLevel::M), vec![]).unwrap();
+        assert_eq!(qrdata.get_version(), 1);
+        let bit_vectors = qrdata
+            .write_data_into_vectors(qrdata.get_error_info())
+            .unwrap();
+        assert_eq!(
+            bit_vectors[0].data,
+            vec![
+                0x10, 0x20, 0x0c, 0x56, 0x61, 0x80, 0xec, 0x11, 0xec, 0x11, 0xec, 0x11, 0xec, 0x11,
+                0xec, 0x11
+            ]
+        );
+    }
+
+    #[test]
+    fn alphanumeric_mode_data_codewords() {
+        use super::QRData;
+        use crate::input::{test_settings, ErrorLevel};
+
+        // "HELLO WORLD" as 1-Q
+        let qrdata = QRData::new(test_settings("HELLO WORLD", ErrorLevel::Q), vec![]).unwrap();
+        assert_eq!(qrdata.get_version(), 1);
+        let bit_vectors = qrdata
+            .write_data_into_vectors(qrdata.get_error_info())
+            .unwrap();
+        assert_eq!(
+            bit_vectors[0].data,
+            vec![0x20, 0x5b, 0x0b, 0x78, 0xd1, 0x72, 0xdc, 0x4d, 0x43, 0x40, 0xec, 0x11, 0xec]
+        );
+    }
+
+    #[test]
+    fn kanji_mode_data_codewords() {
+        use super::QRData;
+        use crate::input::{test_settings, ErrorLevel};
+
+        // example from ISO/IEC 18004: "点茗" as 1-H
+        let qrdata = QRData::new(test_settings("点茗", ErrorLevel::H), vec![]).unwrap();
+        assert_eq!(qrdata.get_version(), 1);
+        let bit_vectors = qrdata
+            .write_data_into_vectors(qrdata.get_error_info())
+            .unwrap();
+        // 1000 00000010 0110110011111 1101010101010 0000
+        assert_eq!(
+            bit_vectors[0].data,
+            vec![0x80, 0x26, 0xcf, 0xea, 0xa8, 0x00, 0xec, 0x11, 0xec]
+        );
+    }
+
     #[test]
     fn symbol_status_xor() {
         use crate::standard_qr_code::qr_struct::SymbolStatus;
@@ -1805,4 +1434,46 @@ mod tests {
             SymbolStatus::LogicalFalse
         );
     }
+
+    #[test]
+    fn penalty_rules() {
+        use super::{penalty_finder_like, penalty_runs};
+
+        // the run of 5 black elements at the start counts as well
+        assert_eq!(penalty_runs(&[true; 5]), 3);
+        assert_eq!(penalty_runs(&[false; 4]), 0);
+        let mut line: Vec<bool> = vec![true; 6];
+        line.extend([false; 4]);
+        line.extend([true; 7]);
+        assert_eq!(penalty_runs(&line), 4 + 5);
+        // the quiet zone is white
+        let core: [bool; 7] = [true, false, true, true, true, false, true];
+        assert_eq!(penalty_finder_like(&core), 40);
+        // white on both sides is only counted once
+        let line: Vec<bool> = [&[false; 4][..], &core, &[false; 4]].concat();
+        assert_eq!(penalty_finder_like(&line), 40);
+        let line: Vec<bool> = [
+            &[true, false, false, false][..],
+            &core,
+            &[false, false, true],
+        ]
+        .concat();
+        assert_eq!(penalty_finder_like(&line), 0);
+    }
+
+    #[test]
+    fn mask_penalty_report() {
+        use crate::input::ErrorLevel;
+        use crate::standard_qr_code::qr_struct::{MaskPenalty, QRData};
+
+        // a white symbol of version 1
+        let qrdata: QRData = QRData::empty(1, ErrorLevel::L).unwrap();
+        let penalty: MaskPenalty = qrdata.calculate_penalty(0);
+        // 42 lines of 21 elements, 20x20 blocks and 0 % black elements
+        assert_eq!(penalty.n1, 42 * (3 + 16));
+        assert_eq!(penalty.n2, 20 * 20 * 3);
+        assert_eq!(penalty.n3, 0);
+        assert_eq!(penalty.n4, 100);
+        assert_eq!(penalty.total(), 798 + 1200 + 100);
+    }
 }
@@ -0,0 +1,406 @@
+use crate::error::QrError;
+use crate::input::EciCharset;
+use crate::standard_qr_code::encoding_modes::{
+    alphanumeric_value, kanji_value, shift_jis_value, EncodingMode, CHARACTERBITS, KANJIBITS,
+};
+use crate::standard_qr_code::gs1::GROUPSEPARATOR;
+
+/// maximum number of symbols a text can be split into with structured append
+pub const MAXSYMBOLS: u8 = 16;
+
+/// all modes the segmentation chooses from
+const SEGMENTMODES: [EncodingMode; 4] = [
+    EncodingMode::Byte,
+    EncodingMode::Alphanumeric,
+    EncodingMode::Numeric,
+    EncodingMode::Kanji,
+];
+
+/// describes how the characters of the text are turned into bytes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TextEncoding {
+    /// the character set byte mode data is written in
+    pub charset: EciCharset,
+    /// the symbol is in FNC1 mode; in alphanumeric mode the group separator
+    /// is written as '%' and a literal '%' as "%%"
+    pub fnc1: bool,
+}
+
+impl TextEncoding {
+    pub fn new(charset: EciCharset, fnc1: bool) -> TextEncoding {
+        TextEncoding { charset, fnc1 }
+    }
+}
+
+/// a part of the text that is written in a single mode
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment {
+    /// the mode the text of this segment is written in
+    pub mode: EncodingMode,
+    /// the encoded bytes of this segment; ascii for numeric and alphanumeric mode,
+    /// the raw bytes for byte mode, two shift jis bytes per character for kanji mode
+    /// and the header bytes for ECI, structured append and FNC1
+    pub data: Vec<u8>,
+}
+
+impl Segment {
+    pub fn new(mode: EncodingMode, data: Vec<u8>) -> Segment {
+        Segment { mode, data }
+    }
+
+    /// creates the structured append header of the symbol with the given index
+    /// (starting at 0) in a sequence of total_symbols symbols
+    pub fn structured_append(index: u8, total_symbols: u8, parity: u8) -> Segment {
+        assert!(index < total_symbols && total_symbols <= MAXSYMBOLS);
+        Segment::new(
+            EncodingMode::StructuredAppend,
+            vec![(index << 4) | (total_symbols - 1), parity],
+        )
+    }
+
+    /// returns the number of characters written into the character count indicator
+    pub fn character_count(&self) -> usize {
+        self.mode.character_count(self.data.len())
+    }
+
+    /// returns the amount of bits this segment takes up in the given version
+    pub fn total_bits(&self, version: u8) -> usize {
+        self.mode.total_bits(self.character_count(), version)
+    }
+
+    /// returns the values the data of this segment is written as, each with its length in bits
+    pub fn value_groups(&self) -> Vec<(u16, u8)> {
+        match self.mode {
+            // groups of three digits are written as 10 bit numbers, the last group
+            // with 7 bits for two digits or 4 bits for a single digit
+            EncodingMode::Numeric => self
+                .data
+                .chunks(3)
+                .map(|group| {
+                    let group_value: u16 = group
+                        .iter()
+                        .fold(0, |value, digit| value * 10 + (digit - b'0') as u16);
+                    let group_bits: u8 = match group.len() {
+                        3 => 10,
+                        2 => 7,
+                        _ => 4,
+                    };
+                    (group_value, group_bits)
+                })
+                .collect(),
+            // pairs of characters are written as 45 * first + second in 11 bits,
+            // a single remaining character with 6 bits
+            EncodingMode::Alphanumeric => {
+                let values: Vec<u16> = self
+                    .data
+                    .iter()
+                    .map(|character| {
+                        alphanumeric_value(*character as char)
+                            .expect("alphanumeric mode was selected for a non alphanumeric text")
+                            as u16
+                    })
+                    .collect();
+                values
+                    .chunks(2)
+                    .map(|pair| match pair {
+                        [first, second] => (first * 45 + second, 11),
+                        _ => (pair[0], 6),
+                    })
+                    .collect()
+            }
+            EncodingMode::Byte
+            | EncodingMode::Eci
+            | EncodingMode::StructuredAppend
+            | EncodingMode::Fnc1First
+            | EncodingMode::Fnc1Second => self
+                .data
+                .iter()
+                .map(|byte| (*byte as u16, CHARACTERBITS))
+                .collect(),
+            EncodingMode::Kanji => self
+                .data
+                .chunks(2)
+                .map(|shift_jis| {
+                    let shift_jis_value: u16 = u16::from_be_bytes([shift_jis[0], shift_jis[1]]);
+                    (kanji_value(shift_jis_value), KANJIBITS)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// returns the amount of bits all segments take up in the given version
+pub fn segments_bits(segments: &[Segment], version: u8) -> usize {
+    segments
+        .iter()
+        .map(|segment| segment.total_bits(version))
+        .sum()
+}
+
+/// returns the cost of a character in the given mode in sixths of a bit,
+/// or None if the character can't be written in that mode
+fn character_cost(mode: EncodingMode, character: char, encoding: TextEncoding) -> Option<usize> {
+    match mode {
+        // 10 bits per 3 digits
+        EncodingMode::Numeric => character.is_ascii_digit().then_some(20),
+        // 11 bits per 2 characters; with FNC1 a '%' takes up two characters
+        EncodingMode::Alphanumeric => match character {
+            GROUPSEPARATOR if encoding.fnc1 => Some(33),
+            '%' if encoding.fnc1 => Some(66),
+            _ => alphanumeric_value(character).map(|_| 33),
+        },
+        EncodingMode::Byte => encoding
+            .charset
+            .encode_char(character)
+            .map(|bytes| bytes.len() * 8 * 6),
+        // 13 bits per character
+        EncodingMode::Kanji => shift_jis_value(character).map(|_| 78),
+        EncodingMode::Eci
+        | EncodingMode::StructuredAppend
+        | EncodingMode::Fnc1First
+        | EncodingMode::Fnc1Second => None,
+    }
+}
+
+/// returns the bytes a character is stored as in a segment of the given mode
+fn character_bytes(mode: EncodingMode, character: char, encoding: TextEncoding) -> Vec<u8> {
+    match mode {
+        EncodingMode::Alphanumeric if encoding.fnc1 && character == GROUPSEPARATOR => b"%".to_vec(),
+        EncodingMode::Alphanumeric if encoding.fnc1 && character == '%' => b"%%".to_vec(),
+        EncodingMode::Kanji => shift_jis_value(character)
+            .expect("kanji mode was selected for a non kanji character")
+            .to_be_bytes()
+            .to_vec(),
+        EncodingMode::Byte => encoding
+            .charset
+            .encode_char(character)
+            .expect("byte mode was selected for a character outside of the character set"),
+        _ => character.to_string().into_bytes(),
+    }
+}
+
+/// splits the text into the segments that take up the fewest bits in the given version,
+/// byte mode data is written in the character set of the encoding
+pub fn segment_text(
+    text: &str,
+    version: u8,
+    encoding: TextEncoding,
+) -> Result<Vec<Segment>, QrError> {
+    segment_text_with_headers(text, encoding, |mode| {
+        Some(4 + mode.character_count_bits(version) as usize)
+    })
+}
+
+/// splits the text into the segments that take up the fewest bits, where header_bits returns
+/// the length of the mode indicator and character count indicator of a mode
+/// (or None if the mode isn't available in the symbol)
+///
+/// for every character the cheapest way to end in every mode is calculated
+/// (in sixths of a bit, so numeric and alphanumeric groups can be split up per character),
+/// switching the mode costs the bits of the next mode indicator and character count indicator;
+/// the cheapest path is then traced back from the last character
+pub fn segment_text_with_headers(
+    text: &str,
+    encoding: TextEncoding,
+    header_bits: impl Fn(EncodingMode) -> Option<usize>,
+) -> Result<Vec<Segment>, QrError> {
+    let characters: Vec<char> = text.chars().collect();
+    if characters.is_empty() {
+        return Ok(vec![]);
+    }
+    // cost of the header of a new segment in sixths of a bit
+    let head_costs: Vec<Option<usize>> = SEGMENTMODES
+        .iter()
+        .map(|mode| header_bits(*mode).map(|bits| bits * 6))
+        .collect();
+    // for every character and mode the mode the character is written in on the cheapest path
+    // that ends in that mode
+    let mut previous_modes: Vec<Vec<Option<usize>>> = Vec::with_capacity(characters.len());
+    let mut previous_costs: Vec<usize> = head_costs
+        .iter()
+        .map(|cost| cost.unwrap_or(usize::MAX))
+        .collect();
+    for character in characters.iter() {
+        let mut current_costs: Vec<usize> = vec![usize::MAX; SEGMENTMODES.len()];
+        let mut current_modes: Vec<Option<usize>> = vec![None; SEGMENTMODES.len()];
+        // continue the segment in the same mode
+        for (mode_index, mode) in SEGMENTMODES.iter().enumerate() {
+            if head_costs[mode_index].is_none() {
+                continue;
+            }
+            if let Some(cost) = character_cost(*mode, *character, encoding) {
+                current_costs[mode_index] = previous_costs[mode_index] + cost;
+                current_modes[mode_index] = Some(mode_index);
+            }
+        }
+        if current_modes.iter().all(|mode| mode.is_none()) {
+            return Err(QrError::InvalidCharacter {
+                character: *character,
+                charset: encoding.charset,
+            });
+        }
+        // start a new segment after this character; the bits of the finished
+        // segment are rounded up to full bits
+        for (to_index, head_cost) in head_costs.iter().enumerate() {
+            let Some(head_cost) = head_cost else {
+                continue;
+            };
+            for from_index in 0..SEGMENTMODES.len() {
+                if current_modes[from_index].is_none() {
+                    continue;
+                }
+                let switch_cost: usize = current_costs[from_index].div_ceil(6) * 6 + head_cost;
+                if current_modes[to_index].is_none() || switch_cost < current_costs[to_index] {
+                    current_costs[to_index] = switch_cost;
+                    current_modes[to_index] = current_modes[from_index];
+                }
+            }
+        }
+        previous_modes.push(current_modes);
+        previous_costs = current_costs;
+    }
+    // find the cheapest mode of the last character
+    let mut current_mode: usize = 0;
+    for mode_index in 0..SEGMENTMODES.len() {
+        if previous_costs[mode_index].div_ceil(6) < previous_costs[current_mode].div_ceil(6) {
+            current_mode = mode_index;
+        }
+    }
+    // trace the cheapest path back to the first character
+    let mut character_modes: Vec<usize> = vec![0; characters.len()];
+    for character_index in (0..characters.len()).rev() {
+        current_mode = previous_modes[character_index][current_mode]
+            .expect("character without a valid mode on the cheapest path");
+        character_modes[character_index] = current_mode;
+    }
+    // combine consecutive characters of the same mode into segments
+    let mut segments: Vec<Segment> = vec![];
+    for (character, mode_index) in characters.iter().zip(character_modes.iter()) {
+        match segments.last_mut() {
+            Some(segment) if segment.mode == SEGMENTMODES[*mode_index] => segment
+                .data
+                .extend(character_bytes(segment.mode, *character, encoding)),
+            _ => segments.push(Segment::new(
+                SEGMENTMODES[*mode_index],
+                character_bytes(SEGMENTMODES[*mode_index], *character, encoding),
+            )),
+        }
+    }
+    Ok(segments)
+}
+
+mod tests {
+    #[test]
+    fn single_mode_segments() {
+        use super::{segment_text, Segment, TextEncoding};
+        use crate::input::EciCharset;
+        use crate::standard_qr_code::encoding_modes::EncodingMode;
+
+        let utf8 = TextEncoding::new(EciCharset::Utf8, false);
+        assert_eq!(
+            segment_text("0123456789", 1, utf8).unwrap(),
+            vec![Segment::new(EncodingMode::Numeric, b"0123456789".to_vec())]
+        );
+        assert_eq!(
+            segment_text("HELLO WORLD", 1, utf8).unwrap(),
+            vec![Segment::new(
+                EncodingMode::Alphanumeric,
+                b"HELLO WORLD".to_vec()
+            )]
+        );
+        assert_eq!(
+            segment_text("Hello, world! 123", 1, utf8).unwrap(),
+            vec![Segment::new(
+                EncodingMode::Byte,
+                b"Hello, world! 123".to_vec()
+            )]
+        );
+        assert_eq!(
+            segment_text("点茗", 1, utf8).unwrap(),
+            vec![Segment::new(
+                EncodingMode::Kanji,
+                vec![0x93, 0x5F, 0xE4, 0xAA]
+            )]
+        );
+        assert!(segment_text("", 1, utf8).unwrap().is_empty());
+    }
+
+    #[test]
+    fn mixed_mode_segments() {
+        use super::{segment_text, segments_bits, Segment, TextEncoding};
+        use crate::input::EciCharset;
+        use crate::standard_qr_code::encoding_modes::EncodingMode;
+
+        let utf8 = TextEncoding::new(EciCharset::Utf8, false);
+        let text = "https://EXAMPLE.COM/ORDER/000123456789";
+        let segments = segment_text(text, 1, utf8).unwrap();
+        assert_eq!(
+            segments,
+            vec![
+                Segment::new(EncodingMode::Byte, b"https".to_vec()),
+                Segment::new(
+                    EncodingMode::Alphanumeric,
+                    b"://EXAMPLE.COM/ORDER/".to_vec()
+                ),
+                Segment::new(EncodingMode::Numeric, b"000123456789".to_vec()),
+            ]
+        );
+        // the segments have to be smaller than the text in a single mode
+        let byte_bits = EncodingMode::Byte.total_bits(text.len(), 1);
+        assert!(segments_bits(&segments, 1) < byte_bits);
+        // non ascii characters take up their utf-8 bytes in byte mode
+        assert_eq!(
+            segment_text("café", 1, utf8).unwrap(),
+            vec![Segment::new(EncodingMode::Byte, "café".as_bytes().to_vec())]
+        );
+        // a short run of digits isn't worth a mode switch
+        assert_eq!(
+            segment_text("ORDER 12", 1, utf8).unwrap(),
+            vec![Segment::new(
+                EncodingMode::Alphanumeric,
+                b"ORDER 12".to_vec()
+            )]
+        );
+    }
+
+    #[test]
+    fn charset_segments() {
+        use super::{segment_text, Segment, TextEncoding};
+        use crate::input::EciCharset;
+        use crate::standard_qr_code::encoding_modes::EncodingMode;
+
+        let latin1 = TextEncoding::new(EciCharset::Iso8859_1, false);
+        // single byte character sets take up one byte per character
+        assert_eq!(
+            segment_text("Grüße", 1, latin1).unwrap(),
+            vec![Segment::new(
+                EncodingMode::Byte,
+                vec![0x47, 0x72, 0xFC, 0xDF, 0x65]
+            )]
+        );
+        // characters outside of the character set can't be written
+        assert!(segment_text("€", 1, latin1).is_err());
+    }
+
+    #[test]
+    fn fnc1_segments() {
+        use super::{segment_text, Segment, TextEncoding};
+        use crate::input::EciCharset;
+        use crate::standard_qr_code::encoding_modes::EncodingMode;
+
+        // with FNC1 the group separator is written as '%' and a literal '%' as "%%"
+        let gs1 = TextEncoding::new(EciCharset::Utf8, true);
+        assert_eq!(
+            segment_text("10AB-12\u{1D}21XY%", 1, gs1).unwrap(),
+            vec![Segment::new(
+                EncodingMode::Alphanumeric,
+                b"10AB-12%21XY%%".to_vec()
+            )]
+        );
+        // in byte mode the group separator is written as is
+        assert_eq!(
+            segment_text("10ab\u{1D}21", 1, gs1).unwrap(),
+            vec![Segment::new(EncodingMode::Byte, b"10ab\x1D21".to_vec())]
+        );
+    }
+}
@@ -1,53 +1,114 @@
+use std::ops::RangeInclusive;
+
+use crate::error::QrError;
 use crate::input::ErrorLevel;
+use crate::standard_qr_code::segmentation::{segment_text, segments_bits, Segment, TextEncoding};
 use crate::standard_qr_code::version_constants::get_error_block_info;
 
 use super::qr_struct::ErrorBlockInfo;
 
 type VersionInfo = (u8, u16, Vec<(ErrorLevel, Vec<ErrorBlockInfo>)>);
 
-/// takes text length and error correction level and returns the reqired version
-/// and the total databytes that can be stored in it
+/// takes the text and error correction level and returns the smallest version of the range that fits,
+/// the error block information of that version and the segments the text is split into
+/// (the prefix segments are written in front of the segments of the text,
+/// the characters are turned into bytes with the given encoding)
 pub fn get_verison_info(
-    text_length: usize,
+    prefix_segments: &[Segment],
+    text: &str,
+    encoding: TextEncoding,
     error_level: ErrorLevel,
-) -> Result<(u8, Vec<ErrorBlockInfo>), String> {
-    // increase the text length by one to account for the QR code symbols
-    // and a 1 byte character count indicator
-    let text_length_complete: usize = text_length + 2;
-    // check the length of the text for being to long
-    if text_length_complete > (3706 - 750) {
-        eprintln!("text is way to long for a qr code; biggest possible is 2956 characters");
-        panic!();
-    }
-    let mut search_length: u16 = text_length_complete as u16;
+    versions: RangeInclusive<u8>,
+) -> Result<(u8, Vec<ErrorBlockInfo>, Vec<Segment>), QrError> {
     let all_info: Vec<VersionInfo> = get_error_block_info();
+    let mut segments: Vec<Segment> = vec![];
+    // bits of the text and of the symbol in the largest version that was checked
+    let mut needed_bits: usize = 0;
+    let mut available_bits: usize = 0;
     // look for the fitting version
-    for version in all_info {
-        // when the version is 10 or higher the character count indicator has a length of 2 bytes
-        if version.0 == 10 {
-            search_length += 1;
+    for version in all_info
+        .into_iter()
+        .filter(|version| versions.contains(&version.0))
+    {
+        // the character count indicators grow at version 10 and 27,
+        // which can change the optimal segmentation
+        if version.0 == *versions.start() || version.0 == 10 || version.0 == 27 {
+            segments = prefix_segments.to_vec();
+            segments.extend(segment_text(text, version.0, encoding)?);
         }
-        // does this one potentally fit the text
-        if search_length <= version.1 {
-            // does the error level fit the text
-            for (error_enum, block_vector) in version.2 {
-                // does the level fit
-                if error_enum == error_level {
-                    let mut current_length: u16 = 0;
-                    for info_block in block_vector.clone() {
-                        current_length +=
-                            info_block.num_block as u16 * info_block.num_data_bytes as u16;
-                    }
-                    // if the length that can be fit is larger or equal to the text length
-                    // select this information for return
-                    if current_length >= search_length {
-                        return Ok((version.0, block_vector.clone()));
-                    }
+        let search_bits: usize = segments_bits(&segments, version.0);
+        needed_bits = search_bits;
+        // does the error level fit the text
+        for (error_enum, block_vector) in version.2 {
+            // does the level fit
+            if error_enum == error_level {
+                let mut current_length: usize = 0;
+                for info_block in block_vector.clone() {
+                    current_length +=
+                        info_block.num_block as usize * info_block.num_data_bytes as usize * 8;
+                }
+                // if the bits that can be fit are more or equal to the bits of the text
+                // select this information for return
+                if current_length >= search_bits {
+                    return Ok((version.0, block_vector.clone(), segments));
                 }
+                available_bits = current_length;
             }
         }
     }
-    let return_result: Result<(u8, Vec<ErrorBlockInfo>), String> =
-        Err(String::from("text length or error level invalid"));
-    return_result
+    Err(QrError::DataTooLong {
+        needed_bits,
+        available_bits,
+    })
+}
+
+/// splits the text into the fewest parts that fit into a single symbol each
+/// (behind the prefix segments); fails if more than max_parts parts are required
+pub fn split_text(
+    prefix_segments: &[Segment],
+    text: &str,
+    encoding: TextEncoding,
+    error_level: ErrorLevel,
+    versions: RangeInclusive<u8>,
+    max_parts: u8,
+) -> Result<Vec<String>, QrError> {
+    let characters: Vec<char> = text.chars().collect();
+    let mut parts: Vec<String> = vec![];
+    let mut start: usize = 0;
+    while start < characters.len() {
+        if parts.len() == max_parts as usize {
+            return Err(QrError::TooManySymbols(max_parts));
+        }
+        // binary search for the most characters that still fit into a symbol
+        let mut fitting: usize = 0;
+        let mut not_fitting: usize = characters.len() - start + 1;
+        while not_fitting - fitting > 1 {
+            let middle: usize = (fitting + not_fitting) / 2;
+            let part: String = characters[start..start + middle].iter().collect();
+            match get_verison_info(
+                prefix_segments,
+                &part,
+                encoding,
+                error_level,
+                versions.clone(),
+            ) {
+                Ok(_) => fitting = middle,
+                Err(_) => not_fitting = middle,
+            }
+        }
+        if fitting == 0 {
+            let part: String = characters[start..start + 1].iter().collect();
+            // report why the character can't be written
+            get_verison_info(
+                prefix_segments,
+                &part,
+                encoding,
+                error_level,
+                versions.clone(),
+            )?;
+        }
+        parts.push(characters[start..start + fitting].iter().collect());
+        start += fitting;
+    }
+    Ok(parts)
 }
@@ -1231,8 +1231,9 @@ pub fn alignment_pattern_data(version: u8) -> (u8, Vec<u8>) {
     }
 }
 
+// only used in tests
 /// contains all bit streams for all versions
-pub fn version_info(version: u8) -> u32 {
+pub fn _version_info(version: u8) -> u32 {
     match version {
         7 => 0x07C94,
         8 => 0x085BC,
@@ -1272,8 +1273,9 @@ pub fn version_info(version: u8) -> u32 {
     }
 }
 
+// only used in tests
 /// valid formant information bit sequences
-pub fn information_sequences(data: u8) -> u16 {
+pub fn _information_sequences(data: u8) -> u16 {
     match data {
         0 => 0x5412,
         1 => 0x5125,